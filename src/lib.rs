@@ -26,6 +26,7 @@
 //! * BalanceTransaction:
 //!     * Ability to retrieve a BalanceTransaction
 //!     * Ability to list all BalanceTransactions
+//!     * Ability to lazily page through BalanceTransactions as an Iterator or Stream
 //! 
 //! * Card:
 //!     * Ability to attach a Card to a PaymentMethod
@@ -33,50 +34,96 @@
 //! * Charge:
 //!     * Ability to retrieve a Charge
 //!     * Ability to list all Charges
+//!     * Ability to lazily page through Charges as an Iterator or Stream
+//!     * Ability to search Charges, e.g. by metadata
 //!     * Ability to update an existing Charge
 //!     * Ability to create a new Charge
 //!     * Ability to capture a charge
+//!     * Ability to refund a charge in full or in part
+//! 
+//! * Coupon:
+//!     * Ability to create a new Coupon with an amount or a percentage off
+//!     * Ability to retrieve a Coupon
+//!     * Ability to update an existing Coupon
+//!     * Ability to delete a Coupon
+//!     * Ability to list all Coupons
+//!     * Ability to lazily page through Coupons as an Iterator or Stream
 //! 
 //! * Customer:
 //!     * Ability to retrieve a Customer
 //!     * Ability to list all Customers
+//!     * Ability to lazily page through Customers as an Iterator or Stream
+//!     * Ability to search Customers, e.g. by metadata
 //!     * Ability to update an existing Customer
 //!     * Ability to create a new Customer
 //!     * Ability to destroy a Customer
 //!     * Ability to attach payment methods to Customers
 //!     * Ability to list a customers invoices
 //!     * Ability to list a customers payment methods
+//!     * Ability to apply a coupon or promotion code and to remove the discount of a Customer
 //! 
 //! * Dispute:
 //!     * Ability to retrieve a Dispute
 //!     * Ability to list all Disputes
+//!     * Ability to lazily page through Disputes as an Iterator or Stream
 //!     * Ability to close a Dispute
 //!     * Ability to update an existing Dispute
 //! 
 //! * Event:
 //!     * Ability to retrieve an Event
 //!     * Ability to list all Events
+//!     * Ability to lazily page through Events as an Iterator or Stream
+//!     * Ability to read the object of an Event as a typed struct
+//!     * Ability to match on the type of an Event with the EventType enum
 //! 
 //! * Files:
 //!     * Ability to retrieve a File
 //!     * Ability to list all Files
+//!     * Ability to lazily page through Files as an Iterator or Stream
 //!     * Ability to create a File
 //! 
 //! * FileLink:
 //!     * Ability to retrieve a FileLink
 //!     * Ability to list all FileLinks
+//!     * Ability to lazily page through FileLinks as an Iterator or Stream
 //!     * Ability to create a FileLink
 //!     * Ability to update an existing FileLink
 //! 
 //! * Invoice:
 //!     * Ability to retrieve an Invoice
 //!     * Ability to list all Invoices
+//!     * Ability to lazily page through Invoices as an Iterator or Stream
+//!     * Ability to search Invoices, e.g. by metadata
 //!     * Ability to create an Invoice
 //!     * Ability to update an existing Invoice
+//!     * Ability to finalize, pay, send, void or mark an Invoice as uncollectible
+//!     * Ability to delete a draft Invoice
+//!     * Ability to apply coupons and promotion codes to an Invoice
+//!     * Ability to preview the upcoming Invoice and its lines, e.g. to quote a proration
+//! 
+//! * InvoiceItem:
+//!     * Ability to create a new InvoiceItem from an amount or a price and quantity
+//!     * Ability to retrieve an InvoiceItem
+//!     * Ability to update an existing InvoiceItem
+//!     * Ability to delete an InvoiceItem
+//!     * Ability to list all InvoiceItems
+//!     * Ability to lazily page through InvoiceItems as an Iterator or Stream
 //! 
 //! * Mandate:
 //!     * Ability to retrieve a Mandate
 //! 
+//! * PaymentIntent:
+//!     * Ability to create a new PaymentIntent
+//!     * Ability to retrieve a PaymentIntent
+//!     * Ability to update an existing PaymentIntent
+//!     * Ability to list all PaymentIntents
+//!     * Ability to lazily page through PaymentIntents as an Iterator or Stream
+//!     * Ability to search PaymentIntents, e.g. by metadata
+//!     * Ability to confirm, capture and cancel a PaymentIntent
+//!     * Ability to increment the authorization of a PaymentIntent
+//!     * Ability to apply a customer balance to a PaymentIntent
+//!     * Typed status and next_action for 3D Secure redirects
+//! 
 //! * PaymentMethod:
 //!     * Ability to retrieve a PaymentMethod
 //!     * Ability to create a new PaymentMethod
@@ -84,17 +131,101 @@
 //! * Plan:
 //!     * Ability to retrieve a Plan
 //!     * Ability to list all Plan
+//!     * Ability to lazily page through Plans as an Iterator or Stream
 //!     * Ability to update an existing Plan
 //!     * Ability to create a new Plan
 //! 
 //! * Price
 //!     * Ability to create a new Price
-//! 
-//! * Subscription
-//!     * Ability to create a new Subscription
+//!     * Ability to retrieve a Price
+//!     * Ability to update an existing Price
+//!     * Ability to list all Prices
+//!     * Ability to lazily page through Prices as an Iterator or Stream
+//!     * Ability to search Prices, e.g. by metadata
+//!     * Recurring, tiered and transformed quantity prices, with lookup keys
+//! 
+//! * Product:
+//!     * Ability to create a new Product
+//!     * Ability to retrieve a Product
+//!     * Ability to update an existing Product
+//!     * Ability to destroy a Product
+//!     * Ability to list all Products
+//!     * Ability to lazily page through Products as an Iterator or Stream
+//!     * Ability to search Products, e.g. by metadata
+//! 
+//! * PromotionCode:
+//!     * Ability to create a new PromotionCode for a Coupon
+//!     * Ability to retrieve a PromotionCode
+//!     * Ability to update or deactivate an existing PromotionCode
+//!     * Ability to list all PromotionCodes
+//!     * Ability to lazily page through PromotionCodes as an Iterator or Stream
+//! 
+//! * Refund:
+//!     * Ability to create a new Refund for a Charge or PaymentIntent
+//!     * Ability to retrieve a Refund
+//!     * Ability to update the metadata of a Refund
+//!     * Ability to cancel a Refund
+//!     * Ability to list all Refunds
+//!     * Ability to lazily page through Refunds as an Iterator or Stream
+//! 
+//! * SetupIntent:
+//!     * Ability to create a new SetupIntent
+//!     * Ability to retrieve a SetupIntent
+//!     * Ability to update an existing SetupIntent
+//!     * Ability to list all SetupIntents
+//!     * Ability to lazily page through SetupIntents as an Iterator or Stream
+//!     * Ability to confirm and cancel a SetupIntent
+//!     * Ability to verify microdeposits
+//!     * Ability to retrieve the Mandate of a SetupIntent
+//! 
+//! * Subscription:
+//!     * Ability to create a new Subscription with several prices and quantities
 //!     * Ability to retrieve a Subscription
 //!     * Ability to update an existing Subscription
-//!     * Ability to cancel a subscription
+//!     * Ability to cancel a subscription right away or at the end of the period
+//!     * Ability to list all Subscriptions
+//!     * Ability to lazily page through Subscriptions as an Iterator or Stream
+//!     * Ability to search Subscriptions, e.g. by metadata
+//!     * Ability to start trials, apply coupons and choose the proration and payment behavior
+//!     * Ability to apply a promotion code and to remove the discount of a Subscription
+//!     * Ability to pause and resume a Subscription
+//! 
+//! * SubscriptionItem:
+//!     * Ability to add an item to a Subscription
+//!     * Ability to retrieve a SubscriptionItem
+//!     * Ability to update an existing SubscriptionItem
+//!     * Ability to delete a SubscriptionItem
+//!     * Ability to list all items of a Subscription
+//!     * Ability to lazily page through SubscriptionItems as an Iterator or Stream
+//! 
+//! * SubscriptionSchedule:
+//!     * Ability to create a new SubscriptionSchedule with phases
+//!     * Ability to create a SubscriptionSchedule from an existing Subscription
+//!     * Ability to retrieve a SubscriptionSchedule
+//!     * Ability to update the phases of a SubscriptionSchedule
+//!     * Ability to cancel or release a SubscriptionSchedule
+//!     * Ability to list all SubscriptionSchedules
+//!     * Ability to lazily page through SubscriptionSchedules as an Iterator or Stream
+//! 
+//! * UsageRecord:
+//!     * Ability to report the usage of a metered SubscriptionItem
+//!     * Ability to list the usage summaries of a SubscriptionItem
+//!     * Ability to buffer usage increments and report them in batches with a UsageAggregator
+//! 
+//! * Webhook:
+//!     * Ability to verify the Stripe-Signature of webhook requests, with a timestamp tolerance and several secrets
+//!     * Ability to parse webhook payloads into an Event with a typed object
+//!     * Ability to route events to typed sync or async handlers with an EventDispatcher
+//!     * Ability to skip duplicate deliveries and replay missed events with an EventStore, kept in memory or in a file
+//!     * Ability to mount a ready-made webhook receiver in axum with the `webhook-server` feature
+//! 
+//! * WebhookEndpoint:
+//!     * Ability to create a new WebhookEndpoint with its enabled events and api version, returning its signing secret
+//!     * Ability to retrieve a WebhookEndpoint
+//!     * Ability to update, disable or enable an existing WebhookEndpoint
+//!     * Ability to delete a WebhookEndpoint
+//!     * Ability to list all WebhookEndpoints
+//!     * Ability to lazily page through WebhookEndpoints as an Iterator or Stream
 
 
 /// Stripe API Implementation
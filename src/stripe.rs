@@ -1,9 +1,34 @@
+pub mod client;
+pub mod dispatch;
+pub mod error;
+pub mod expandable;
+pub mod form;
+pub mod list;
 pub mod response;
-
-use serde_json::json;
-
+pub mod retry;
+#[cfg(feature = "webhook-server")]
+pub mod server;
+pub mod store;
+pub mod usage;
+pub mod webhook;
+
+pub use client::{StripeClient, StripeClientBuilder};
+pub use dispatch::{AsyncEventDispatcher, DispatchError, EventDispatcher, FromEvent, HandlerResult};
+pub use error::{ApiError, StripeError};
+pub use expandable::Expandable;
+pub use list::{ListIter, ListStream, RangeQuery, SearchParams};
+pub use retry::RetryPolicy;
+#[cfg(feature = "webhook-server")]
+pub use server::WebhookReceiver;
+pub use store::{EventStore, FileEventStore, MemoryEventStore};
+pub use usage::UsageAggregator;
+pub use webhook::{Webhook, WebhookError};
+
+use futures_util::TryStreamExt;
+use std::collections::HashMap;
 use serde::{Serialize, Deserialize};
-use std::convert::TryInto;
+
+use crate::stripe::list::Object;
 
 // Full V1 API Support Complete
 /// Stores the Stripe API client + secret.
@@ -14,7 +39,7 @@ pub struct Auth {
 }
 impl Auth {
     pub fn new(client: String, secret: String) -> Self {
-        return Auth{client, secret};
+        Auth{client, secret}
     }
 }
 
@@ -34,44 +59,48 @@ impl Balance {
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Fetch customer using id
-    /// let balance = payup::stripe::Balance::async_get(auth).await;
+    /// let balance = payup::stripe::Balance::async_get(&client).await;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_get(creds: Auth) -> Result<Self, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/balance");
-        let request = reqwest::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send().await?;
-        let json = request.json::<Self>().await?;
-        return Ok(json);
+    pub async fn async_get(client: &StripeClient) -> Result<Self, StripeError> {
+        client.async_get("/balance").await
     }
 
     /// Retrieves the current account balance based on the authentication that was used to make the request. 
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Fetch customer using id
-    /// let balance = payup::stripe::Balance::get(auth).await;
+    /// let balance = payup::stripe::Balance::get(&client);
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn get(creds: Auth) -> Result<Self, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/balance");
-        let request = reqwest::blocking::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send()?;
-        let json = request.json::<Self>()?;
-        return Ok(json);
+    pub fn get(client: &StripeClient) -> Result<Self, StripeError> {
+        client.get("/balance")
     }
 }
 
@@ -107,140 +136,211 @@ impl BalanceTransaction {
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
     /// * `id` - A string representing an existing stripe transaction balance id
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Retrieve the balance transaction with the given ID.
-    /// let balance_transaction = payup::stripe::BalanceTransaction::async_get(auth, "txn_").await;
+    /// let balance_transaction = payup::stripe::BalanceTransaction::async_get(&client, format!("txn_")).await;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_get(creds: Auth, id: String) -> Result<Self, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/balance_transactions/{}", id.clone());
-        let request = reqwest::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send().await?;
-        let json = request.json::<Self>().await?;
-        return Ok(json);
+    pub async fn async_get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/balance_transactions/{}", id.clone());
+        client.async_get(&path).await
     }
 
-    /// Asynchronously lists all balance transactions
+    /// Asynchronously and lazily iterates over balance transactions, fetching pages from the stripe api only as they are needed
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::BalanceTransactionListParams with the filters, page size and the object to start from
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
-    /// // Retrieve the balance transaction with the given ID.
-    /// let balance_transactions = payup::stripe::BalanceTransaction::async_list(auth).await;
+    /// // Walk the 50 most recent balance transactions, fetching 25 per request
+    /// let mut params = payup::stripe::BalanceTransactionListParams::new();
+    /// params.limit = Some(25);
+    /// let mut balance_transactions = payup::stripe::BalanceTransaction::async_iter(&client, params).take(50);
+    /// while let Some(balance_transaction) = balance_transactions.next().await {
+    ///     let balance_transaction = balance_transaction?;
+    /// }
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_list(creds: Auth) -> Result<Vec<Self>, reqwest::Error>{
-        let mut objects: Vec<Self> = Vec::new();
+    pub fn async_iter(client: &StripeClient, params: BalanceTransactionListParams) -> ListStream<Self> {
+        list::stream(client, "/balance_transactions", &params)
+    }
 
-        let mut has_more = true;
-        let mut starting_after: Option<String> = None;
-        while has_more{
-            let json = Self::list_chunk_async(creds.clone(), starting_after).await?;
-            for json_object in json.data{
-                objects.push(json_object);
-            }
-            has_more = json.has_more;
-            starting_after = Some(objects[objects.len() - 1].id.clone());
-        }
-        return Ok(objects);
+    /// Asynchronously lists all balance transactions
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::BalanceTransactionListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Retrieve the balance transaction with the given ID.
+    /// let balance_transactions = payup::stripe::BalanceTransaction::async_list(&client, payup::stripe::BalanceTransactionListParams::new()).await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_list(client: &StripeClient, params: BalanceTransactionListParams) -> Result<Vec<Self>, StripeError>{
+        let params = BalanceTransactionListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::async_iter(client, params).try_collect().await
     }
 
     /// Retrieves the balance transaction with the given ID.
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
     /// * `id` - A string representing an existing stripe transaction balance id
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Retrieve the balance transaction with the given ID.
-    /// let balance_transaction = payup::stripe::BalanceTransaction::get(auth, "txn_").await;
+    /// let balance_transaction = payup::stripe::BalanceTransaction::get(&client, format!("txn_"));
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn get(creds: Auth, id: String) -> Result<Self, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/balance_transactions/{}", id.clone());
-        let request = reqwest::blocking::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send()?;
-        let json = request.json::<Self>()?;
-        return Ok(json);
+    pub fn get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/balance_transactions/{}", id.clone());
+        client.get(&path)
     }
 
-    /// Lists all balance transactions
+    /// Lazily iterates over balance transactions, fetching pages from the stripe api only as they are needed
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::BalanceTransactionListParams with the filters, page size and the object to start from
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
-    /// // Retrieve the balance transaction with the given ID.
-    /// let balance_transactions = payup::stripe::BalanceTransaction::async_list(auth).await;
+    /// // Walk the 50 most recent balance transactions, fetching 25 per request
+    /// let mut params = payup::stripe::BalanceTransactionListParams::new();
+    /// params.limit = Some(25);
+    /// for balance_transaction in payup::stripe::BalanceTransaction::iter(&client, params).take(50) {
+    ///     let balance_transaction = balance_transaction?;
+    /// }
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn list(creds: Auth) -> Result<Vec<Self>, reqwest::Error>{
-        let mut objects: Vec<Self> = Vec::new();
-
-        let mut has_more = true;
-        let mut starting_after: Option<String> = None;
-        while has_more{
-            let json = Self::list_chunk(creds.clone(), starting_after)?;
-            for json_object in json.data{
-                objects.push(json_object);
-            }
-            has_more = json.has_more;
-            starting_after = Some(objects[objects.len() - 1].id.clone());
-        }
-        return Ok(objects);
+    pub fn iter(client: &StripeClient, params: BalanceTransactionListParams) -> ListIter<Self> {
+        list::iter(client, "/balance_transactions", &params)
     }
 
-    fn list_chunk(creds: Auth, starting_after: Option<String>) -> Result<BalanceTransactions, reqwest::Error> {
-        let mut url = "https://api.stripe.com/v1/balance_transactions".to_string();
-
-        if starting_after.is_some() {
-            url = format!("https://api.stripe.com/v1/balance_transactions?starting_after={}", starting_after.unwrap());
-        }
-
-        let request = reqwest::blocking::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send()?;
-
-        let json = request.json::<BalanceTransactions>()?;
-        return Ok(json);
+    /// Lists all balance transactions
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::BalanceTransactionListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Retrieve the balance transaction with the given ID.
+    /// let balance_transactions = payup::stripe::BalanceTransaction::list(&client, payup::stripe::BalanceTransactionListParams::new());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(client: &StripeClient, params: BalanceTransactionListParams) -> Result<Vec<Self>, StripeError>{
+        let params = BalanceTransactionListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::iter(client, params).collect()
     }
+}
 
-    async fn list_chunk_async(creds: Auth, starting_after: Option<String>) -> Result<BalanceTransactions, reqwest::Error> {
-        let mut url = "https://api.stripe.com/v1/balance_transactions".to_string();
-
-        if starting_after.is_some() {
-            url = format!("https://api.stripe.com/v1/balance_transactions?starting_after={}", starting_after.unwrap());
-        }
-
-        let request = reqwest::blocking::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send()?;
+impl Object for BalanceTransaction {
+    fn object_id(&self) -> Option<&str> {
+        Some(&self.id)
+    }
+}
 
-        let json = request.json::<BalanceTransactions>()?;
-        return Ok(json);
+/// Filters and pagination for listing balance transactions with `BalanceTransaction::list` and `BalanceTransaction::iter`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct BalanceTransactionListParams {
+    /// Only balance transactions created in this range.
+    pub created: Option<RangeQuery>,
+    /// Only balance transactions in this three-letter ISO currency code.
+    pub currency: Option<String>,
+    /// Only balance transactions paid out in this payout id.
+    pub payout: Option<String>,
+    /// Only balance transactions caused by this source id, e.g. a charge.
+    pub source: Option<String>,
+    /// Only balance transactions of this type, e.g. `charge` or `refund`.
+    #[serde(rename = "type")]
+    pub type_field: Option<String>,
+    /// Number of objects fetched per page, between 1 and 100.
+    pub limit: Option<u32>,
+    /// Starts the list after the object with this id.
+    pub starting_after: Option<String>,
+    /// Starts the list before the object with this id and walks towards newer objects.
+    pub ending_before: Option<String>,
+}
+impl BalanceTransactionListParams {
+    pub fn new() -> Self {
+        BalanceTransactionListParams::default()
     }
 }
 
+
 // TODO - Finish Implementation
 /// You can store multiple cards on a customer in order to charge the customer later.
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct Card {
     pub id: Option<String>,
     pub brand: Option<String>,
@@ -249,42 +349,29 @@ pub struct Card {
     pub cvc: Option<String>,
     pub network: Option<String>,
     pub country: Option<String>,
-    pub exp_month: Option<String>,
-    pub exp_year: Option<String>,
+    pub exp_month: Option<i64>,
+    pub exp_year: Option<i64>,
     pub fingerprint: Option<String>,
 }
 impl Card {
     pub fn new() -> Self {
-        return Card{
-            id: None, 
-            brand: None, 
-            last4: None, 
-            number: None, 
-            cvc: None, 
-            network: None, 
-            country: None, 
-            exp_month: None,
-            exp_year: None,
-            fingerprint: None
-        };
+        Card::default()
     }
 }
 
 // Full V1 API Support Complete
 /// Represents a charge to a credit or a debit card.
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct Charge {
     pub id: Option<String>,
     pub object: Option<String>,
-    pub amount: Option<String>,
-    #[serde(rename = "amount")]
-    pub stripe_amount: Option<i64>,
+    pub amount: Option<i64>,
     #[serde(rename = "amount_captured")]
     pub amount_captured: Option<i64>,
     #[serde(rename = "amount_refunded")]
     pub amount_refunded: Option<i64>,
     #[serde(rename = "balance_transaction")]
-    pub balance_transaction: Option<String>,
+    pub balance_transaction: Option<Expandable<BalanceTransaction>>,
     #[serde(rename = "billing_details")]
     pub billing_details: Option<BillingDetails>,
     pub captured: Option<bool>,
@@ -295,7 +382,8 @@ pub struct Charge {
     #[serde(rename = "fraud_details")]
     pub fraud_details: Option<FraudDetails>,
     pub livemode: Option<bool>,
-    // pub metadata: Metadata,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
     pub paid: Option<bool>,
     #[serde(rename = "payment_method")]
     pub payment_method: Option<String>,
@@ -308,7 +396,7 @@ pub struct Charge {
     pub status: Option<String>,
     // #[serde(rename = "calculated_statement_descriptor")]
     // pub calculated_statement_descriptor: Value,
-    pub customer: Option<String>,
+    pub customer: Option<Expandable<Customer>>,
     // pub invoice: Value,
     // #[serde(rename = "failure_code")]
     // pub failure_code: Value,
@@ -318,8 +406,8 @@ pub struct Charge {
     // pub on_behalf_of: Value,
     // pub order: Value,
     // pub outcome: Value,
-    // #[serde(rename = "payment_intent")]
-    // pub payment_intent: Value,
+    #[serde(rename = "payment_intent")]
+    pub payment_intent: Option<Expandable<PaymentIntent>>,
     #[serde(rename = "receipt_email")]
     pub receipt_email: Option<String>,
     pub source: Option<String>,
@@ -342,6 +430,9 @@ pub struct Charge {
     // pub application_fee: Value,
     // #[serde(rename = "application_fee_amount")]
     // pub application_fee_amount: Value,
+    /// Set when Stripe answered with the stored result of an earlier request with the same idempotency key.
+    #[serde(skip_serializing)]
+    pub idempotent_replayed: Option<bool>,
 }
 impl Charge {
 
@@ -349,45 +440,20 @@ impl Charge {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
     /// let mut charge = payup::stripe::Charge::new();
     /// charge.amount = Some(100);
     /// charge.currency = Some(format!("usd"));
-    /// charge.customer = Some(format!("cust_"));
+    /// charge.customer = Some(format!("cust_").into());
     /// charge.description = Some(format!("test charge"));
     /// charge.receipt_email = Some(format!("test@test.com"));
     /// charge.source = Some(format!("card_"));
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn new() -> Self {
-        return Charge{
-            id: None, 
-            object: None,
-            amount: None, 
-            stripe_amount: None,
-            amount_captured: None,
-            amount_refunded: None,
-            balance_transaction: None,
-            billing_details: None,
-            captured: None,
-            created: None,
-            currency: None, 
-            customer: None, 
-            description: None,
-            disputed: None,
-            fraud_details: None,
-            livemode: None,
-            paid: None,
-            payment_method: None,
-            payment_method_details: None,
-            receipt_url: None,
-            refunded: None,
-            refunds: None,
-            status: None,
-            source: None, 
-            receipt_email: None, 
-            statement_descriptor: None, 
-            statement_descriptor_suffix: None
-        };
+        Charge::default()
     }
 
     /// Asynchronously capture the payment of an existing, uncaptured, charge. 
@@ -395,149 +461,235 @@ impl Charge {
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// let mut charge = payup::stripe::Charge::new();
     /// charge.amount = Some(100);
     /// charge.currency = Some(format!("usd"));
-    /// charge.customer = Some(format!("cust_"));
+    /// charge.customer = Some(format!("cust_").into());
     /// charge.description = Some(format!("test charge"));
     /// charge.receipt_email = Some(format!("test@test.com"));
     /// charge.source = Some(format!("card_"));
     ///
-    /// charge = charge.async_post(auth.clone()).await?;
+    /// charge = charge.async_post(&client).await?;
     ///
     /// // Fetch customer using id
-    /// let captured_charge = payup::stripe::Charge::async_capture(charge, "cust_").await?;
+    /// let captured_charge = charge.async_capture(&client).await?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_capture(&self, creds: Auth) ->  Result<Self, reqwest::Error>{
-        let url = format!("https://api.stripe.com/v1/charges/{}/capture", self.id.clone().unwrap());
+    pub async fn async_capture(&self, client: &StripeClient) ->  Result<Self, StripeError>{
+        let path = format!("/charges/{}/capture", self.id.clone().unwrap());
 
-        let request = reqwest::Client::new().post(url)
-            .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-            .form(&self.to_capture_params())
-            .send().await?;
-    
-        let json = request.json::<Self>().await?;
-        return Ok(json);
+        client.async_post(&path, &self.to_capture_params()).await
     }
 
     /// Asynchronously retrieves the details of a charge that has previously been created. 
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
     /// * `id` - The id of the charge you want to retrieve.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Fetch customer using id
-    /// let charge = payup::stripe::Charge::async_get(auth, "ch_").await?;
+    /// let charge = payup::stripe::Charge::async_get(&client, format!("ch_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/charges/{}", id.clone());
+        client.async_get(&path).await
+    }
+
+    /// Asynchronously and lazily iterates over charges, fetching pages from the stripe api only as they are needed
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::ChargeListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Walk the 50 most recent charges, fetching 25 per request
+    /// let mut params = payup::stripe::ChargeListParams::new();
+    /// params.limit = Some(25);
+    /// let mut charges = payup::stripe::Charge::async_iter(&client, params).take(50);
+    /// while let Some(charge) = charges.next().await {
+    ///     let charge = charge?;
+    /// }
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_get(creds: Auth, id: String) -> Result<Self, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/charges/{}", id.clone());
-        let request = reqwest::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send().await?;
-        let json = request.json::<Self>().await?;
-        return Ok(json);
+    pub fn async_iter(client: &StripeClient, params: ChargeListParams) -> ListStream<Self> {
+        list::stream(client, "/charges", &params)
     }
 
     /// Asynchronously returns all stripe charges.
     ///
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::ChargeListParams with the filters to apply
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Fetch all customers from stripe
-    /// let charges = payup::stripe::Charge::async_list(auth).await?;
-    /// ```
-    pub async fn async_list(creds: Auth) -> Result<Vec<Self>, reqwest::Error>{
-        let mut objects: Vec<Self> = Vec::new();
-
-        let mut has_more = true;
-        let mut starting_after: Option<String> = None;
-        while has_more{
-            let json = Self::list_chunk_async(creds.clone(), starting_after).await?;
-            for json_object in json.data{
-                objects.push(json_object);
-            }
-            has_more = json.has_more;
-            starting_after = Some(objects[objects.len() - 1].id.clone().unwrap());
-        }
-        return Ok(objects);
+    /// let charges = payup::stripe::Charge::async_list(&client, payup::stripe::ChargeListParams::new()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_list(client: &StripeClient, params: ChargeListParams) -> Result<Vec<Self>, StripeError>{
+        let params = ChargeListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::async_iter(client, params).try_collect().await
     }
 
     /// Asynchronously POSTs a new Charge to the stripe api
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// let mut charge = payup::stripe::Charge::new();
     /// charge.amount = Some(100);
     /// charge.currency = Some(format!("usd"));
-    /// charge.customer = Some(format!("cust_"));
+    /// charge.customer = Some(format!("cust_").into());
     /// charge.description = Some(format!("test charge"));
     /// charge.receipt_email = Some(format!("test@test.com"));
     /// charge.source = Some(format!("card_"));
     ///
-    /// charge = charge.async_post(auth.clone()).await?;
+    /// charge = charge.async_post(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_post(&self, client: &StripeClient) ->  Result<Self, StripeError> {
+        client.async_post("/charges", &self.to_params()).await
+    }
+
+    /// Asynchronously refunds the charge in full or in part.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `amount` - Amount to refund in cents, None refunds whatever was not refunded yet.
+    /// * `reason` - duplicate, fraudulent or requested_by_customer.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let charge = payup::stripe::Charge::async_get(&client, format!("ch_")).await?;
+    /// let refund = charge.async_refund(&client, Some(50), Some(format!("requested_by_customer"))).await?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_post(&self, creds: Auth) ->  Result<Self, reqwest::Error> {
-        let request = reqwest::Client::new()
-            .post("https://api.stripe.com/v1/charges")
-            .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-            .form(&self.to_params())
-            .send().await?;
+    pub async fn async_refund(&self, client: &StripeClient, amount: Option<i64>, reason: Option<String>) -> Result<Refund, StripeError> {
+        self.to_refund(amount, reason).async_post(client).await
+    }
 
-        let json = request.json::<Self>().await?;
-        return Ok(json);
+    /// Asynchronously and lazily iterates over the charges matching a search query, e.g. by metadata
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SearchParams holding the query
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Find the charges of an order
+    /// let params = payup::stripe::SearchParams::metadata("order_id", "6735");
+    /// let mut charges = payup::stripe::Charge::async_search(&client, params);
+    /// while let Some(charge) = charges.next().await {
+    ///     let charge = charge?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn async_search(client: &StripeClient, params: SearchParams) -> ListStream<Self> {
+        list::search_stream(client, "/charges/search", &params)
     }
 
     /// Asynchronously POSTs an update to an existing Charge
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// let mut charge = payup::stripe::Charge::new();
     /// charge.amount = Some(100);
     /// charge.currency = Some(format!("usd"));
-    /// charge.customer = Some(format!("cust_"));
+    /// charge.customer = Some(format!("cust_").into());
     /// charge.description = Some(format!("test charge"));
     /// charge.receipt_email = Some(format!("test@test.com"));
     /// charge.source = Some(format!("card_"));
     ///
-    /// charge = charge.async_post(auth.clone()).await?;
+    /// charge = charge.async_post(&client).await?;
     ///
     /// charge.receipt_email = Some(format!("testchanged@test.com"));
-    /// charge = charge.async_update(auth.clone()).await?;
+    /// charge = charge.async_update(&client).await?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_update(&self, creds: Auth) ->  Result<Self, reqwest::Error> {
-        let request = reqwest::Client::new().post(format!("https://api.stripe.com/v1/charges/{}", self.clone().id.unwrap()))
-            .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-            .form(&self.to_params())
-            .send().await?;
-
-        let json = request.json::<Self>().await?;
-        return Ok(json);
+    pub async fn async_update(&self, client: &StripeClient) ->  Result<Self, StripeError> {
+        client.async_post(&format!("/charges/{}", self.clone().id.unwrap()), &self.to_params()).await
     }
 
     /// Capture the payment of an existing, uncaptured, charge. 
@@ -545,694 +697,1047 @@ impl Charge {
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// let mut charge = payup::stripe::Charge::new();
     /// charge.amount = Some(100);
     /// charge.currency = Some(format!("usd"));
-    /// charge.customer = Some(format!("cust_"));
+    /// charge.customer = Some(format!("cust_").into());
     /// charge.description = Some(format!("test charge"));
     /// charge.receipt_email = Some(format!("test@test.com"));
     /// charge.source = Some(format!("card_"));
     ///
-    /// charge = charge.post(auth.clone());
+    /// charge = charge.post(&client)?;
     ///
     /// // Fetch customer using id
-    /// let captured_charge = payup::stripe::Charge::capture(charge, "cust_");
+    /// let captured_charge = charge.capture(&client)?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn capture(&self, creds: Auth) ->  Result<Self, reqwest::Error>{
-        let url = format!("https://api.stripe.com/v1/charges/{}/capture", self.id.clone().unwrap());
+    pub fn capture(&self, client: &StripeClient) ->  Result<Self, StripeError>{
+        let path = format!("/charges/{}/capture", self.id.clone().unwrap());
 
-        let request = reqwest::blocking::Client::new().post(url)
-            .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-            .form(&self.to_capture_params())
-            .send()?;
-    
-        let json = request.json::<Self>()?;
-        return Ok(json);
+        client.post(&path, &self.to_capture_params())
     }
 
     /// Retrieves the details of a charge that has previously been created. 
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
     /// * `id` - The id of the charge you want to retrieve.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Fetch customer using id
-    /// let charge = payup::stripe::Charge::get(auth, "ch_");
+    /// let charge = payup::stripe::Charge::get(&client, format!("ch_"));
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn get(creds: Auth, id: String) -> Result<Self, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/charges/{}", id.clone());
-        let request = reqwest::blocking::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send()?;
-        let json = request.json::<Self>()?;
-        return Ok(json);
+    pub fn get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/charges/{}", id.clone());
+        client.get(&path)
     }
 
-    /// Returns all stripe charges.
-    ///
+    /// Lazily iterates over charges, fetching pages from the stripe api only as they are needed
+    /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::ChargeListParams with the filters, page size and the object to start from
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
-    /// // Fetch all customers from stripe
-    /// let charges = payup::stripe::Charge::list(auth)?;
+    /// // Walk the 50 most recent charges, fetching 25 per request
+    /// let mut params = payup::stripe::ChargeListParams::new();
+    /// params.limit = Some(25);
+    /// for charge in payup::stripe::Charge::iter(&client, params).take(50) {
+    ///     let charge = charge?;
+    /// }
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn list(creds: Auth) -> Result<Vec<Self>, reqwest::Error>{
-        let mut objects: Vec<Self> = Vec::new();
+    pub fn iter(client: &StripeClient, params: ChargeListParams) -> ListIter<Self> {
+        list::iter(client, "/charges", &params)
+    }
 
-        let mut has_more = true;
-        let mut starting_after: Option<String> = None;
-        while has_more{
-            let json = Self::list_chunk(creds.clone(), starting_after)?;
-            for json_object in json.data{
-                objects.push(json_object);
-            }
-            has_more = json.has_more;
-            starting_after = Some(objects[objects.len() - 1].id.clone().unwrap());
-        }
-        return Ok(objects);
+    /// Returns all stripe charges.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::ChargeListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Fetch all charges of a customer made in January 2022
+    /// let mut params = payup::stripe::ChargeListParams::new();
+    /// params.customer = Some(format!("cus_"));
+    /// params.created = Some(payup::stripe::RangeQuery::between(1640995200, 1643673599));
+    /// let charges = payup::stripe::Charge::list(&client, params)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(client: &StripeClient, params: ChargeListParams) -> Result<Vec<Self>, StripeError>{
+        let params = ChargeListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::iter(client, params).collect()
     }
 
     /// POSTs a new Charge to the stripe api
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// let mut charge = payup::stripe::Charge::new();
     /// charge.amount = Some(100);
     /// charge.currency = Some(format!("usd"));
-    /// charge.customer = Some(format!("cust_"));
+    /// charge.customer = Some(format!("cust_").into());
     /// charge.description = Some(format!("test charge"));
     /// charge.receipt_email = Some(format!("test@test.com"));
     /// charge.source = Some(format!("card_"));
     ///
-    /// charge = charge.post(auth.clone())?;
+    /// charge = charge.post(&client)?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn post(&self, creds: Auth) ->  Result<Self, reqwest::Error> {
-        let request = reqwest::blocking::Client::new()
-            .post("https://api.stripe.com/v1/charges")
-            .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-            .form(&self.to_params())
-            .send()?;
+    pub fn post(&self, client: &StripeClient) ->  Result<Self, StripeError> {
+        client.post("/charges", &self.to_params())
+    }
 
-        let json = request.json::<Self>()?;
-        return Ok(json);
+    /// Refunds the charge in full or in part.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `amount` - Amount to refund in cents, None refunds whatever was not refunded yet.
+    /// * `reason` - duplicate, fraudulent or requested_by_customer.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Refund the whole charge
+    /// let charge = payup::stripe::Charge::get(&client, format!("ch_"))?;
+    /// let refund = charge.refund(&client, None, None)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn refund(&self, client: &StripeClient, amount: Option<i64>, reason: Option<String>) -> Result<Refund, StripeError> {
+        self.to_refund(amount, reason).post(client)
     }
 
-    /// POSTs an update to an existing Charge
+    /// Lazily iterates over the charges matching a search query, e.g. by metadata
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SearchParams holding the query
+    ///
     /// # Examples
     ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Find the charges of an order
+    /// let params = payup::stripe::SearchParams::metadata("order_id", "6735");
+    /// for charge in payup::stripe::Charge::search(&client, params) {
+    ///     let charge = charge?;
+    /// }
+    /// # Ok(())
+    /// # }
     /// ```
+    pub fn search(client: &StripeClient, params: SearchParams) -> ListIter<Self> {
+        list::search(client, "/charges/search", &params)
+    }
+
+    /// POSTs an update to an existing Charge
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// let mut charge = payup::stripe::Charge::new();
     /// charge.amount = Some(100);
     /// charge.currency = Some(format!("usd"));
-    /// charge.customer = Some(format!("cust_"));
+    /// charge.customer = Some(format!("cust_").into());
     /// charge.description = Some(format!("test charge"));
     /// charge.receipt_email = Some(format!("test@test.com"));
     /// charge.source = Some(format!("card_"));
     ///
-    /// charge = charge.async_post(auth.clone()).await?;
+    /// charge = charge.post(&client)?;
     ///
     /// charge.receipt_email = Some(format!("testchanged@test.com"));
-    /// charge = charge.update(auth.clone()).await?;
-    /// ```
-    pub fn update(&self, creds: Auth) ->  Result<Self, reqwest::Error> {
-        let request = reqwest::blocking::Client::new().post(format!("https://api.stripe.com/v1/charges/{}", self.clone().id.unwrap()))
-            .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-            .form(&self.to_params())
-            .send()?;
-
-        let json = request.json::<Self>()?;
-        return Ok(json);
-    }
-
-    fn list_chunk(creds: Auth, starting_after: Option<String>) -> Result<Charges, reqwest::Error> {
-        let mut url = "https://api.stripe.com/v1/charges".to_string();
-
-        if starting_after.is_some() {
-            url = format!("https://api.stripe.com/v1/charges?starting_after={}", starting_after.unwrap());
+    /// charge = charge.update(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self, client: &StripeClient) ->  Result<Self, StripeError> {
+        client.post(&format!("/charges/{}", self.clone().id.unwrap()), &self.to_params())
+    }
+    fn to_capture_params(&self) -> ChargeCaptureParams<'_> {
+        ChargeCaptureParams {
+            amount: &self.amount,
+            receipt_email: &self.receipt_email,
+            statement_descriptor: &self.statement_descriptor,
+            statement_descriptor_suffix: &self.statement_descriptor_suffix,
         }
-
-        let request = reqwest::blocking::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send()?;
-
-        let json = request.json::<Charges>()?;
-        return Ok(json);
     }
-
-    async fn list_chunk_async(creds: Auth, starting_after: Option<String>) -> Result<Charges, reqwest::Error> {
-        let mut url = "https://api.stripe.com/v1/charges".to_string();
-
-        if starting_after.is_some() {
-            url = format!("https://api.stripe.com/v1/charges?starting_after={}", starting_after.unwrap());
+    fn to_params(&self) -> ChargeParams<'_> {
+        ChargeParams {
+            amount: &self.amount,
+            currency: &self.currency,
+            customer: self.customer.as_ref().and_then(Expandable::id),
+            description: &self.description,
+            receipt_email: &self.receipt_email,
+            source: &self.source,
+            statement_descriptor: &self.statement_descriptor,
+            statement_descriptor_suffix: &self.statement_descriptor_suffix,
+            metadata: &self.metadata,
         }
-
-        let request = reqwest::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send().await?;
-
-        let json = request.json::<Charges>().await?;
-        return Ok(json);
     }
-    fn to_capture_params(&self) -> Vec<(&str, &str)> {
-        let mut params = vec![];
- 
-        match &self.receipt_email{
-            Some(receipt_email) => params.push(("receipt_email", receipt_email.as_str())),
-            None => {}
-        }
-        match &self.amount{
-            Some(amount) => params.push(("amount", amount.as_str())),
-            None => {}
-        }
-        match &self.statement_descriptor{
-            Some(statement_descriptor) => params.push(("statement_descriptor", statement_descriptor.as_str())),
-            None => {}
+    fn to_refund(&self, amount: Option<i64>, reason: Option<String>) -> Refund {
+        Refund {
+            amount,
+            charge: self.id.clone().map(Expandable::Id),
+            reason,
+            ..Refund::default()
         }
-        match &self.statement_descriptor_suffix{
-            Some(statement_descriptor_suffix) => params.push(("statement_descriptor_suffix", statement_descriptor_suffix.as_str())),
-            None => {}
-        }
-        return params;
     }
-    fn to_params(&self) -> Vec<(&str, &str)> {
-        let mut params = vec![];
-        match &self.customer{
-            Some(customer) => params.push(("customer", customer.as_str())),
-            None => {}
-        }
-        match &self.description{
-            Some(description) => params.push(("description", description.as_str())),
-            None => {}
-        }
-        match &self.receipt_email{
-            Some(receipt_email) => params.push(("receipt_email", receipt_email.as_str())),
-            None => {}
-        }
-        match &self.amount{
-            Some(amount) => params.push(("amount", amount.as_str())),
-            None => {}
-        }
-        match &self.currency{
-            Some(currency) => params.push(("currency", currency.as_str())),
-            None => {}
-        }
-        // TODO - Impliment Shipping
-        // match &self.shipping{
-        //     Some(shipping) => params.push(("shipping", shipping.as_str())),
-        //     None => {}
-        // }
-        match &self.source{
-            Some(source) => params.push(("source", source.as_str())),
-            None => {}
-        }
-        match &self.statement_descriptor{
-            Some(statement_descriptor) => params.push(("statement_descriptor", statement_descriptor.as_str())),
-            None => {}
-        }
-        match &self.statement_descriptor_suffix{
-            Some(statement_descriptor_suffix) => params.push(("statement_descriptor_suffix", statement_descriptor_suffix.as_str())),
-            None => {}
-        }
-        return params;
+
+}
+
+impl Object for Charge {
+    fn object_id(&self) -> Option<&str> {
+        self.id.as_deref()
     }
+}
 
+/// Filters and pagination for listing charges with `Charge::list` and `Charge::iter`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ChargeListParams {
+    /// Only charges created in this range.
+    pub created: Option<RangeQuery>,
+    /// Only charges for this customer id.
+    pub customer: Option<String>,
+    /// Only charges created by this PaymentIntent id.
+    pub payment_intent: Option<String>,
+    /// Only charges in this transfer group.
+    pub transfer_group: Option<String>,
+    /// Number of objects fetched per page, between 1 and 100.
+    pub limit: Option<u32>,
+    /// Starts the list after the object with this id.
+    pub starting_after: Option<String>,
+    /// Starts the list before the object with this id and walks towards newer objects.
+    pub ending_before: Option<String>,
+}
+impl ChargeListParams {
+    pub fn new() -> Self {
+        ChargeListParams::default()
+    }
 }
 
-/// Represents a customer of your business.
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct Customer {
+
+/// A coupon takes a percentage or an amount off of invoices, applied to a customer, subscription or invoice.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Coupon {
+    /// The code of the coupon, Stripe generates one when empty on create.
     pub id: Option<String>,
     pub object: Option<String>,
-    // #[serde(skip_serializing_if = "Option::is_none")]
-    // pub address: Value,
-    pub balance: Option<i64>,
+    /// Amount in cents taken off, requires `currency`.
+    pub amount_off: Option<i64>,
+    /// Limits the coupon to some products.
+    pub applies_to: Option<AppliesTo>,
     pub created: Option<i64>,
     pub currency: Option<String>,
-    #[serde(rename = "default_source")]
-    pub default_source: Option<String>,
-    pub payment_method: Option<String>,
-    pub delinquent: Option<bool>,
-    pub description: Option<String>,
-    // pub discount: Value,
-    pub email: Option<String>,
-    #[serde(rename = "invoice_prefix")]
-    pub invoice_prefix: Option<String>,
-    // #[serde(rename = "invoice_settings")]
-    // pub invoice_settings: InvoiceSettings,
+    /// Set once the coupon was deleted.
+    pub deleted: Option<bool>,
+    /// `forever`, `once` or `repeating`, how long a discount with the coupon lasts.
+    pub duration: Option<String>,
+    /// Number of months a `repeating` coupon lasts.
+    pub duration_in_months: Option<i64>,
     pub livemode: Option<bool>,
-    // pub metadata: Metadata,
+    /// How often the coupon can be redeemed across all customers.
+    pub max_redemptions: Option<i64>,
+    /// Name of the coupon shown to customers, e.g. on invoices.
     pub name: Option<String>,
-    #[serde(rename = "next_invoice_sequence")]
-    pub next_invoice_sequence: Option<i64>,
-    pub phone: Option<String>,
-    // #[serde(rename = "preferred_locales")]
-    // pub preferred_locales: Vec<Value>,
-    // pub shipping: Value,
-    #[serde(rename = "tax_exempt")]
-    pub tax_exempt: Option<String>,
+    /// Percentage taken off, between 0 and 100.
+    pub percent_off: Option<f64>,
+    /// Last time the coupon can be redeemed, as a unix timestamp.
+    pub redeem_by: Option<i64>,
+    pub times_redeemed: Option<i64>,
+    /// Whether the coupon can still be redeemed.
+    pub valid: Option<bool>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    /// Set when Stripe answered with the stored result of an earlier request with the same idempotency key.
+    #[serde(skip_serializing)]
+    pub idempotent_replayed: Option<bool>,
 }
-impl Customer {
-    /// Returns an empty Customer object
+impl Coupon {
+
+    /// Returns an empty Coupon object
     ///
     /// # Examples
     ///
-    /// ```
-    /// let mut cust = payup::stripe::Customer::new();
-    /// cust.name = Some("Rust Test".to_string());
-    /// cust.description = Some("A test customer from rust.".to_string());
-    /// cust.phone = Some("333-333-3333".to_string());
-    /// cust.email = Some("rust@test.com".to_string());
-    /// cust.payment_method = None;
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// let mut coupon = payup::stripe::Coupon::new();
+    /// coupon.percent_off = Some(20.0);
+    /// coupon.duration = Some(format!("forever"));
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn new() -> Self {
-        return Customer{
-            id: None, 
-            object: None, 
-            balance: None, 
-            created: None, 
-            currency: None, 
-            default_source: None,
-            payment_method: None, 
-            delinquent: None, 
-            description: None, 
-            email: None, 
-            invoice_prefix: None, 
-            livemode: None, 
-            name: None, 
-            next_invoice_sequence: None, 
-            phone: None, 
-            tax_exempt: None
-        };
+        Coupon::default()
     }
 
- 
-    /// Asynchronously destroy a stripe Customer
-    /// 
+    /// Asynchronously deletes a coupon, customers and subscriptions it was already applied to keep their discount.
+    ///
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
-    /// * `id` - A string representing an existing stripe customer_id
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the coupon you want to delete.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
-    /// // Fetch customer using id
-    /// let customer = payup::stripe::Customer::async_delete(auth, "cust_").await?;
+    /// let coupon = payup::stripe::Coupon::async_delete(&client, format!("SUMMER25")).await?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_delete(creds: Auth, id: String) -> Result<Self, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/customers/{}", id.clone());
-        
-        let request = reqwest::Client::new().delete(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send().await?;
-
-        let json = request.json::<Self>().await?;
-        return Ok(json);
+    pub async fn async_delete(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/coupons/{}", id.clone());
+        client.async_delete(&path).await
     }
 
-
-    /// Asynchronously lookup a stripe Customer using customer_id
-    /// 
+    /// Asynchronously retrieves a coupon.
+    ///
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
-    /// * `id` - A string representing an existing stripe customer_id
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the coupon you want to retrieve.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
-    /// // Fetch customer using id
-    /// let customer = payup::stripe::Customer::async_get(auth, "cust_").await?;
+    /// let coupon = payup::stripe::Coupon::async_get(&client, format!("SUMMER25")).await?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_get(creds: Auth, id: String) -> Result<Self, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/customers/{}", id.clone());
-        let request = reqwest::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send().await?;
-        let json = request.json::<Self>().await?;
-        return Ok(json);
+    pub async fn async_get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/coupons/{}", id.clone());
+        client.async_get(&path).await
     }
 
-    /// Asynchronously returns all Invoices belonging to the customer_id
-    /// 
+    /// Asynchronously and lazily iterates over coupons, fetching pages from the stripe api only as they are needed
+    ///
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
-    /// * `customer_id` - A string representing an existing stripe customer_id
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::CouponListParams with the filters, page size and the object to start from
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
-    /// let customers_invoices = payup::stripe::Customer::invoices(auth, format!("cust_")).await?;     
-    /// ```    
-    pub async fn async_invoices(creds: Auth, customer_id: String) -> Result<Vec<crate::stripe::response::Invoice>, reqwest::Error>{
-        let mut objects: Vec<crate::stripe::response::Invoice> = Vec::new();
-
-        let mut has_more = true;
-        let mut starting_after: Option<String> = None;
-        while has_more{
-            let json = Self::get_invoices_chunk(creds.clone(), customer_id.clone(), starting_after.clone())?;
-            for json_object in json.data{
-                objects.push(json_object);
-            }
-            has_more = json.has_more;
-            starting_after = Some(objects[objects.len() - 1].id.clone());
-        }
-        return Ok(objects);
+    /// let params = payup::stripe::CouponListParams::new();
+    /// let mut coupons = payup::stripe::Coupon::async_iter(&client, params);
+    /// while let Some(coupon) = coupons.next().await {
+    ///     let coupon = coupon?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn async_iter(client: &StripeClient, params: CouponListParams) -> ListStream<Self> {
+        list::stream(client, "/coupons", &params)
     }
 
-
-    /// Asynchronously returns all stripe customers owned by the account.
-    /// 
+    /// Asynchronously returns all coupons.
+    ///
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::CouponListParams with the filters to apply
     ///
     /// # Examples
     ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let coupons = payup::stripe::Coupon::async_list(&client, payup::stripe::CouponListParams::new()).await?;
+    /// # Ok(())
+    /// # }
     /// ```
+    pub async fn async_list(client: &StripeClient, params: CouponListParams) -> Result<Vec<Self>, StripeError>{
+        let params = CouponListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::async_iter(client, params).try_collect().await
+    }
+
+    /// Asynchronously POSTs a new Coupon to the stripe api
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
-    /// // Fetch all customers from stripe
-    /// let customers = payup::stripe::Customer::async_list(auth).await?;
+    /// // 25% off for the first three months
+    /// let mut coupon = payup::stripe::Coupon::new();
+    /// coupon.id = Some(format!("SUMMER25"));
+    /// coupon.percent_off = Some(25.0);
+    /// coupon.duration = Some(format!("repeating"));
+    /// coupon.duration_in_months = Some(3);
+    /// coupon.max_redemptions = Some(100);
+    /// coupon = coupon.async_post(&client).await?;
+    ///
+    /// // $10 off once, only for the gold plan
+    /// let mut coupon = payup::stripe::Coupon::new();
+    /// coupon.amount_off = Some(1000);
+    /// coupon.currency = Some(format!("usd"));
+    /// coupon.duration = Some(format!("once"));
+    /// coupon.applies_to = Some(payup::stripe::AppliesTo::products(vec![format!("prod_gold")]));
+    /// coupon = coupon.async_post(&client).await?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_list(creds: Auth) -> Result<Vec<Self>, reqwest::Error>{
-        let mut objects: Vec<Self> = Vec::new();
+    pub async fn async_post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.async_post("/coupons", &self.to_create_params()).await
+    }
 
-        let mut has_more = true;
-        let mut starting_after: Option<String> = None;
-        while has_more{
-            let json = Self::list_chunk_async(creds.clone(), starting_after).await?;
-            for json_object in json.data{
-                objects.push(json_object);
-            }
-            has_more = json.has_more;
-            starting_after = Some(objects[objects.len() - 1].id.clone().unwrap());
-        }
-        return Ok(objects);
+    /// Asynchronously POSTs an update to an existing Coupon, only its name and metadata can be changed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut coupon = payup::stripe::Coupon::async_get(&client, format!("SUMMER25")).await?;
+    /// coupon.name = Some(format!("Summer sale"));
+    /// coupon = coupon.async_update(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_update(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.async_post(&format!("/coupons/{}", self.clone().id.unwrap()), &self.to_params()).await
     }
 
-    /// Asynchronously returns all PaymentMethods belonging to the customer_id
-    /// 
+    /// Deletes a coupon, customers and subscriptions it was already applied to keep their discount.
+    ///
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
-    /// * `customer_id` - A string representing an existing stripe customer_id
-    /// * `method_type` - A string representing the type of payment method (acss_debit, afterpay_clearpay, alipay, au_becs_debit, bacs_debit, bancontact, boleto, card, eps, fpx, giropay, grabpay, ideal, klarna, oxxo, p24, sepa_debit, sofort, wechat_pay)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the coupon you want to delete.
     ///
     /// # Examples
     ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let coupon = payup::stripe::Coupon::delete(&client, format!("SUMMER25"))?;
+    /// # Ok(())
+    /// # }
     /// ```
+    pub fn delete(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/coupons/{}", id.clone());
+        client.delete(&path)
+    }
+
+    /// Retrieves a coupon.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the coupon you want to retrieve.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
-    /// let customers_payment_methods = payup::stripe::Customer::async_payment_methods(auth, format!("cust_"), format!("card")).await?;     
+    /// let coupon = payup::stripe::Coupon::get(&client, format!("SUMMER25"))?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_payment_methods(creds: Auth, customer_id: String, method_type: String) -> Result<Vec<crate::stripe::response::PaymentMethod>, reqwest::Error>{
-        let mut objects: Vec<crate::stripe::response::PaymentMethod> = Vec::new();
+    pub fn get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/coupons/{}", id.clone());
+        client.get(&path)
+    }
 
-        let mut has_more = true;
-        let mut starting_after: Option<String> = None;
-        while has_more{
-            let json = Self::get_payment_methods_chunk_async(creds.clone(), customer_id.clone(), method_type.clone(), starting_after.clone()).await?;
-            for json_object in json.data{
-                objects.push(json_object);
-            }
-            has_more = json.has_more;
-            starting_after = Some(objects[objects.len() - 1].id.clone());
-        }
-        return Ok(objects);
+    /// Lazily iterates over coupons, fetching pages from the stripe api only as they are needed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::CouponListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// for coupon in payup::stripe::Coupon::iter(&client, payup::stripe::CouponListParams::new()) {
+    ///     let coupon = coupon?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter(client: &StripeClient, params: CouponListParams) -> ListIter<Self> {
+        list::iter(client, "/coupons", &params)
     }
 
+    /// Returns all coupons.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::CouponListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let coupons = payup::stripe::Coupon::list(&client, payup::stripe::CouponListParams::new())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(client: &StripeClient, params: CouponListParams) -> Result<Vec<Self>, StripeError>{
+        let params = CouponListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::iter(client, params).collect()
+    }
 
-    /// Asynchronously POSTs a new customer to the stripe api
-    /// 
+    /// POSTs a new Coupon to the stripe api
+    ///
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
     ///
     /// # Examples
     ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // 25% off for the first three months
+    /// let mut coupon = payup::stripe::Coupon::new();
+    /// coupon.id = Some(format!("SUMMER25"));
+    /// coupon.percent_off = Some(25.0);
+    /// coupon.duration = Some(format!("repeating"));
+    /// coupon.duration_in_months = Some(3);
+    /// coupon.max_redemptions = Some(100);
+    /// coupon = coupon.post(&client)?;
+    ///
+    /// // $10 off once, only for the gold plan
+    /// let mut coupon = payup::stripe::Coupon::new();
+    /// coupon.amount_off = Some(1000);
+    /// coupon.currency = Some(format!("usd"));
+    /// coupon.duration = Some(format!("once"));
+    /// coupon.applies_to = Some(payup::stripe::AppliesTo::products(vec![format!("prod_gold")]));
+    /// coupon = coupon.post(&client)?;
+    /// # Ok(())
+    /// # }
     /// ```
+    pub fn post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.post("/coupons", &self.to_create_params())
+    }
+
+    /// POSTs an update to an existing Coupon, only its name and metadata can be changed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut coupon = payup::stripe::Coupon::get(&client, format!("SUMMER25"))?;
+    /// coupon.name = Some(format!("Summer sale"));
+    /// coupon = coupon.update(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.post(&format!("/coupons/{}", self.clone().id.unwrap()), &self.to_params())
+    }
+    fn to_create_params(&self) -> CouponCreateParams<'_> {
+        CouponCreateParams {
+            params: self.to_params(),
+            id: &self.id,
+            amount_off: &self.amount_off,
+            applies_to: &self.applies_to,
+            currency: &self.currency,
+            duration: &self.duration,
+            duration_in_months: &self.duration_in_months,
+            max_redemptions: &self.max_redemptions,
+            percent_off: &self.percent_off,
+            redeem_by: &self.redeem_by,
+        }
+    }
+    fn to_params(&self) -> CouponParams<'_> {
+        CouponParams {
+            name: &self.name,
+            metadata: &self.metadata,
+        }
+    }
+
+}
+
+impl Object for Coupon {
+    fn object_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+}
+
+/// Filters and pagination for listing coupons with `Coupon::list` and `Coupon::iter`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CouponListParams {
+    /// Only coupons created in this range.
+    pub created: Option<RangeQuery>,
+    /// Number of objects fetched per page, between 1 and 100.
+    pub limit: Option<u32>,
+    /// Starts the list after the object with this id.
+    pub starting_after: Option<String>,
+    /// Starts the list before the object with this id and walks towards newer objects.
+    pub ending_before: Option<String>,
+}
+impl CouponListParams {
+    pub fn new() -> Self {
+        CouponListParams::default()
+    }
+}
+
+/// The products a coupon is limited to.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct AppliesTo {
+    /// Ids of the products the coupon applies to.
+    #[serde(default)]
+    pub products: Vec<String>,
+}
+impl AppliesTo {
+    /// Limits a coupon to the given product ids.
+    pub fn products(products: Vec<String>) -> Self {
+        AppliesTo { products }
+    }
+}
+
+/// A coupon or promotion code applied to a customer, subscription or invoice.
+///
+/// Set `coupon` or `promotion_code` on the object to apply one, and remove it with `delete_discount`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Discount {
+    pub id: Option<String>,
+    pub object: Option<String>,
+    pub coupon: Option<Coupon>,
+    pub customer: Option<String>,
+    /// Set once the discount was removed.
+    pub deleted: Option<bool>,
+    /// When the discount ends as a unix timestamp, empty for coupons that last forever.
+    pub end: Option<i64>,
+    pub invoice: Option<String>,
+    pub invoice_item: Option<String>,
+    pub promotion_code: Option<String>,
+    pub start: Option<i64>,
+    pub subscription: Option<String>,
+}
+
+
+/// Represents a customer of your business.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Customer {
+    pub id: Option<String>,
+    pub object: Option<String>,
+    // #[serde(skip_serializing_if = "Option::is_none")]
+    // pub address: Value,
+    pub balance: Option<i64>,
+    /// Id of a coupon to apply to the customer. Only sent when creating or updating.
+    pub coupon: Option<String>,
+    pub created: Option<i64>,
+    pub currency: Option<String>,
+    #[serde(rename = "default_source")]
+    pub default_source: Option<String>,
+    pub payment_method: Option<String>,
+    pub delinquent: Option<bool>,
+    pub description: Option<String>,
+    pub discount: Option<Discount>,
+    pub email: Option<String>,
+    #[serde(rename = "invoice_prefix")]
+    pub invoice_prefix: Option<String>,
+    // #[serde(rename = "invoice_settings")]
+    // pub invoice_settings: InvoiceSettings,
+    pub livemode: Option<bool>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    pub name: Option<String>,
+    #[serde(rename = "next_invoice_sequence")]
+    pub next_invoice_sequence: Option<i64>,
+    pub phone: Option<String>,
+    /// Id of a promotion code to apply to the customer. Only sent when creating or updating.
+    pub promotion_code: Option<String>,
+    // #[serde(rename = "preferred_locales")]
+    // pub preferred_locales: Vec<Value>,
+    // pub shipping: Value,
+    #[serde(rename = "tax_exempt")]
+    pub tax_exempt: Option<String>,
+    /// Set when Stripe answered with the stored result of an earlier request with the same idempotency key.
+    #[serde(skip_serializing)]
+    pub idempotent_replayed: Option<bool>,
+}
+impl Customer {
+    /// Returns an empty Customer object
     ///
-    /// // Build a customer object
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
     /// let mut cust = payup::stripe::Customer::new();
     /// cust.name = Some("Rust Test".to_string());
     /// cust.description = Some("A test customer from rust.".to_string());
     /// cust.phone = Some("333-333-3333".to_string());
     /// cust.email = Some("rust@test.com".to_string());
     /// cust.payment_method = None;
-    /// 
-    /// // Post customer to stripe and update the local cust variable
-    /// let customer = cust.async_post(auth).await?;
-    /// ```
-    pub async fn async_post(&self, creds: Auth) ->  Result<Self, reqwest::Error> {
-        let request = reqwest::Client::new()
-        .post("https://api.stripe.com/v1/customers")
-        .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-        .form(&self.to_params())
-        .send().await;
-        match request{
-            Ok(req) => {
-                let json = req.json::<Self>().await?;
-            
-                return Ok(json);
-            },
-            Err(err) => Err(err)
-        }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new() -> Self {
+        Customer::default()
     }
 
-    /// Asynchronously POSTs updates to an existing stripe Customer
+ 
+    /// Asynchronously destroy a stripe Customer
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - A string representing an existing stripe customer_id
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
-    /// // Build a customer object
-    /// let mut customer = payup::stripe::Customer::new();
-    /// customer.name = Some("Rust Test".to_string());
-    /// customer.description = Some("A test customer from rust.".to_string());
-    /// customer.phone = Some("333-333-3333".to_string());
-    /// customer.email = Some("rust@test.com".to_string());
-    /// customer.payment_method = None;
-    /// 
-    /// // Post customer to stripe and update the local cust variable
-    /// customer = cust.async_post(auth).await?;
+    /// // Fetch customer using id
+    /// let customer = payup::stripe::Customer::async_delete(&client, format!("cust_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_delete(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/customers/{}", id.clone());
+        
+        client.async_delete(&path).await
+    }
+
+
+    /// Asynchronously removes the discount of a customer, e.g. to end a coupon that lasts forever.
     ///
-    /// // Makes changes
-    /// customer.email = Some("RustNewEmail@test.com".to_string());
+    /// # Arguments
     ///
-    /// // Update customer
-    /// customer = cust.async_update(auth).await?;
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the customer whose discount you want to remove.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let discount = payup::stripe::Customer::async_delete_discount(&client, format!("cus_")).await?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_update(&self, creds: Auth) ->  Result<Self, reqwest::Error> {
-        let request = reqwest::Client::new().post(format!("https://api.stripe.com/v1/customers/{}", self.clone().id.unwrap()))
-            .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-            .form(&self.to_params())
-            .send().await?;
-
-        let json = request.json::<Self>().await?;
-        return Ok(json);
+    pub async fn async_delete_discount(client: &StripeClient, id: String) -> Result<Discount, StripeError> {
+        let path = format!("/customers/{}/discount", id.clone());
+        client.async_delete(&path).await
     }
 
-    /// Destroy a stripe Customer
+    /// Asynchronously lookup a stripe Customer using customer_id
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
     /// * `id` - A string representing an existing stripe customer_id
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Fetch customer using id
-    /// let customer = payup::stripe::Customer::async_delete(auth, "cust_").await?;
+    /// let customer = payup::stripe::Customer::async_get(&client, format!("cust_")).await?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn delete(creds: Auth, id: String) -> Result<Self, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/customers/{}", id.clone());
-        
-        let request = reqwest::blocking::Client::new().delete(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send()?;
-
-        let json = request.json::<Self>()?;
-        return Ok(json);
+    pub async fn async_get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/customers/{}", id.clone());
+        client.async_get(&path).await
     }
 
- 
-    /// Lookup a stripe Customer using customer_id
+    /// Asynchronously returns all Invoices belonging to the customer_id
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
-    /// * `id` - A string representing an existing stripe customer_id
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `customer_id` - A string representing an existing stripe customer_id
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
-    /// // Fetch customer using id
-    /// let customer = payup::stripe::Customer::get(auth, "cust_")?;
+    /// let customers_invoices = payup::stripe::Customer::async_invoices(&client, format!("cust_")).await?;     
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn get(auth: Auth, id: String) -> Result<Self, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/customers/{}", id.clone());
-        let request = reqwest::blocking::Client::new().get(url).basic_auth(auth.client.as_str(), Some(auth.secret.as_str())).send()?;
-        let json = request.json::<Self>()?;
-        return Ok(json);
+    pub async fn async_invoices(client: &StripeClient, customer_id: String) -> Result<Vec<crate::stripe::response::Invoice>, StripeError>{
+        let params = InvoiceListParams {
+            customer: Some(customer_id),
+            limit: Some(100),
+            ..InvoiceListParams::default()
+        };
+        list::stream(client, "/invoices", &params).try_collect().await
     }
 
-    /// Returns all Invoices belonging to the customer_id
+
+    /// Asynchronously and lazily iterates over customers, fetching pages from the stripe api only as they are needed
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
-    /// * `id` - A string representing an existing stripe customer_id
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::CustomerListParams with the filters, page size and the object to start from
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
-    /// let customers_invoices = payup::stripe::Customer::invoices(auth, format!("cust_"))?;     
-    /// ```    
-    pub fn invoices(creds: Auth, customer_id: String) -> Result<Vec<crate::stripe::response::Invoice>, reqwest::Error>{
-        let mut objects: Vec<crate::stripe::response::Invoice> = Vec::new();
-
-        let mut has_more = true;
-        let mut starting_after: Option<String> = None;
-        while has_more{
-            let json = Self::get_invoices_chunk(creds.clone(), customer_id.clone(), starting_after.clone())?;
-            for json_object in json.data{
-                objects.push(json_object);
-            }
-            has_more = json.has_more;
-            starting_after = Some(objects[objects.len() - 1].id.clone());
-        }
-        return Ok(objects);
+    /// // Walk the 50 most recent customers, fetching 25 per request
+    /// let mut params = payup::stripe::CustomerListParams::new();
+    /// params.limit = Some(25);
+    /// let mut customers = payup::stripe::Customer::async_iter(&client, params).take(50);
+    /// while let Some(customer) = customers.next().await {
+    ///     let customer = customer?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn async_iter(client: &StripeClient, params: CustomerListParams) -> ListStream<Self> {
+        list::stream(client, "/customers", &params)
     }
 
-
-    /// Returns all stripe customers
+    /// Asynchronously returns all stripe customers owned by the account.
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::CustomerListParams with the filters to apply
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Fetch all customers from stripe
-    /// let customers = payup::stripe::Customer::list(auth.clone())?;
-    /// ```
-    pub fn list(creds: Auth) -> Result<Vec<Self>, reqwest::Error>{
-        let mut objects: Vec<Self> = Vec::new();
-
-        let mut has_more = true;
-        let mut starting_after: Option<String> = None;
-        while has_more{
-            let json = Self::list_chunk(creds.clone(), starting_after)?;
-            for json_object in json.data{
-                objects.push(json_object);
-            }
-            has_more = json.has_more;
-            starting_after = Some(objects[objects.len() - 1].id.clone().unwrap());
-        }
-        return Ok(objects);
+    /// let customers = payup::stripe::Customer::async_list(&client, payup::stripe::CustomerListParams::new()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_list(client: &StripeClient, params: CustomerListParams) -> Result<Vec<Self>, StripeError>{
+        let params = CustomerListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::async_iter(client, params).try_collect().await
     }
 
-    
-
-
-
- 
-
-   
-    /// Returns all PaymentMethods belonging to the customer_id
+    /// Asynchronously returns all PaymentMethods belonging to the customer_id
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
     /// * `customer_id` - A string representing an existing stripe customer_id
     /// * `method_type` - A string representing the type of payment method (acss_debit, afterpay_clearpay, alipay, au_becs_debit, bacs_debit, bancontact, boleto, card, eps, fpx, giropay, grabpay, ideal, klarna, oxxo, p24, sepa_debit, sofort, wechat_pay)
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
-    /// let customers_payment_methods = payup::stripe::Customer::payment_methods(auth, format!("cust_"), format!("card"))?;     
+    /// let customers_payment_methods = payup::stripe::Customer::async_payment_methods(&client, format!("cust_"), format!("card")).await?;     
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn payment_methods(creds: Auth, customer_id: String, method_type: String) -> Result<Vec<crate::stripe::response::PaymentMethod>, reqwest::Error>{
-        let mut objects: Vec<crate::stripe::response::PaymentMethod> = Vec::new();
-
-        let mut has_more = true;
-        let mut starting_after: Option<String> = None;
-        while has_more{
-            let json = Self::get_payment_methods_chunk(creds.clone(), customer_id.clone(), method_type.clone(), starting_after.clone())?;
-            for json_object in json.data{
-                objects.push(json_object);
-            }
-            has_more = json.has_more;
-            starting_after = Some(objects[objects.len() - 1].id.clone());
-        }
-        return Ok(objects);
+    pub async fn async_payment_methods(client: &StripeClient, customer_id: String, method_type: String) -> Result<Vec<crate::stripe::response::PaymentMethod>, StripeError>{
+        let path = format!("/customers/{}/payment_methods", customer_id);
+        let params = serde_json::json!({
+            "type": method_type,
+            "limit": 100,
+        });
+        list::stream(client, &path, &params).try_collect().await
     }
 
-    /// POSTs a new customer to the stripe api
+
+    /// Asynchronously POSTs a new customer to the stripe api
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Build a customer object
     /// let mut cust = payup::stripe::Customer::new();
@@ -1243,166 +1748,446 @@ impl Customer {
     /// cust.payment_method = None;
     /// 
     /// // Post customer to stripe and update the local cust variable
-    /// let customer = cust.post(auth).unwrap();
-    /// ```
-    pub fn post(&self, creds: Auth) ->  Result<Self, reqwest::Error> {
-        let request = reqwest::blocking::Client::new().post("https://api.stripe.com/v1/customers")
-        .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-        .form(&self.to_params())
-        .send();
-
-        match request{
-            Ok(req) => {
-                let json = req.json::<Self>()?;
-                Ok(json)
-            },
-            Err(err) => Err(err)
-        }
+    /// let customer = cust.async_post(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_post(&self, client: &StripeClient) ->  Result<Self, StripeError> {
+        client.async_post("/customers", &self.to_params()).await
     }
 
- 
-    /// POSTs updates to an existing stripe Customer
+    /// Asynchronously and lazily iterates over the customers matching a search query, e.g. by metadata
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SearchParams holding the query
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
-    /// // Build a customer object
-    /// let mut customer = payup::stripe::Customer::new();
-    /// customer.name = Some("Rust Test".to_string());
-    /// customer.description = Some("A test customer from rust.".to_string());
-    /// customer.phone = Some("333-333-3333".to_string());
-    /// customer.email = Some("rust@test.com".to_string());
-    /// customer.payment_method = None;
-    /// 
-    /// // Post customer to stripe and update the local cust variable
-    /// customer = cust.async_post(auth)?;
+    /// // Find the customers of an order
+    /// let params = payup::stripe::SearchParams::metadata("order_id", "6735");
+    /// let mut customers = payup::stripe::Customer::async_search(&client, params);
+    /// while let Some(customer) = customers.next().await {
+    ///     let customer = customer?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn async_search(client: &StripeClient, params: SearchParams) -> ListStream<Self> {
+        list::search_stream(client, "/customers/search", &params)
+    }
+
+    /// Asynchronously POSTs updates to an existing stripe Customer
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Build a customer object
+    /// let mut customer = payup::stripe::Customer::new();
+    /// customer.name = Some("Rust Test".to_string());
+    /// customer.description = Some("A test customer from rust.".to_string());
+    /// customer.phone = Some("333-333-3333".to_string());
+    /// customer.email = Some("rust@test.com".to_string());
+    /// customer.payment_method = None;
+    /// 
+    /// // Post customer to stripe and update the local cust variable
+    /// customer = customer.async_post(&client).await?;
     ///
     /// // Makes changes
     /// customer.email = Some("RustNewEmail@test.com".to_string());
     ///
+    /// // Tag the customer with an order and unset a key by sending it empty
+    /// customer.metadata.insert(format!("order_id"), format!("6735"));
+    /// customer.metadata.insert(format!("legacy_id"), String::new());
+    ///
     /// // Update customer
-    /// customer = cust.update(auth)?;
+    /// customer = customer.async_update(&client).await?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn update(&self, creds: Auth) ->  Result<Self, reqwest::Error> {
-        let request = reqwest::blocking::Client::new().post(format!("https://api.stripe.com/v1/customers/{}", self.clone().id.unwrap()))
-            .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-            .form(&self.to_params())
-            .send()?;
-
-        let json = request.json::<Self>()?;
-        return Ok(json);
+    pub async fn async_update(&self, client: &StripeClient) ->  Result<Self, StripeError> {
+        client.async_post(&format!("/customers/{}", self.clone().id.unwrap()), &self.to_params()).await
     }
 
-    fn get_invoices_chunk(creds: Auth, customer_id: String, starting_after: Option<String>) ->  Result<crate::stripe::response::Invoices, reqwest::Error>{  
-        let mut url = format!("https://api.stripe.com/v1/invoices?customer={}", customer_id);
-
-        if starting_after.is_some() {
-            url = format!("https://api.stripe.com/v1/invoices?customer={}&starting_after={}", customer_id, starting_after.unwrap())
-        }
+    /// Destroy a stripe Customer
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - A string representing an existing stripe customer_id
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Fetch customer using id
+    /// let customer = payup::stripe::Customer::delete(&client, format!("cust_"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/customers/{}", id.clone());
+        
+        client.delete(&path)
+    }
 
-        let request = reqwest::blocking::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send()?;
-  
-        let json = request.json::<crate::stripe::response::Invoices>()?;
-        return Ok(json);
+ 
+    /// Removes the discount of a customer, e.g. to end a coupon that lasts forever.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the customer whose discount you want to remove.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let discount = payup::stripe::Customer::delete_discount(&client, format!("cus_"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_discount(client: &StripeClient, id: String) -> Result<Discount, StripeError> {
+        let path = format!("/customers/{}/discount", id.clone());
+        client.delete(&path)
     }
 
-    fn list_chunk(creds: Auth, starting_after: Option<String>) -> Result<Customers, reqwest::Error> {
-        let mut url = "https://api.stripe.com/v1/customers".to_string();
+    /// Lookup a stripe Customer using customer_id
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - A string representing an existing stripe customer_id
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Fetch customer using id
+    /// let customer = payup::stripe::Customer::get(&client, format!("cust_"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/customers/{}", id.clone());
+        client.get(&path)
+    }
 
-        if starting_after.is_some() {
-            url = format!("https://api.stripe.com/v1/customers?starting_after={}", starting_after.unwrap());
-        }
+    /// Returns all Invoices belonging to the customer_id
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - A string representing an existing stripe customer_id
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let customers_invoices = payup::stripe::Customer::invoices(&client, format!("cust_"))?;     
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn invoices(client: &StripeClient, customer_id: String) -> Result<Vec<crate::stripe::response::Invoice>, StripeError>{
+        let params = InvoiceListParams {
+            customer: Some(customer_id),
+            limit: Some(100),
+            ..InvoiceListParams::default()
+        };
+        list::iter(client, "/invoices", &params).collect()
+    }
 
-        let request = reqwest::blocking::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send()?;
 
-        let json = request.json::<Customers>()?;
-        return Ok(json);
+    /// Lazily iterates over customers, fetching pages from the stripe api only as they are needed
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::CustomerListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Walk the 50 most recent customers, fetching 25 per request
+    /// let mut params = payup::stripe::CustomerListParams::new();
+    /// params.limit = Some(25);
+    /// for customer in payup::stripe::Customer::iter(&client, params).take(50) {
+    ///     let customer = customer?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter(client: &StripeClient, params: CustomerListParams) -> ListIter<Self> {
+        list::iter(client, "/customers", &params)
     }
 
-    async fn list_chunk_async(creds: Auth, starting_after: Option<String>) -> Result<Customers, reqwest::Error> {
-        let mut url = "https://api.stripe.com/v1/customers".to_string();
+    /// Returns all stripe customers
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::CustomerListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Fetch all customers from stripe
+    /// let customers = payup::stripe::Customer::list(&client, payup::stripe::CustomerListParams::new())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(client: &StripeClient, params: CustomerListParams) -> Result<Vec<Self>, StripeError>{
+        let params = CustomerListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::iter(client, params).collect()
+    }
 
-        if starting_after.is_some() {
-            url = format!("https://api.stripe.com/v1/customers?starting_after={}", starting_after.unwrap());
-        }
+    
 
-        let request = reqwest::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send().await?;
- 
-        let json = request.json::<Customers>().await?;
-        return Ok(json);
-    }
 
-    fn get_payment_methods_chunk(creds: Auth, customer_id: String, method_type: String, starting_after: Option<String>) ->  Result<crate::stripe::response::PaymentMethods, reqwest::Error>{
-        let mut url = format!("https://api.stripe.com/v1/customers/{}/payment_methods?type={}", customer_id, method_type);
 
-        if starting_after.is_some() {
-            url = format!("https://api.stripe.com/v1/customers/{}/payment_methods?type={}&starting_after={}", customer_id, method_type, starting_after.unwrap());
-        }
+ 
 
-        let request = reqwest::blocking::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send()?;
    
-        let json = request.json::<crate::stripe::response::PaymentMethods>()?;
-        return Ok(json);
+    /// Returns all PaymentMethods belonging to the customer_id
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `customer_id` - A string representing an existing stripe customer_id
+    /// * `method_type` - A string representing the type of payment method (acss_debit, afterpay_clearpay, alipay, au_becs_debit, bacs_debit, bancontact, boleto, card, eps, fpx, giropay, grabpay, ideal, klarna, oxxo, p24, sepa_debit, sofort, wechat_pay)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let customers_payment_methods = payup::stripe::Customer::payment_methods(&client, format!("cust_"), format!("card"))?;     
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn payment_methods(client: &StripeClient, customer_id: String, method_type: String) -> Result<Vec<crate::stripe::response::PaymentMethod>, StripeError>{
+        let path = format!("/customers/{}/payment_methods", customer_id);
+        let params = serde_json::json!({
+            "type": method_type,
+            "limit": 100,
+        });
+        list::iter(client, &path, &params).collect()
+    }
+
+    /// POSTs a new customer to the stripe api
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Build a customer object
+    /// let mut cust = payup::stripe::Customer::new();
+    /// cust.name = Some("Rust Test".to_string());
+    /// cust.description = Some("A test customer from rust.".to_string());
+    /// cust.phone = Some("333-333-3333".to_string());
+    /// cust.email = Some("rust@test.com".to_string());
+    /// cust.payment_method = None;
+    /// 
+    /// // Post customer to stripe and update the local cust variable
+    /// let customer = cust.post(&client).unwrap();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn post(&self, client: &StripeClient) ->  Result<Self, StripeError> {
+        client.post("/customers", &self.to_params())
     }
 
-    async fn get_payment_methods_chunk_async(creds: Auth, customer_id: String, method_type: String, starting_after: Option<String>) ->  Result<crate::stripe::response::PaymentMethods, reqwest::Error>{
-        let mut url = format!("https://api.stripe.com/v1/customers/{}/payment_methods?type={}", customer_id, method_type);
+ 
+    /// Lazily iterates over the customers matching a search query, e.g. by metadata
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SearchParams holding the query
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Find the customers of an order
+    /// let params = payup::stripe::SearchParams::metadata("order_id", "6735");
+    /// for customer in payup::stripe::Customer::search(&client, params) {
+    ///     let customer = customer?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search(client: &StripeClient, params: SearchParams) -> ListIter<Self> {
+        list::search(client, "/customers/search", &params)
+    }
 
-        if starting_after.is_some() {
-            url = format!("https://api.stripe.com/v1/customers/{}/payment_methods?type={}&starting_after={}", customer_id, method_type, starting_after.unwrap());
+    /// POSTs updates to an existing stripe Customer
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Build a customer object
+    /// let mut customer = payup::stripe::Customer::new();
+    /// customer.name = Some("Rust Test".to_string());
+    /// customer.description = Some("A test customer from rust.".to_string());
+    /// customer.phone = Some("333-333-3333".to_string());
+    /// customer.email = Some("rust@test.com".to_string());
+    /// customer.payment_method = None;
+    /// 
+    /// // Post customer to stripe and update the local cust variable
+    /// customer = customer.post(&client)?;
+    ///
+    /// // Makes changes
+    /// customer.email = Some("RustNewEmail@test.com".to_string());
+    ///
+    /// // Tag the customer with an order and unset a key by sending it empty
+    /// customer.metadata.insert(format!("order_id"), format!("6735"));
+    /// customer.metadata.insert(format!("legacy_id"), String::new());
+    ///
+    /// // Update customer
+    /// customer = customer.update(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self, client: &StripeClient) ->  Result<Self, StripeError> {
+        client.post(&format!("/customers/{}", self.clone().id.unwrap()), &self.to_params())
+    }
+
+    fn to_params(&self) -> CustomerParams<'_> {
+        CustomerParams {
+            coupon: &self.coupon,
+            description: &self.description,
+            email: &self.email,
+            name: &self.name,
+            payment_method: &self.payment_method,
+            phone: &self.phone,
+            promotion_code: &self.promotion_code,
+            metadata: &self.metadata,
         }
+    }
+}
 
-        let request = reqwest::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send().await?;
-     
-        let json = request.json::<crate::stripe::response::PaymentMethods>().await?;
-        return Ok(json);
+impl Object for Customer {
+    fn object_id(&self) -> Option<&str> {
+        self.id.as_deref()
     }
+}
 
-    fn to_params(&self) -> Vec<(&str, &str)> {
-        // return Customer{client, secret};
-        let mut params = vec![];
-        match &self.payment_method{
-            Some(payment_method) => params.push(("payment_method", payment_method.as_str())),
-            None => {}
-        }
-        match &self.description{
-            Some(description) => params.push(("description", description.as_str())),
-            None => {}
-        }
-        match &self.email{
-            Some(email) => params.push(("email", email.as_str())),
-            None => {}
-        }
-        match &self.name{
-            Some(name) => params.push(("name", name.as_str())),
-            None => {}
-        }
-        match &self.phone{
-            Some(phone) => params.push(("phone", phone.as_str())),
-            None => {}
-        }
-        return params;
+/// Filters and pagination for listing customers with `Customer::list` and `Customer::iter`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CustomerListParams {
+    /// Only customers created in this range.
+    pub created: Option<RangeQuery>,
+    /// Only customers with this exact, case-sensitive email.
+    pub email: Option<String>,
+    /// Number of objects fetched per page, between 1 and 100.
+    pub limit: Option<u32>,
+    /// Starts the list after the object with this id.
+    pub starting_after: Option<String>,
+    /// Starts the list before the object with this id and walks towards newer objects.
+    pub ending_before: Option<String>,
+}
+impl CustomerListParams {
+    pub fn new() -> Self {
+        CustomerListParams::default()
     }
 }
 
 
 
+
 /// Represents a charge to a credit or a debit card.
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct Dispute {
     pub id: Option<String>,
     pub object: Option<String>,
     pub amount: Option<i64>,
     // #[serde(rename = "balance_transactions")]
     // pub balance_transactions: Vec<BalanceTransaction>,
-    pub charge: Option<String>,
+    pub charge: Option<Expandable<Charge>>,
     pub created: Option<i64>,
     pub currency: Option<String>,
     pub evidence: Option<Evidence>,
@@ -1412,11 +2197,15 @@ pub struct Dispute {
     pub is_charge_refundable: Option<bool>,
     pub livemode: Option<bool>,
     pub submit: Option<bool>,
-    // pub metadata: Metadata,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
     #[serde(rename = "payment_intent")]
-    pub payment_intent: Option<String>,
+    pub payment_intent: Option<Expandable<PaymentIntent>>,
     pub reason: Option<String>,
     pub status: Option<String>,
+    /// Set when Stripe answered with the stored result of an earlier request with the same idempotency key.
+    #[serde(skip_serializing)]
+    pub idempotent_replayed: Option<bool>,
 }
 impl Dispute {
 
@@ -1424,51 +2213,37 @@ impl Dispute {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
     /// let mut dispute = payup::stripe::Dispute::new();
     /// dispute.amount = Some(100);
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn new() -> Self {
-        return Dispute{
-            id: None, 
-            object: None,
-            amount: None, 
-            charge: None,
-            created: None,
-            currency: None,
-            // balance_transactions: None,
-            // captured: None,
-            evidence: None, 
-            evidence_details: None,
-            is_charge_refundable: None,
-            livemode: None,
-            submit: None,
-            payment_intent: None,
-            reason: None,
-            status: None
-        };
+        Dispute::default()
     }
 
     /// Asynchronously close a dispute.
     /// Closing the dispute for a charge indicates that you do not have any evidence to submit and are essentially dismissing the dispute, acknowledging it as lost.
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// let mut dispute = payup::stripe::Dispute::new();
     /// dispute.id = Some(format!("dp_"));
     ///
-    /// dispute = dispute.async_close(auth.clone()).await?;
+    /// dispute = dispute.async_close(&client).await?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_close(&self, creds: Auth) ->  Result<Self, reqwest::Error> {
-        let request = reqwest::Client::new().post(format!("https://api.stripe.com/v1/disputes/{}/close", self.clone().id.unwrap()))
-            .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-            .send().await?;
-
-        let json = request.json::<Self>().await?;
-        return Ok(json);
+    pub async fn async_close(&self, client: &StripeClient) ->  Result<Self, StripeError> {
+        client.async_post(&format!("/disputes/{}/close", self.clone().id.unwrap()), &()).await
     }
 
 
@@ -1477,62 +2252,98 @@ impl Dispute {
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
     /// * `id` - The id of the dispute you want to retrieve.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Fetch customer using id
-    /// let dispute = payup::stripe::Dispute::async_get(auth, "ch_").await?;
+    /// let dispute = payup::stripe::Dispute::async_get(&client, format!("ch_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/disputes/{}", id.clone());
+        client.async_get(&path).await
+    }
+
+    /// Asynchronously and lazily iterates over disputes, fetching pages from the stripe api only as they are needed
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::DisputeListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Walk the 50 most recent disputes, fetching 25 per request
+    /// let mut params = payup::stripe::DisputeListParams::new();
+    /// params.limit = Some(25);
+    /// let mut disputes = payup::stripe::Dispute::async_iter(&client, params).take(50);
+    /// while let Some(dispute) = disputes.next().await {
+    ///     let dispute = dispute?;
+    /// }
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_get(creds: Auth, id: String) -> Result<Self, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/disputes/{}", id.clone());
-        let request = reqwest::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send().await?;
-        let json = request.json::<Self>().await?;
-        return Ok(json);
+    pub fn async_iter(client: &StripeClient, params: DisputeListParams) -> ListStream<Self> {
+        list::stream(client, "/disputes", &params)
     }
 
     /// Asynchronously returns all stripe Disputes.
     ///
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::DisputeListParams with the filters to apply
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Fetch all customers from stripe
-    /// let disputes = payup::stripe::Dispute::async_list(auth).await?;
-    /// ```
-    pub async fn async_list(creds: Auth) -> Result<Vec<Self>, reqwest::Error>{
-        let mut objects: Vec<Self> = Vec::new();
-
-        let mut has_more = true;
-        let mut starting_after: Option<String> = None;
-        while has_more{
-            let json = Self::list_chunk_async(creds.clone(), starting_after).await?;
-            for json_object in json.data{
-                objects.push(json_object);
-            }
-            has_more = json.has_more;
-            starting_after = Some(objects[objects.len() - 1].id.clone().unwrap());
-        }
-        return Ok(objects);
+    /// let disputes = payup::stripe::Dispute::async_list(&client, payup::stripe::DisputeListParams::new()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_list(client: &StripeClient, params: DisputeListParams) -> Result<Vec<Self>, StripeError>{
+        let params = DisputeListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::async_iter(client, params).try_collect().await
     }
 
     /// Asynchronously POSTs an update to an existing Dispute
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Crate some evidence to update the dispute with
     /// let mut evidence = payup::stripe::Evidence::new();
@@ -1547,101 +2358,130 @@ impl Dispute {
     /// dispute.submit = Some(true);
     ///
     /// // Update the dispute
-    /// dispute = dispute.async_update(auth.clone()).await?;
+    /// dispute = dispute.async_update(&client).await?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_update(&self, creds: Auth) ->  Result<Self, reqwest::Error> {
-        let request = reqwest::Client::new().post(format!("https://api.stripe.com/v1/disputes/{}", self.clone().id.unwrap()))
-            .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-            .form(&self.to_params())
-            .send().await?;
-
-        let json = request.json::<Self>().await?;
-        return Ok(json);
+    pub async fn async_update(&self, client: &StripeClient) ->  Result<Self, StripeError> {
+        client.async_post(&format!("/disputes/{}", self.clone().id.unwrap()), &self.to_params()).await
     }
 
     /// Close a dispute.
     /// Closing the dispute for a charge indicates that you do not have any evidence to submit and are essentially dismissing the dispute, acknowledging it as lost.
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// let mut dispute = payup::stripe::Dispute::new();
     /// dispute.id = Some(format!("dp_"));
     ///
-    /// dispute = dispute.async_close(auth.clone())?;
+    /// dispute = dispute.close(&client)?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn close(&self, creds: Auth) ->  Result<Self, reqwest::Error> {
-        let request = reqwest::blocking::Client::new().post(format!("https://api.stripe.com/v1/disputes/{}/close", self.clone().id.unwrap()))
-            .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-            .send()?;
-
-        let json = request.json::<Self>()?;
-        return Ok(json);
+    pub fn close(&self, client: &StripeClient) ->  Result<Self, StripeError> {
+        client.post(&format!("/disputes/{}/close", self.clone().id.unwrap()), &())
     }
 
     /// Retrieves the dispute with the given ID.
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
     /// * `id` - The id of the dispute you want to retrieve.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Fetch customer using id
-    /// let dispute = payup::stripe::Dispute::get(auth, "ch_")?;
+    /// let dispute = payup::stripe::Dispute::get(&client, format!("ch_"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/disputes/{}", id.clone());
+        client.get(&path)
+    }
+
+    /// Lazily iterates over disputes, fetching pages from the stripe api only as they are needed
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::DisputeListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Walk the 50 most recent disputes, fetching 25 per request
+    /// let mut params = payup::stripe::DisputeListParams::new();
+    /// params.limit = Some(25);
+    /// for dispute in payup::stripe::Dispute::iter(&client, params).take(50) {
+    ///     let dispute = dispute?;
+    /// }
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn get(creds: Auth, id: String) -> Result<Self, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/disputes/{}", id.clone());
-        let request = reqwest::blocking::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send()?;
-        let json = request.json::<Self>()?;
-        return Ok(json);
+    pub fn iter(client: &StripeClient, params: DisputeListParams) -> ListIter<Self> {
+        list::iter(client, "/disputes", &params)
     }
 
     /// Returns all stripe disputes.
     ///
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::DisputeListParams with the filters to apply
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Fetch all customers from stripe
-    /// let charges = payup::stripe::Dispute::list(auth)?;
-    /// ```
-    pub fn list(creds: Auth) -> Result<Vec<Self>, reqwest::Error>{
-        let mut objects: Vec<Self> = Vec::new();
-
-        let mut has_more = true;
-        let mut starting_after: Option<String> = None;
-        while has_more{
-            let json = Self::list_chunk(creds.clone(), starting_after)?;
-            for json_object in json.data{
-                objects.push(json_object);
-            }
-            has_more = json.has_more;
-            starting_after = Some(objects[objects.len() - 1].id.clone().unwrap());
-        }
-        return Ok(objects);
+    /// let charges = payup::stripe::Dispute::list(&client, payup::stripe::DisputeListParams::new())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(client: &StripeClient, params: DisputeListParams) -> Result<Vec<Self>, StripeError>{
+        let params = DisputeListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::iter(client, params).collect()
     }
 
 
     /// POSTs an update to an existing Dispute
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Crate some evidence to update the dispute with
     /// let mut evidence = payup::stripe::Evidence::new();
@@ -1656,270 +2496,56 @@ impl Dispute {
     /// dispute.submit = Some(true);
     ///
     /// // Update the dispute
-    /// dispute = dispute.async_update(auth.clone())?;
+    /// dispute = dispute.update(&client)?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn update(&self, creds: Auth) ->  Result<Self, reqwest::Error> {
-        let request = reqwest::blocking::Client::new().post(format!("https://api.stripe.com/v1/disputes/{}", self.clone().id.unwrap()))
-            .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-            .form(&self.to_params())
-            .send()?;
-
-        let json = request.json::<Self>()?;
-        return Ok(json);
+    pub fn update(&self, client: &StripeClient) ->  Result<Self, StripeError> {
+        client.post(&format!("/disputes/{}", self.clone().id.unwrap()), &self.to_params())
     }
 
-    fn list_chunk(creds: Auth, starting_after: Option<String>) -> Result<Disputes, reqwest::Error> {
-        let mut url = "https://api.stripe.com/v1/disputes".to_string();
-
-        if starting_after.is_some() {
-            url = format!("https://api.stripe.com/v1/disputes?starting_after={}", starting_after.unwrap());
+    fn to_params(&self) -> DisputeParams<'_> {
+        DisputeParams {
+            evidence: &self.evidence,
+            submit: &self.submit,
+            metadata: &self.metadata,
         }
+    }
 
-        let request = reqwest::blocking::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send()?;
+}
 
-        let json = request.json::<Disputes>()?;
-        return Ok(json);
+impl Object for Dispute {
+    fn object_id(&self) -> Option<&str> {
+        self.id.as_deref()
     }
+}
 
-    async fn list_chunk_async(creds: Auth, starting_after: Option<String>) -> Result<Disputes, reqwest::Error> {
-        let mut url = "https://api.stripe.com/v1/disputes".to_string();
+/// Filters and pagination for listing disputes with `Dispute::list` and `Dispute::iter`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DisputeListParams {
+    /// Only disputes for this charge id.
+    pub charge: Option<String>,
+    /// Only disputes created in this range.
+    pub created: Option<RangeQuery>,
+    /// Only disputes for this PaymentIntent id.
+    pub payment_intent: Option<String>,
+    /// Number of objects fetched per page, between 1 and 100.
+    pub limit: Option<u32>,
+    /// Starts the list after the object with this id.
+    pub starting_after: Option<String>,
+    /// Starts the list before the object with this id and walks towards newer objects.
+    pub ending_before: Option<String>,
+}
+impl DisputeListParams {
+    pub fn new() -> Self {
+        DisputeListParams::default()
+    }
+}
 
-        if starting_after.is_some() {
-            url = format!("https://api.stripe.com/v1/disputes?starting_after={}", starting_after.unwrap());
-        }
-
-        let request = reqwest::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send().await?;
-
-        let json = request.json::<Disputes>().await?;
-        return Ok(json);
-    }
-
-    fn to_params(&self) -> Vec<(&str, &str)> {
-        let mut params = vec![];
-        match &self.evidence{
-            Some(evidence) => {
-
-                match &evidence.access_activity_log{
-                    Some(access_activity_log) => {
-                        params.push(("evidence[access_activity_log]", access_activity_log.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.billing_address{
-                    Some(billing_address) => {
-                        params.push(("evidence[billing_address]", billing_address.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.cancellation_policy{
-                    Some(cancellation_policy) => {
-                        params.push(("evidence[cancellation_policy]", cancellation_policy.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.cancellation_policy_disclosure{
-                    Some(cancellation_policy_disclosure) => {
-                        params.push(("evidence[cancellation_policy_disclosure]", cancellation_policy_disclosure.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.cancellation_rebuttal{
-                    Some(cancellation_rebuttal) => {
-                        params.push(("evidence[cancellation_rebuttal]", cancellation_rebuttal.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.customer_communication{
-                    Some(customer_communication) => {
-                        params.push(("evidence[customer_communication]", customer_communication.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.customer_email_address{
-                    Some(customer_email_address) => {
-                        params.push(("evidence[customer_email_address]", customer_email_address.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.customer_name{
-                    Some(customer_name) => {
-                        params.push(("evidence[customer_name]", customer_name.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.customer_purchase_ip{
-                    Some(customer_purchase_ip) => {
-                        params.push(("evidence[customer_purchase_ip]", customer_purchase_ip.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.customer_signature{
-                    Some(customer_signature) => {
-                        params.push(("evidence[customer_signature]", customer_signature.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.duplicate_charge_documentation{
-                    Some(duplicate_charge_documentation) => {
-                        params.push(("evidence[duplicate_charge_documentation]", duplicate_charge_documentation.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.duplicate_charge_explanation{
-                    Some(duplicate_charge_explanation) => {
-                        params.push(("evidence[duplicate_charge_explanation]", duplicate_charge_explanation.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.duplicate_charge_id{
-                    Some(duplicate_charge_id) => {
-                        params.push(("evidence[duplicate_charge_id]", duplicate_charge_id.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.product_description{
-                    Some(product_description) => {
-                        params.push(("evidence[product_description]", product_description.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.receipt{
-                    Some(receipt) => {
-                        params.push(("evidence[receipt]", receipt.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.refund_policy{
-                    Some(refund_policy) => {
-                        params.push(("evidence[refund_policy]", refund_policy.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.refund_policy_disclosure{
-                    Some(refund_policy_disclosure) => {
-                        params.push(("evidence[refund_policy_disclosure]", refund_policy_disclosure.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.refund_refusal_explanation{
-                    Some(refund_refusal_explanation) => {
-                        params.push(("evidence[refund_refusal_explanation]", refund_refusal_explanation.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.service_date{
-                    Some(service_date) => {
-                        params.push(("evidence[service_date]", service_date.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.service_documentation{
-                    Some(service_documentation) => {
-                        params.push(("evidence[service_documentation]", service_documentation.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.shipping_address{
-                    Some(shipping_address) => {
-                        params.push(("evidence[shipping_address]", shipping_address.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.shipping_carrier{
-                    Some(shipping_carrier) => {
-                        params.push(("evidence[shipping_carrier]", shipping_carrier.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.shipping_date{
-                    Some(shipping_date) => {
-                        params.push(("evidence[shipping_date]", shipping_date.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.shipping_documentation{
-                    Some(shipping_documentation) => {
-                        params.push(("evidence[shipping_documentation]", shipping_documentation.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.shipping_tracking_number{
-                    Some(shipping_tracking_number) => {
-                        params.push(("evidence[shipping_tracking_number]", shipping_tracking_number.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.uncategorized_file{
-                    Some(uncategorized_file) => {
-                        params.push(("evidence[uncategorized_file]", uncategorized_file.as_str()));
-                    },
-                    None => {}
-                }
-
-                match &evidence.uncategorized_text{
-                    Some(uncategorized_text) => {
-                        params.push(("evidence[uncategorized_text]", uncategorized_text.as_str()));
-                    },
-                    None => {}
-                }
-
-
-
-            },
-            None => {}
-        }
-        // "metadata[order_id]"=6735
-        // match &self.metadata{
-        //     Some(metadata) => params.push(("metadata", metadata.as_str())),
-        //     None => {}
-        // }
-        match &self.submit{
-            Some(submit) => {
-
-                if *submit{
-                    params.push(("submit", "true"));
-                } else {
-                    params.push(("submit", "false"));
-                }
-
-
-               
-
-            },
-            None => {}
-        }
-        return params;
-    }
-
-}
 
-// TODO - Impliment data/object. This can be any stripe object so it's best to build out the other structs before implementing.
 /// Events occur when the state of another API resource changes.
+///
+/// Webhook payloads are events as well, see `Webhook::construct_event` to verify and parse them.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Event {
     pub id: Option<String>,
@@ -1927,13 +2553,14 @@ pub struct Event {
     #[serde(rename = "api_version")]
     pub api_version: Option<String>,
     pub created: Option<i64>,
-    // pub data: Option<Data>,
+    /// The object the event is about, as it was when the event happened.
+    pub data: Option<EventData>,
     pub livemode: Option<bool>,
     #[serde(rename = "pending_webhooks")]
     pub pending_webhooks: Option<i64>,
     pub request: Option<Request>,
     #[serde(rename = "type")]
-    pub type_field: Option<String>,
+    pub type_field: Option<EventType>,
 }
 impl Event {
 
@@ -1942,54 +2569,87 @@ impl Event {
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
     /// * `id` - The id of the event you want to retrieve.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Fetch customer using id
-    /// let event = payup::stripe::Event::async_get(auth, "ch_").await?;
+    /// let event = payup::stripe::Event::async_get(&client, format!("ch_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/events/{}", id.clone());
+        client.async_get(&path).await
+    }
+
+    /// Asynchronously and lazily iterates over events, fetching pages from the stripe api only as they are needed
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::EventListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Walk the 50 most recent events, fetching 25 per request
+    /// let mut params = payup::stripe::EventListParams::new();
+    /// params.limit = Some(25);
+    /// let mut events = payup::stripe::Event::async_iter(&client, params).take(50);
+    /// while let Some(event) = events.next().await {
+    ///     let event = event?;
+    /// }
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_get(creds: Auth, id: String) -> Result<Self, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/events/{}", id.clone());
-        let request = reqwest::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send().await?;
-        let json = request.json::<Self>().await?;
-        return Ok(json);
+    pub fn async_iter(client: &StripeClient, params: EventListParams) -> ListStream<Self> {
+        list::stream(client, "/events", &params)
     }
 
     /// Asynchronously returns all stripe Events.
     ///
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::EventListParams with the filters to apply
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Fetch all customers from stripe
-    /// let events = payup::stripe::Event::async_list(auth).await?;
-    /// ```
-    pub async fn async_list(creds: Auth) -> Result<Vec<Self>, reqwest::Error>{
-        let mut objects: Vec<Self> = Vec::new();
-
-        let mut has_more = true;
-        let mut starting_after: Option<String> = None;
-        while has_more{
-            let json = Self::list_chunk_async(creds.clone(), starting_after).await?;
-            for json_object in json.data{
-                objects.push(json_object);
-            }
-            has_more = json.has_more;
-            starting_after = Some(objects[objects.len() - 1].id.clone().unwrap());
-        }
-        return Ok(objects);
+    /// let events = payup::stripe::Event::async_list(&client, payup::stripe::EventListParams::new()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_list(client: &StripeClient, params: EventListParams) -> Result<Vec<Self>, StripeError>{
+        let params = EventListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::async_iter(client, params).try_collect().await
     }
 
 
@@ -1997,87 +2657,607 @@ impl Event {
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
     /// * `id` - The id of the event you want to retrieve.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Fetch customer using id
-    /// let event = payup::stripe::Event::get(auth, "ch_")?;
+    /// let event = payup::stripe::Event::get(&client, format!("ch_"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/events/{}", id.clone());
+        client.get(&path)
+    }
+
+    /// Lazily iterates over events, fetching pages from the stripe api only as they are needed
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::EventListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Walk the 50 most recent events, fetching 25 per request
+    /// let mut params = payup::stripe::EventListParams::new();
+    /// params.limit = Some(25);
+    /// for event in payup::stripe::Event::iter(&client, params).take(50) {
+    ///     let event = event?;
+    /// }
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn get(creds: Auth, id: String) -> Result<Self, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/events/{}", id.clone());
-        let request = reqwest::blocking::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send()?;
-        let json = request.json::<Self>()?;
-        return Ok(json);
+    pub fn iter(client: &StripeClient, params: EventListParams) -> ListIter<Self> {
+        list::iter(client, "/events", &params)
     }
 
     /// Returns all stripe events.
     ///
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::EventListParams with the filters to apply
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Fetch all customers from stripe
-    /// let events = payup::stripe::Event::list(auth)?;
-    /// ```
-    pub fn list(creds: Auth) -> Result<Vec<Self>, reqwest::Error>{
-        let mut objects: Vec<Self> = Vec::new();
+    /// let events = payup::stripe::Event::list(&client, payup::stripe::EventListParams::new())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(client: &StripeClient, params: EventListParams) -> Result<Vec<Self>, StripeError>{
+        let params = EventListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::iter(client, params).collect()
+    }
 
-        let mut has_more = true;
-        let mut starting_after: Option<String> = None;
-        while has_more{
-            let json = Self::list_chunk(creds.clone(), starting_after)?;
-            for json_object in json.data{
-                objects.push(json_object);
-            }
-            has_more = json.has_more;
-            starting_after = Some(objects[objects.len() - 1].id.clone().unwrap());
-        }
-        return Ok(objects);
+
+}
+
+impl Object for Event {
+    fn object_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+}
+
+/// Filters and pagination for listing events with `Event::list` and `Event::iter`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EventListParams {
+    /// Only events created in this range.
+    pub created: Option<RangeQuery>,
+    /// Only events whose webhooks were (true) or were not (false) delivered successfully.
+    pub delivery_success: Option<bool>,
+    /// Only events of this type, e.g. `charge.succeeded`. Accepts `*` as a wildcard, e.g. `charge.*`.
+    #[serde(rename = "type")]
+    pub type_field: Option<String>,
+    /// Only events of one of these types, cannot be combined with `type_field`.
+    pub types: Option<Vec<String>>,
+    /// Number of objects fetched per page, between 1 and 100.
+    pub limit: Option<u32>,
+    /// Starts the list after the object with this id.
+    pub starting_after: Option<String>,
+    /// Starts the list before the object with this id and walks towards newer objects.
+    pub ending_before: Option<String>,
+}
+impl EventListParams {
+    pub fn new() -> Self {
+        EventListParams::default()
     }
+}
 
+/// The object an Event is about, plus the attributes that changed for `*.updated` events.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EventData {
+    pub object: EventObject,
+    /// The previous values of the attributes that changed, only set for `*.updated` events.
+    pub previous_attributes: Option<serde_json::Value>,
+}
 
-    fn list_chunk(creds: Auth, starting_after: Option<String>) -> Result<Events, reqwest::Error> {
-        let mut url = "https://api.stripe.com/v1/events".to_string();
+/// The `data.object` of an Event, typed by its `object` field.
+///
+/// Objects of a type this crate has no struct for are kept as `Unknown` with the raw JSON. So is an object of
+/// a known type that does not match its struct, e.g. because a newer api version changed a field, so the
+/// rest of the Event can still be read. The dispatcher decodes such an object again and reports the error
+/// as `DispatchError::Decode`.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), serde_json::Error> {
+/// // The body of a charge.succeeded webhook
+/// let payload = r#"{
+///   "id": "evt_3MtwBwLkdIwHu7ix28a3tqPa",
+///   "object": "event",
+///   "api_version": "2022-11-15",
+///   "created": 1680064028,
+///   "data": {
+///     "object": {
+///       "id": "ch_3MtwBwLkdIwHu7ix28a3tqPa",
+///       "object": "charge",
+///       "amount": 1099,
+///       "amount_captured": 1099,
+///       "amount_refunded": 0,
+///       "balance_transaction": "txn_3MtwBwLkdIwHu7ix2GHbwTHS",
+///       "billing_details": {
+///         "address": {"city": null, "country": null, "line1": null, "line2": null, "postal_code": "94103", "state": null},
+///         "email": null,
+///         "name": "Jenny Rosen",
+///         "phone": null
+///       },
+///       "captured": true,
+///       "created": 1680064028,
+///       "currency": "usd",
+///       "customer": "cus_NffrFeUfNV2Hib",
+///       "description": null,
+///       "disputed": false,
+///       "fraud_details": {},
+///       "livemode": false,
+///       "metadata": {"order_id": "6735"},
+///       "outcome": {"network_status": "approved_by_network", "reason": null, "risk_level": "normal", "risk_score": 32, "seller_message": "Payment complete.", "type": "authorized"},
+///       "paid": true,
+///       "payment_intent": "pi_3MtwBwLkdIwHu7ix28a3tqPa",
+///       "payment_method": "pm_1MtwBwLkdIwHu7ixKbYDTqqJ",
+///       "payment_method_details": {
+///         "card": {
+///           "brand": "visa",
+///           "checks": {"address_line1_check": null, "address_postal_code_check": "pass", "cvc_check": "pass"},
+///           "country": "US",
+///           "exp_month": 8,
+///           "exp_year": 2024,
+///           "fingerprint": "Xt5EWLLDS7FJjR1c",
+///           "funding": "credit",
+///           "last4": "4242",
+///           "network": "visa",
+///           "three_d_secure": null,
+///           "wallet": null
+///         },
+///         "type": "card"
+///       },
+///       "receipt_email": null,
+///       "receipt_url": "https://pay.stripe.com/receipts/payment/CAcaFwoVYWNjdF8xTTJKVGtMa2RJd0h1N2l4KJzskaEGMgbzSGVD5cQ6LBZ6bdLhBNbhSwJ5U2gNaXNgixkDUYUYk2ZFzyOD5xUWOIRXLHY8rHJ1g2zz",
+///       "refunded": false,
+///       "refunds": {"object": "list", "data": [], "has_more": false, "url": "/v1/charges/ch_3MtwBwLkdIwHu7ix28a3tqPa/refunds"},
+///       "source": null,
+///       "statement_descriptor": null,
+///       "statement_descriptor_suffix": null,
+///       "status": "succeeded"
+///     }
+///   },
+///   "livemode": false,
+///   "pending_webhooks": 1,
+///   "request": {"id": "req_yWbIvoYhANPH2S", "idempotency_key": "8a8b4f4c-2a97-4c28-a4a2-1f4b1b1ec3c5"},
+///   "type": "charge.succeeded"
+/// }"#;
+///
+/// let event: payup::stripe::Event = serde_json::from_str(payload)?;
+/// match event.data.map(|data| data.object) {
+///     Some(payup::stripe::EventObject::Charge(charge)) => {
+///         let card = charge.payment_method_details.and_then(|details| details.card).unwrap();
+///         assert_eq!(charge.amount, Some(1099));
+///         assert_eq!((card.exp_month, card.exp_year), (Some(8), Some(2024)));
+///     }
+///     object => panic!("expected a charge, got {:?}", object),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum EventObject {
+    Balance(Box<Balance>),
+    Charge(Box<Charge>),
+    Coupon(Box<Coupon>),
+    Customer(Box<Customer>),
+    Discount(Box<Discount>),
+    Dispute(Box<Dispute>),
+    File(Box<File>),
+    Invoice(Box<Invoice>),
+    InvoiceItem(Box<InvoiceItem>),
+    Mandate(Box<Mandate>),
+    PaymentIntent(Box<PaymentIntent>),
+    PaymentMethod(Box<PaymentMethod>),
+    Plan(Box<Plan>),
+    Price(Box<Price>),
+    Product(Box<Product>),
+    PromotionCode(Box<PromotionCode>),
+    Refund(Box<Refund>),
+    SetupIntent(Box<SetupIntent>),
+    Subscription(Box<Subscription>),
+    SubscriptionSchedule(Box<SubscriptionSchedule>),
+    Unknown(serde_json::Value),
+}
 
-        if starting_after.is_some() {
-            url = format!("https://api.stripe.com/v1/events?starting_after={}", starting_after.unwrap());
+impl EventObject {
+    fn from_value(value: serde_json::Value) -> Self {
+        fn typed<T: serde::de::DeserializeOwned>(value: &serde_json::Value, variant: fn(Box<T>) -> EventObject) -> Option<EventObject> {
+            T::deserialize(value).ok().map(|object| variant(Box::new(object)))
         }
 
-        let request = reqwest::blocking::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send()?;
+        let object = match value.get("object").and_then(serde_json::Value::as_str) {
+            Some("balance") => typed(&value, EventObject::Balance),
+            Some("charge") => typed(&value, EventObject::Charge),
+            Some("coupon") => typed(&value, EventObject::Coupon),
+            Some("customer") => typed(&value, EventObject::Customer),
+            Some("discount") => typed(&value, EventObject::Discount),
+            Some("dispute") => typed(&value, EventObject::Dispute),
+            Some("file") => typed(&value, EventObject::File),
+            Some("invoice") => typed(&value, EventObject::Invoice),
+            Some("invoiceitem") => typed(&value, EventObject::InvoiceItem),
+            Some("mandate") => typed(&value, EventObject::Mandate),
+            Some("payment_intent") => typed(&value, EventObject::PaymentIntent),
+            Some("payment_method") => typed(&value, EventObject::PaymentMethod),
+            Some("plan") => typed(&value, EventObject::Plan),
+            Some("price") => typed(&value, EventObject::Price),
+            Some("product") => typed(&value, EventObject::Product),
+            Some("promotion_code") => typed(&value, EventObject::PromotionCode),
+            Some("refund") => typed(&value, EventObject::Refund),
+            Some("setup_intent") => typed(&value, EventObject::SetupIntent),
+            Some("subscription") => typed(&value, EventObject::Subscription),
+            Some("subscription_schedule") => typed(&value, EventObject::SubscriptionSchedule),
+            _ => None,
+        };
+        object.unwrap_or(EventObject::Unknown(value))
+    }
+}
 
-        let json = request.json::<Events>()?;
-        return Ok(json);
+impl<'de> Deserialize<'de> for EventObject {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        serde_json::Value::deserialize(deserializer).map(EventObject::from_value)
     }
+}
+
+/// The type of an Event, e.g. `invoice.paid`, named after the object and what happened to it.
+///
+/// Types this crate does not know yet, e.g. ones added in a newer api version, are kept as `Other` with
+/// their name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EventType {
+    BalanceAvailable,
+    ChargeCaptured,
+    ChargeDisputeClosed,
+    ChargeDisputeCreated,
+    ChargeDisputeFundsReinstated,
+    ChargeDisputeFundsWithdrawn,
+    ChargeDisputeUpdated,
+    ChargeExpired,
+    ChargeFailed,
+    ChargePending,
+    ChargeRefundUpdated,
+    ChargeRefunded,
+    ChargeSucceeded,
+    ChargeUpdated,
+    CheckoutSessionAsyncPaymentFailed,
+    CheckoutSessionAsyncPaymentSucceeded,
+    CheckoutSessionCompleted,
+    CheckoutSessionExpired,
+    CouponCreated,
+    CouponDeleted,
+    CouponUpdated,
+    CustomerCreated,
+    CustomerDeleted,
+    CustomerDiscountCreated,
+    CustomerDiscountDeleted,
+    CustomerDiscountUpdated,
+    CustomerSubscriptionCreated,
+    CustomerSubscriptionDeleted,
+    CustomerSubscriptionPaused,
+    CustomerSubscriptionPendingUpdateApplied,
+    CustomerSubscriptionPendingUpdateExpired,
+    CustomerSubscriptionResumed,
+    CustomerSubscriptionTrialWillEnd,
+    CustomerSubscriptionUpdated,
+    CustomerUpdated,
+    FileCreated,
+    InvoiceCreated,
+    InvoiceDeleted,
+    InvoiceFinalizationFailed,
+    InvoiceFinalized,
+    InvoiceMarkedUncollectible,
+    InvoicePaid,
+    InvoicePaymentActionRequired,
+    InvoicePaymentFailed,
+    InvoicePaymentSucceeded,
+    InvoiceSent,
+    InvoiceUpcoming,
+    InvoiceUpdated,
+    InvoiceVoided,
+    InvoiceItemCreated,
+    InvoiceItemDeleted,
+    InvoiceItemUpdated,
+    MandateUpdated,
+    PaymentIntentAmountCapturableUpdated,
+    PaymentIntentCanceled,
+    PaymentIntentCreated,
+    PaymentIntentPartiallyFunded,
+    PaymentIntentPaymentFailed,
+    PaymentIntentProcessing,
+    PaymentIntentRequiresAction,
+    PaymentIntentSucceeded,
+    PaymentMethodAttached,
+    PaymentMethodAutomaticallyUpdated,
+    PaymentMethodDetached,
+    PaymentMethodUpdated,
+    PayoutCanceled,
+    PayoutCreated,
+    PayoutFailed,
+    PayoutPaid,
+    PayoutUpdated,
+    PlanCreated,
+    PlanDeleted,
+    PlanUpdated,
+    PriceCreated,
+    PriceDeleted,
+    PriceUpdated,
+    ProductCreated,
+    ProductDeleted,
+    ProductUpdated,
+    PromotionCodeCreated,
+    PromotionCodeUpdated,
+    SetupIntentCanceled,
+    SetupIntentCreated,
+    SetupIntentRequiresAction,
+    SetupIntentSetupFailed,
+    SetupIntentSucceeded,
+    SubscriptionScheduleAborted,
+    SubscriptionScheduleCanceled,
+    SubscriptionScheduleCompleted,
+    SubscriptionScheduleCreated,
+    SubscriptionScheduleExpiring,
+    SubscriptionScheduleReleased,
+    SubscriptionScheduleUpdated,
+    Other(String),
+}
 
-    async fn list_chunk_async(creds: Auth, starting_after: Option<String>) -> Result<Events, reqwest::Error> {
-        let mut url = "https://api.stripe.com/v1/events".to_string();
+impl EventType {
+    /// The name Stripe uses for the type, e.g. `invoice.paid`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            EventType::BalanceAvailable => "balance.available",
+            EventType::ChargeCaptured => "charge.captured",
+            EventType::ChargeDisputeClosed => "charge.dispute.closed",
+            EventType::ChargeDisputeCreated => "charge.dispute.created",
+            EventType::ChargeDisputeFundsReinstated => "charge.dispute.funds_reinstated",
+            EventType::ChargeDisputeFundsWithdrawn => "charge.dispute.funds_withdrawn",
+            EventType::ChargeDisputeUpdated => "charge.dispute.updated",
+            EventType::ChargeExpired => "charge.expired",
+            EventType::ChargeFailed => "charge.failed",
+            EventType::ChargePending => "charge.pending",
+            EventType::ChargeRefundUpdated => "charge.refund.updated",
+            EventType::ChargeRefunded => "charge.refunded",
+            EventType::ChargeSucceeded => "charge.succeeded",
+            EventType::ChargeUpdated => "charge.updated",
+            EventType::CheckoutSessionAsyncPaymentFailed => "checkout.session.async_payment_failed",
+            EventType::CheckoutSessionAsyncPaymentSucceeded => "checkout.session.async_payment_succeeded",
+            EventType::CheckoutSessionCompleted => "checkout.session.completed",
+            EventType::CheckoutSessionExpired => "checkout.session.expired",
+            EventType::CouponCreated => "coupon.created",
+            EventType::CouponDeleted => "coupon.deleted",
+            EventType::CouponUpdated => "coupon.updated",
+            EventType::CustomerCreated => "customer.created",
+            EventType::CustomerDeleted => "customer.deleted",
+            EventType::CustomerDiscountCreated => "customer.discount.created",
+            EventType::CustomerDiscountDeleted => "customer.discount.deleted",
+            EventType::CustomerDiscountUpdated => "customer.discount.updated",
+            EventType::CustomerSubscriptionCreated => "customer.subscription.created",
+            EventType::CustomerSubscriptionDeleted => "customer.subscription.deleted",
+            EventType::CustomerSubscriptionPaused => "customer.subscription.paused",
+            EventType::CustomerSubscriptionPendingUpdateApplied => "customer.subscription.pending_update_applied",
+            EventType::CustomerSubscriptionPendingUpdateExpired => "customer.subscription.pending_update_expired",
+            EventType::CustomerSubscriptionResumed => "customer.subscription.resumed",
+            EventType::CustomerSubscriptionTrialWillEnd => "customer.subscription.trial_will_end",
+            EventType::CustomerSubscriptionUpdated => "customer.subscription.updated",
+            EventType::CustomerUpdated => "customer.updated",
+            EventType::FileCreated => "file.created",
+            EventType::InvoiceCreated => "invoice.created",
+            EventType::InvoiceDeleted => "invoice.deleted",
+            EventType::InvoiceFinalizationFailed => "invoice.finalization_failed",
+            EventType::InvoiceFinalized => "invoice.finalized",
+            EventType::InvoiceMarkedUncollectible => "invoice.marked_uncollectible",
+            EventType::InvoicePaid => "invoice.paid",
+            EventType::InvoicePaymentActionRequired => "invoice.payment_action_required",
+            EventType::InvoicePaymentFailed => "invoice.payment_failed",
+            EventType::InvoicePaymentSucceeded => "invoice.payment_succeeded",
+            EventType::InvoiceSent => "invoice.sent",
+            EventType::InvoiceUpcoming => "invoice.upcoming",
+            EventType::InvoiceUpdated => "invoice.updated",
+            EventType::InvoiceVoided => "invoice.voided",
+            EventType::InvoiceItemCreated => "invoiceitem.created",
+            EventType::InvoiceItemDeleted => "invoiceitem.deleted",
+            EventType::InvoiceItemUpdated => "invoiceitem.updated",
+            EventType::MandateUpdated => "mandate.updated",
+            EventType::PaymentIntentAmountCapturableUpdated => "payment_intent.amount_capturable_updated",
+            EventType::PaymentIntentCanceled => "payment_intent.canceled",
+            EventType::PaymentIntentCreated => "payment_intent.created",
+            EventType::PaymentIntentPartiallyFunded => "payment_intent.partially_funded",
+            EventType::PaymentIntentPaymentFailed => "payment_intent.payment_failed",
+            EventType::PaymentIntentProcessing => "payment_intent.processing",
+            EventType::PaymentIntentRequiresAction => "payment_intent.requires_action",
+            EventType::PaymentIntentSucceeded => "payment_intent.succeeded",
+            EventType::PaymentMethodAttached => "payment_method.attached",
+            EventType::PaymentMethodAutomaticallyUpdated => "payment_method.automatically_updated",
+            EventType::PaymentMethodDetached => "payment_method.detached",
+            EventType::PaymentMethodUpdated => "payment_method.updated",
+            EventType::PayoutCanceled => "payout.canceled",
+            EventType::PayoutCreated => "payout.created",
+            EventType::PayoutFailed => "payout.failed",
+            EventType::PayoutPaid => "payout.paid",
+            EventType::PayoutUpdated => "payout.updated",
+            EventType::PlanCreated => "plan.created",
+            EventType::PlanDeleted => "plan.deleted",
+            EventType::PlanUpdated => "plan.updated",
+            EventType::PriceCreated => "price.created",
+            EventType::PriceDeleted => "price.deleted",
+            EventType::PriceUpdated => "price.updated",
+            EventType::ProductCreated => "product.created",
+            EventType::ProductDeleted => "product.deleted",
+            EventType::ProductUpdated => "product.updated",
+            EventType::PromotionCodeCreated => "promotion_code.created",
+            EventType::PromotionCodeUpdated => "promotion_code.updated",
+            EventType::SetupIntentCanceled => "setup_intent.canceled",
+            EventType::SetupIntentCreated => "setup_intent.created",
+            EventType::SetupIntentRequiresAction => "setup_intent.requires_action",
+            EventType::SetupIntentSetupFailed => "setup_intent.setup_failed",
+            EventType::SetupIntentSucceeded => "setup_intent.succeeded",
+            EventType::SubscriptionScheduleAborted => "subscription_schedule.aborted",
+            EventType::SubscriptionScheduleCanceled => "subscription_schedule.canceled",
+            EventType::SubscriptionScheduleCompleted => "subscription_schedule.completed",
+            EventType::SubscriptionScheduleCreated => "subscription_schedule.created",
+            EventType::SubscriptionScheduleExpiring => "subscription_schedule.expiring",
+            EventType::SubscriptionScheduleReleased => "subscription_schedule.released",
+            EventType::SubscriptionScheduleUpdated => "subscription_schedule.updated",
+            EventType::Other(name) => name,
+        }
+    }
+}
 
-        if starting_after.is_some() {
-            url = format!("https://api.stripe.com/v1/events?starting_after={}", starting_after.unwrap());
+impl From<&str> for EventType {
+    fn from(name: &str) -> Self {
+        match name {
+            "balance.available" => EventType::BalanceAvailable,
+            "charge.captured" => EventType::ChargeCaptured,
+            "charge.dispute.closed" => EventType::ChargeDisputeClosed,
+            "charge.dispute.created" => EventType::ChargeDisputeCreated,
+            "charge.dispute.funds_reinstated" => EventType::ChargeDisputeFundsReinstated,
+            "charge.dispute.funds_withdrawn" => EventType::ChargeDisputeFundsWithdrawn,
+            "charge.dispute.updated" => EventType::ChargeDisputeUpdated,
+            "charge.expired" => EventType::ChargeExpired,
+            "charge.failed" => EventType::ChargeFailed,
+            "charge.pending" => EventType::ChargePending,
+            "charge.refund.updated" => EventType::ChargeRefundUpdated,
+            "charge.refunded" => EventType::ChargeRefunded,
+            "charge.succeeded" => EventType::ChargeSucceeded,
+            "charge.updated" => EventType::ChargeUpdated,
+            "checkout.session.async_payment_failed" => EventType::CheckoutSessionAsyncPaymentFailed,
+            "checkout.session.async_payment_succeeded" => EventType::CheckoutSessionAsyncPaymentSucceeded,
+            "checkout.session.completed" => EventType::CheckoutSessionCompleted,
+            "checkout.session.expired" => EventType::CheckoutSessionExpired,
+            "coupon.created" => EventType::CouponCreated,
+            "coupon.deleted" => EventType::CouponDeleted,
+            "coupon.updated" => EventType::CouponUpdated,
+            "customer.created" => EventType::CustomerCreated,
+            "customer.deleted" => EventType::CustomerDeleted,
+            "customer.discount.created" => EventType::CustomerDiscountCreated,
+            "customer.discount.deleted" => EventType::CustomerDiscountDeleted,
+            "customer.discount.updated" => EventType::CustomerDiscountUpdated,
+            "customer.subscription.created" => EventType::CustomerSubscriptionCreated,
+            "customer.subscription.deleted" => EventType::CustomerSubscriptionDeleted,
+            "customer.subscription.paused" => EventType::CustomerSubscriptionPaused,
+            "customer.subscription.pending_update_applied" => EventType::CustomerSubscriptionPendingUpdateApplied,
+            "customer.subscription.pending_update_expired" => EventType::CustomerSubscriptionPendingUpdateExpired,
+            "customer.subscription.resumed" => EventType::CustomerSubscriptionResumed,
+            "customer.subscription.trial_will_end" => EventType::CustomerSubscriptionTrialWillEnd,
+            "customer.subscription.updated" => EventType::CustomerSubscriptionUpdated,
+            "customer.updated" => EventType::CustomerUpdated,
+            "file.created" => EventType::FileCreated,
+            "invoice.created" => EventType::InvoiceCreated,
+            "invoice.deleted" => EventType::InvoiceDeleted,
+            "invoice.finalization_failed" => EventType::InvoiceFinalizationFailed,
+            "invoice.finalized" => EventType::InvoiceFinalized,
+            "invoice.marked_uncollectible" => EventType::InvoiceMarkedUncollectible,
+            "invoice.paid" => EventType::InvoicePaid,
+            "invoice.payment_action_required" => EventType::InvoicePaymentActionRequired,
+            "invoice.payment_failed" => EventType::InvoicePaymentFailed,
+            "invoice.payment_succeeded" => EventType::InvoicePaymentSucceeded,
+            "invoice.sent" => EventType::InvoiceSent,
+            "invoice.upcoming" => EventType::InvoiceUpcoming,
+            "invoice.updated" => EventType::InvoiceUpdated,
+            "invoice.voided" => EventType::InvoiceVoided,
+            "invoiceitem.created" => EventType::InvoiceItemCreated,
+            "invoiceitem.deleted" => EventType::InvoiceItemDeleted,
+            "invoiceitem.updated" => EventType::InvoiceItemUpdated,
+            "mandate.updated" => EventType::MandateUpdated,
+            "payment_intent.amount_capturable_updated" => EventType::PaymentIntentAmountCapturableUpdated,
+            "payment_intent.canceled" => EventType::PaymentIntentCanceled,
+            "payment_intent.created" => EventType::PaymentIntentCreated,
+            "payment_intent.partially_funded" => EventType::PaymentIntentPartiallyFunded,
+            "payment_intent.payment_failed" => EventType::PaymentIntentPaymentFailed,
+            "payment_intent.processing" => EventType::PaymentIntentProcessing,
+            "payment_intent.requires_action" => EventType::PaymentIntentRequiresAction,
+            "payment_intent.succeeded" => EventType::PaymentIntentSucceeded,
+            "payment_method.attached" => EventType::PaymentMethodAttached,
+            "payment_method.automatically_updated" => EventType::PaymentMethodAutomaticallyUpdated,
+            "payment_method.detached" => EventType::PaymentMethodDetached,
+            "payment_method.updated" => EventType::PaymentMethodUpdated,
+            "payout.canceled" => EventType::PayoutCanceled,
+            "payout.created" => EventType::PayoutCreated,
+            "payout.failed" => EventType::PayoutFailed,
+            "payout.paid" => EventType::PayoutPaid,
+            "payout.updated" => EventType::PayoutUpdated,
+            "plan.created" => EventType::PlanCreated,
+            "plan.deleted" => EventType::PlanDeleted,
+            "plan.updated" => EventType::PlanUpdated,
+            "price.created" => EventType::PriceCreated,
+            "price.deleted" => EventType::PriceDeleted,
+            "price.updated" => EventType::PriceUpdated,
+            "product.created" => EventType::ProductCreated,
+            "product.deleted" => EventType::ProductDeleted,
+            "product.updated" => EventType::ProductUpdated,
+            "promotion_code.created" => EventType::PromotionCodeCreated,
+            "promotion_code.updated" => EventType::PromotionCodeUpdated,
+            "setup_intent.canceled" => EventType::SetupIntentCanceled,
+            "setup_intent.created" => EventType::SetupIntentCreated,
+            "setup_intent.requires_action" => EventType::SetupIntentRequiresAction,
+            "setup_intent.setup_failed" => EventType::SetupIntentSetupFailed,
+            "setup_intent.succeeded" => EventType::SetupIntentSucceeded,
+            "subscription_schedule.aborted" => EventType::SubscriptionScheduleAborted,
+            "subscription_schedule.canceled" => EventType::SubscriptionScheduleCanceled,
+            "subscription_schedule.completed" => EventType::SubscriptionScheduleCompleted,
+            "subscription_schedule.created" => EventType::SubscriptionScheduleCreated,
+            "subscription_schedule.expiring" => EventType::SubscriptionScheduleExpiring,
+            "subscription_schedule.released" => EventType::SubscriptionScheduleReleased,
+            "subscription_schedule.updated" => EventType::SubscriptionScheduleUpdated,
+            _ => EventType::Other(name.to_string()),
         }
+    }
+}
 
-        let request = reqwest::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send().await?;
+impl std::fmt::Display for EventType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 
-        let json = request.json::<Events>().await?;
-        return Ok(json);
+impl Serialize for EventType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
     }
+}
 
+impl<'de> Deserialize<'de> for EventType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(|name| EventType::from(name.as_str()))
+    }
 }
 
+
 /// Represents a file hosted on Stripe's servers. 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct File {
     pub id: Option<String>,
     pub object: Option<String>,
@@ -2093,6 +3273,9 @@ pub struct File {
     pub type_field: Option<String>,
     pub url: Option<String>,
     pub file: Option<Vec<u8>>,
+    /// Set when Stripe answered with the stored result of an earlier request with the same idempotency key.
+    #[serde(skip_serializing)]
+    pub idempotent_replayed: Option<bool>,
 }
 impl File {
 
@@ -2100,25 +3283,15 @@ impl File {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
     /// let mut file = payup::stripe::File::new();
     /// file.title = Some(format!("Title"));
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn new() -> Self {
-        return File{
-            id: None,
-            object: None,
-            created: None,
-            expires_at: None,
-            filename: None,
-            links: None,
-            purpose: None,
-            size: None,
-            title: None,
-            type_field: None,
-            url: None,
-            file: None,
-        };
+        File::default()
     }
 
 
@@ -2126,262 +3299,294 @@ impl File {
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
     /// * `id` - The id of the file you want to retrieve.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Fetch customer using id
-    /// let file = payup::stripe::File::async_get(auth, "ch_").await?;
+    /// let file = payup::stripe::File::async_get(&client, format!("ch_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/files/{}", id.clone());
+        client.async_get(&path).await
+    }
+
+    /// Asynchronously and lazily iterates over files, fetching pages from the stripe api only as they are needed
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::FileListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Walk the 50 most recent files, fetching 25 per request
+    /// let mut params = payup::stripe::FileListParams::new();
+    /// params.limit = Some(25);
+    /// let mut files = payup::stripe::File::async_iter(&client, params).take(50);
+    /// while let Some(file) = files.next().await {
+    ///     let file = file?;
+    /// }
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_get(creds: Auth, id: String) -> Result<Self, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/files/{}", id.clone());
-        let request = reqwest::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send().await?;
-        let json = request.json::<Self>().await?;
-        return Ok(json);
+    pub fn async_iter(client: &StripeClient, params: FileListParams) -> ListStream<Self> {
+        list::stream(client, "/files", &params)
     }
 
     /// Asynchronously returns all stripe Files.
     ///
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::FileListParams with the filters to apply
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
-    /// // Fetch all customers from stripe
-    /// let disputes = payup::stripe::Dispute::async_list(auth).await?;
+    /// // Fetch all files from stripe
+    /// let files = payup::stripe::File::async_list(&client, payup::stripe::FileListParams::new()).await?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_list(creds: Auth) -> Result<Vec<Self>, reqwest::Error>{
-        let mut objects: Vec<Self> = Vec::new();
-
-        let mut has_more = true;
-        let mut starting_after: Option<String> = None;
-        while has_more{
-            let json = Self::list_chunk_async(creds.clone(), starting_after).await?;
-            for json_object in json.data{
-                objects.push(json_object);
-            }
-            has_more = json.has_more;
-            starting_after = Some(objects[objects.len() - 1].id.clone().unwrap());
-        }
-        return Ok(objects);
+    pub async fn async_list(client: &StripeClient, params: FileListParams) -> Result<Vec<Self>, StripeError>{
+        let params = FileListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::async_iter(client, params).try_collect().await
     }
 
     /// Asynchronously POSTs a new File to the stripe api
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// let mut file = payup::stripe::File::new();
-    /// ley bytes: Vec<u8> = Vec::new();
-    /// charge.amount = Some(100);
+    /// let bytes: Vec<u8> = Vec::new();
     /// file.file = Some(bytes);
     ///
     /// // The purpose of the uploaded file.
     /// // Possible enum values: account_requirement, additional_verification, business_icon, business_logo, customer_signature, dispute_evidence, identity_document, pci_document, tax_document_user_upload
     /// file.purpose = Some(format!("cust_"));
     ///
-    /// file = file.post(auth.clone()).await?;
+    /// file = file.async_post(&client).await?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_post(&self, creds: Auth) ->  Result<Self, reqwest::Error> {
-
-        let mut form = self.to_multipart_form_async().await;
+    pub async fn async_post(&self, client: &StripeClient) ->  Result<Self, StripeError> {
 
-        let request = reqwest::Client::new()
-            .post("https://api.stripe.com/v1/files")
-            .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-            .multipart(form)
-            .send().await?;
+        let form = self.to_multipart_form_async().await;
 
-
-
-        let json = request.json::<Self>().await?;
-        return Ok(json);
+        client.async_post_multipart("/files", form).await
     }
 
     /// POSTs a new File to the stripe api
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// let mut file = payup::stripe::File::new();
-    /// ley bytes: Vec<u8> = Vec::new();
-    /// charge.amount = Some(100);
+    /// let bytes: Vec<u8> = Vec::new();
     /// file.file = Some(bytes);
     ///
     /// // The purpose of the uploaded file.
     /// // Possible enum values: account_requirement, additional_verification, business_icon, business_logo, customer_signature, dispute_evidence, identity_document, pci_document, tax_document_user_upload
     /// file.purpose = Some(format!("cust_"));
     ///
-    /// file = file.post(auth.clone())?;
+    /// file = file.post(&client)?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn post(&self, creds: Auth) ->  Result<Self, reqwest::Error> {
+    pub fn post(&self, client: &StripeClient) ->  Result<Self, StripeError> {
 
-        let mut form = self.to_multipart_form();
+        let form = self.to_multipart_form();
 
-        let request = reqwest::blocking::Client::new()
-            .post("https://api.stripe.com/v1/files")
-            .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-            .multipart(form)
-            .send()?;
-
-
-
-        let json = request.json::<Self>()?;
-        return Ok(json);
+        client.post_multipart("/files", form)
     }
 
     /// Retrieves the dispute with the given ID.
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
     /// * `id` - The id of the dispute you want to retrieve.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Fetch customer using id
-    /// let dispute = payup::stripe::Dispute::get(auth, "ch_")?;
+    /// let dispute = payup::stripe::Dispute::get(&client, format!("ch_"))?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn get(creds: Auth, id: String) -> Result<Self, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/files/{}", id.clone());
-        let request = reqwest::blocking::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send()?;
-        let json = request.json::<Self>()?;
-        return Ok(json);
+    pub fn get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/files/{}", id.clone());
+        client.get(&path)
     }
 
-    /// Returns all stripe disputes.
-    ///
+    /// Lazily iterates over files, fetching pages from the stripe api only as they are needed
+    /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::FileListParams with the filters, page size and the object to start from
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
-    /// // Fetch all customers from stripe
-    /// let charges = payup::stripe::Dispute::list(auth)?;
+    /// // Walk the 50 most recent files, fetching 25 per request
+    /// let mut params = payup::stripe::FileListParams::new();
+    /// params.limit = Some(25);
+    /// for file in payup::stripe::File::iter(&client, params).take(50) {
+    ///     let file = file?;
+    /// }
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn list(creds: Auth) -> Result<Vec<Self>, reqwest::Error>{
-        let mut objects: Vec<Self> = Vec::new();
-
-        let mut has_more = true;
-        let mut starting_after: Option<String> = None;
-        while has_more{
-            let json = Self::list_chunk(creds.clone(), starting_after)?;
-            for json_object in json.data{
-                objects.push(json_object);
-            }
-            has_more = json.has_more;
-            starting_after = Some(objects[objects.len() - 1].id.clone().unwrap());
-        }
-        return Ok(objects);
+    pub fn iter(client: &StripeClient, params: FileListParams) -> ListIter<Self> {
+        list::iter(client, "/files", &params)
     }
 
-
-    fn list_chunk(creds: Auth, starting_after: Option<String>) -> Result<Files, reqwest::Error> {
-        let mut url = "https://api.stripe.com/v1/files".to_string();
-
-        if starting_after.is_some() {
-            url = format!("https://api.stripe.com/v1/files?starting_after={}", starting_after.unwrap());
-        }
-
-        let request = reqwest::blocking::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send()?;
-
-        let json = request.json::<Files>()?;
-        return Ok(json);
+    /// Returns all stripe disputes.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::FileListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Fetch all files from stripe
+    /// let files = payup::stripe::File::list(&client, payup::stripe::FileListParams::new())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(client: &StripeClient, params: FileListParams) -> Result<Vec<Self>, StripeError>{
+        let params = FileListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::iter(client, params).collect()
     }
 
-    async fn list_chunk_async(creds: Auth, starting_after: Option<String>) -> Result<Files, reqwest::Error> {
-        let mut url = "https://api.stripe.com/v1/files".to_string();
-
-        if starting_after.is_some() {
-            url = format!("https://api.stripe.com/v1/files?starting_after={}", starting_after.unwrap());
-        }
-
-        let request = reqwest::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send().await?;
-
-        let json = request.json::<Files>().await?;
-        return Ok(json);
-    }
 
     fn to_multipart_form(&self) -> reqwest::blocking::multipart::Form {
         let mut form = reqwest::blocking::multipart::Form::new();
 
-        match &self.purpose{
-            Some(purpose) => {
+        if let Some(purpose) = &self.purpose {
                 form = form.text("purpose", purpose.clone());
-            },
-            None => {
-         
-            }
         }
 
-        match &self.file{
-            Some(file) => {
+        if let Some(file) = &self.file {
                 let part = reqwest::blocking::multipart::Part::bytes(file.clone());
                 form = form.part("file", part);
-             
-            },
-            None => {
-             
-            }
         }
 
-        return form;    
+        form    
     }
 
     async fn to_multipart_form_async(&self) -> reqwest::multipart::Form {
         let mut form = reqwest::multipart::Form::new();
 
-        match &self.purpose{
-            Some(purpose) => {
+        if let Some(purpose) = &self.purpose {
                 form = form.text("purpose", purpose.clone());
-            },
-            None => {
-         
-            }
         }
 
-        match &self.file{
-            Some(file) => {
+        if let Some(file) = &self.file {
                 let part = reqwest::multipart::Part::bytes(file.clone());
                 form = form.part("file", part);
-             
-            },
-            None => {
-             
-            }
         }
 
-        return form;    
+        form    
+    }
+
+}
+
+impl Object for File {
+    fn object_id(&self) -> Option<&str> {
+        self.id.as_deref()
     }
+}
 
+/// Filters and pagination for listing files with `File::list` and `File::iter`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FileListParams {
+    /// Only files created in this range.
+    pub created: Option<RangeQuery>,
+    /// Only files uploaded for this purpose, e.g. `dispute_evidence`.
+    pub purpose: Option<String>,
+    /// Number of objects fetched per page, between 1 and 100.
+    pub limit: Option<u32>,
+    /// Starts the list after the object with this id.
+    pub starting_after: Option<String>,
+    /// Starts the list before the object with this id and walks towards newer objects.
+    pub ending_before: Option<String>,
 }
+impl FileListParams {
+    pub fn new() -> Self {
+        FileListParams::default()
+    }
+}
+
 
 
 /// To share the contents of a File object with non-Stripe users, you can create a FileLink
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct FileLink {
     pub id: Option<String>,
     pub object: Option<String>,
@@ -2392,8 +3597,12 @@ pub struct FileLink {
     pub link_expires_at: Option<String>,
     pub file: Option<String>,
     pub livemode: Option<bool>,
-    // pub metadata: Metadata,
-    pub url: Option<String>
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    pub url: Option<String>,
+    /// Set when Stripe answered with the stored result of an earlier request with the same idempotency key.
+    #[serde(skip_serializing)]
+    pub idempotent_replayed: Option<bool>,
 }
 impl FileLink {
 
@@ -2401,22 +3610,15 @@ impl FileLink {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
     /// let mut file_link = payup::stripe::FileLink::new();
     /// file_link.file = Some(format!("file_"));
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn new() -> Self {
-        return FileLink {
-            id: None,
-            object: None,
-            created: None,
-            expired: None,
-            expires_at: None,
-            link_expires_at: None,
-            file: None,
-            livemode: None,
-            url: None
-        };
+        FileLink::default()
     }
 
 
@@ -2424,253 +3626,307 @@ impl FileLink {
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
     /// * `id` - The id of the file you want to retrieve.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Fetch customer using id
-    /// let file = payup::stripe::FileLink::async_get(auth, "link_").await?;
+    /// let file = payup::stripe::FileLink::async_get(&client, format!("link_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/file_links/{}", id.clone());
+        client.async_get(&path).await
+    }
+
+    /// Asynchronously and lazily iterates over file links, fetching pages from the stripe api only as they are needed
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::FileLinkListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Walk the 50 most recent file links, fetching 25 per request
+    /// let mut params = payup::stripe::FileLinkListParams::new();
+    /// params.limit = Some(25);
+    /// let mut file_links = payup::stripe::FileLink::async_iter(&client, params).take(50);
+    /// while let Some(file_link) = file_links.next().await {
+    ///     let file_link = file_link?;
+    /// }
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_get(creds: Auth, id: String) -> Result<Self, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/file_links/{}", id.clone());
-        let request = reqwest::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send().await?;
-        let json = request.json::<Self>().await?;
-        return Ok(json);
+    pub fn async_iter(client: &StripeClient, params: FileLinkListParams) -> ListStream<Self> {
+        list::stream(client, "/file_links", &params)
     }
 
     /// Asynchronously returns all stripe FileLinks.
     ///
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::FileLinkListParams with the filters to apply
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Fetch all customers from stripe
-    /// let file_links = payup::stripe::FileLink::async_list(auth).await?;
-    /// ```
-    pub async fn async_list(creds: Auth) -> Result<Vec<Self>, reqwest::Error>{
-        let mut objects: Vec<Self> = Vec::new();
-
-        let mut has_more = true;
-        let mut starting_after: Option<String> = None;
-        while has_more{
-            let json = Self::list_chunk_async(creds.clone(), starting_after).await?;
-            for json_object in json.data{
-                objects.push(json_object);
-            }
-            has_more = json.has_more;
-            starting_after = Some(objects[objects.len() - 1].id.clone().unwrap());
-        }
-        return Ok(objects);
+    /// let file_links = payup::stripe::FileLink::async_list(&client, payup::stripe::FileLinkListParams::new()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_list(client: &StripeClient, params: FileLinkListParams) -> Result<Vec<Self>, StripeError>{
+        let params = FileLinkListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::async_iter(client, params).try_collect().await
     }
 
     /// Asynchronously POSTs a new FileLink to the stripe api
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// let mut file_link = payup::stripe::FileLink::new();
     /// file_link.file = Some(format!("file_"));
     /// file_link.link_expires_at = Some(format!("1643341848"));
     ///
-    /// file_link = file_link.async_post(auth.clone()).await?;
+    /// file_link = file_link.async_post(&client).await?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_post(&self, creds: Auth) ->  Result<Self, reqwest::Error> {
-        let request = reqwest::Client::new()
-            .post("https://api.stripe.com/v1/file_links")
-            .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-            .form(&self.to_params())
-            .send().await?;
-
-        let json = request.json::<Self>().await?;
-        return Ok(json);
+    pub async fn async_post(&self, client: &StripeClient) ->  Result<Self, StripeError> {
+        client.async_post("/file_links", &self.to_params()).await
     }
 
     /// Asynchronously POSTs an update to an existing FileLink
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// let mut file_link = payup::stripe::FileLink::new();
     /// file_link.file = Some(format!("file_"));
     ///
-    /// file_link = file_link.async_post(auth.clone()).await?;
+    /// file_link = file_link.async_post(&client).await?;
     ///
     /// file_link.link_expires_at = Some(format!("1643341848"));
-    /// file_link = file_link.async_update(auth.clone()).await?;
+    /// file_link = file_link.async_update(&client).await?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_update(&self, creds: Auth) ->  Result<Self, reqwest::Error> {
-        let request = reqwest::Client::new().post(format!("https://api.stripe.com/v1/file_links/{}", self.clone().id.unwrap()))
-            .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-            .form(&self.to_params())
-            .send().await?;
-
-        let json = request.json::<Self>().await?;
-        return Ok(json);
+    pub async fn async_update(&self, client: &StripeClient) ->  Result<Self, StripeError> {
+        client.async_post(&format!("/file_links/{}", self.clone().id.unwrap()), &self.to_params()).await
     }
 
     /// Retrieves a file link with the given ID.
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
     /// * `id` - The id of the FileLink you want to retrieve.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Fetch customer using id
-    /// let file_link = payup::stripe::FileLink::get(auth, "ch_");
+    /// let file_link = payup::stripe::FileLink::get(&client, format!("ch_"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/file_links/{}", id.clone());
+        client.get(&path)
+    }
+
+    /// Lazily iterates over file links, fetching pages from the stripe api only as they are needed
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::FileLinkListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Walk the 50 most recent file links, fetching 25 per request
+    /// let mut params = payup::stripe::FileLinkListParams::new();
+    /// params.limit = Some(25);
+    /// for file_link in payup::stripe::FileLink::iter(&client, params).take(50) {
+    ///     let file_link = file_link?;
+    /// }
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn get(creds: Auth, id: String) -> Result<Self, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/file_links/{}", id.clone());
-        let request = reqwest::blocking::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send()?;
-        let json = request.json::<Self>()?;
-        return Ok(json);
+    pub fn iter(client: &StripeClient, params: FileLinkListParams) -> ListIter<Self> {
+        list::iter(client, "/file_links", &params)
     }
 
     /// Eeturns all stripe FileLinks.
     ///
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::FileLinkListParams with the filters to apply
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Fetch all customers from stripe
-    /// let file_links = payup::stripe::FileLink::list(auth)?;
-    /// ```
-    pub fn list(creds: Auth) -> Result<Vec<Self>, reqwest::Error>{
-        let mut objects: Vec<Self> = Vec::new();
-
-        let mut has_more = true;
-        let mut starting_after: Option<String> = None;
-        while has_more{
-            let json = Self::list_chunk(creds.clone(), starting_after)?;
-            for json_object in json.data{
-                objects.push(json_object);
-            }
-            has_more = json.has_more;
-            starting_after = Some(objects[objects.len() - 1].id.clone().unwrap());
-        }
-        return Ok(objects);
+    /// let file_links = payup::stripe::FileLink::list(&client, payup::stripe::FileLinkListParams::new())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(client: &StripeClient, params: FileLinkListParams) -> Result<Vec<Self>, StripeError>{
+        let params = FileLinkListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::iter(client, params).collect()
     }
 
     /// POSTs a new FileLink to the stripe api
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// let mut file_link = payup::stripe::FileLink::new();
     /// file_link.file = Some(format!("file_"));
     /// file_link.link_expires_at = Some(format!("1643341848"));
     ///
-    /// file_link = file_link.async_post(auth.clone())?;
+    /// file_link = file_link.post(&client)?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn post(&self, creds: Auth) ->  Result<Self, reqwest::Error> {
-        let request = reqwest::blocking::Client::new()
-            .post("https://api.stripe.com/v1/file_links")
-            .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-            .form(&self.to_params())
-            .send()?;
-
-        let json = request.json::<Self>()?;
-        return Ok(json);
+    pub fn post(&self, client: &StripeClient) ->  Result<Self, StripeError> {
+        client.post("/file_links", &self.to_params())
     }
 
     /// POSTs an update to an existing FileLink
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// let mut file_link = payup::stripe::FileLink::new();
     /// file_link.file = Some(format!("file_"));
     ///
-    /// file_link = file_link.post(auth.clone())?;
+    /// file_link = file_link.post(&client)?;
     ///
     /// file_link.link_expires_at = Some(format!("1643341848"));
-    /// file_link = file_link.update(auth.clone())?;
+    /// file_link = file_link.update(&client)?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn update(&self, creds: Auth) ->  Result<Self, reqwest::Error> {
-        let request = reqwest::blocking::Client::new().post(format!("https://api.stripe.com/v1/file_links/{}", self.clone().id.unwrap()))
-            .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-            .form(&self.to_params())
-            .send()?;
-
-        let json = request.json::<Self>()?;
-        return Ok(json);
+    pub fn update(&self, client: &StripeClient) ->  Result<Self, StripeError> {
+        client.post(&format!("/file_links/{}", self.clone().id.unwrap()), &self.to_params())
     }
 
-    fn list_chunk(creds: Auth, starting_after: Option<String>) -> Result<FileLinks, reqwest::Error> {
-        let mut url = "https://api.stripe.com/v1/file_links".to_string();
-
-        if starting_after.is_some() {
-            url = format!("https://api.stripe.com/v1/file_links?starting_after={}", starting_after.unwrap());
+    fn to_params(&self) -> FileLinkParams<'_> {
+        FileLinkParams {
+            expires_at: &self.link_expires_at,
+            file: &self.file,
+            metadata: &self.metadata,
         }
-
-        let request = reqwest::blocking::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send()?;
-
-        let json = request.json::<FileLinks>()?;
-        return Ok(json);
     }
 
-    async fn list_chunk_async(creds: Auth, starting_after: Option<String>) -> Result<FileLinks, reqwest::Error> {
-        let mut url = "https://api.stripe.com/v1/file_links".to_string();
-
-        if starting_after.is_some() {
-            url = format!("https://api.stripe.com/v1/file_links?starting_after={}", starting_after.unwrap());
-        }
-
-        let request = reqwest::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send().await?;
+}
 
-        let json = request.json::<FileLinks>().await?;
-        return Ok(json);
+impl Object for FileLink {
+    fn object_id(&self) -> Option<&str> {
+        self.id.as_deref()
     }
+}
 
-    fn to_params(&self) -> Vec<(&str, &str)> {
-        let mut params = vec![];
-        match &self.file{
-            Some(file) => params.push(("file", file.as_str())),
-            None => {}
-        }
-        match &self.link_expires_at{
-            Some(link_expires_at) => params.push(("expires_at", link_expires_at.as_str())),
-            None => {}
-        }
-
-        return params;
+/// Filters and pagination for listing file links with `FileLink::list` and `FileLink::iter`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FileLinkListParams {
+    /// Only file links created in this range.
+    pub created: Option<RangeQuery>,
+    /// Only expired (true) or unexpired (false) file links.
+    pub expired: Option<bool>,
+    /// Only links to this file id.
+    pub file: Option<String>,
+    /// Number of objects fetched per page, between 1 and 100.
+    pub limit: Option<u32>,
+    /// Starts the list after the object with this id.
+    pub starting_after: Option<String>,
+    /// Starts the list before the object with this id and walks towards newer objects.
+    pub ending_before: Option<String>,
+}
+impl FileLinkListParams {
+    pub fn new() -> Self {
+        FileLinkListParams::default()
     }
-
 }
 
+
 // TODO - Finish Implementation
 /// Invoices are statements of amounts owed by a customer.
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct Invoice {
     pub id: Option<String>,
     pub object: Option<String>,
@@ -2679,7 +3935,7 @@ pub struct Invoice {
     #[serde(rename = "account_name")]
     pub account_name: Option<String>,
     #[serde(rename = "account_tax_ids")]
-    pub account_tax_ids: Option<String>,
+    pub account_tax_ids: Option<Vec<String>>,
     #[serde(rename = "amount_due")]
     pub amount_due: Option<i64>,
     #[serde(rename = "amount_paid")]
@@ -2700,13 +3956,15 @@ pub struct Invoice {
     // pub charge: Value,
     #[serde(rename = "collection_method")]
     pub collection_method: Option<String>,
+    /// Id of a coupon to apply to the invoice. Only sent when creating or updating.
+    pub coupon: Option<String>,
     pub created: Option<i64>,
     pub currency: Option<String>,
     // #[serde(rename = "custom_fields")]
     // pub custom_fields: Value,
-    pub customer: Option<String>,
+    pub customer: Option<Expandable<Customer>>,
     #[serde(rename = "customer_address")]
-    pub customer_address: Option<String>,
+    pub customer_address: Option<Address>,
     #[serde(rename = "customer_email")]
     pub customer_email: Option<String>,
     #[serde(rename = "customer_name")]
@@ -2714,19 +3972,21 @@ pub struct Invoice {
     #[serde(rename = "customer_phone")]
     pub customer_phone: Option<String>,
     #[serde(rename = "customer_shipping")]
-    pub customer_shipping: Option<String>,
+    pub customer_shipping: Option<CustomerShipping>,
     #[serde(rename = "customer_tax_exempt")]
     pub customer_tax_exempt: Option<String>,
     #[serde(rename = "customer_tax_ids")]
-    pub customer_tax_ids: Option<Vec<String>>,
+    pub customer_tax_ids: Option<Vec<CustomerTaxId>>,
     #[serde(rename = "default_payment_method")]
     pub default_payment_method: Option<String>,
     #[serde(rename = "default_source")]
     pub default_source: Option<String>,
     // #[serde(rename = "default_tax_rates")]
     // pub default_tax_rates: Option<Vec<String>>,
+    /// Set once a draft invoice was deleted.
+    pub deleted: Option<bool>,
     pub description: Option<String>,
-    // pub discount: Value,
+    pub discount: Option<Discount>,
     // pub discounts: Vec<Value>,
     // #[serde(rename = "due_date")]
     // pub due_date: Value,
@@ -2741,7 +4001,8 @@ pub struct Invoice {
     // pub last_finalization_error: Value,
     pub lines: Option<InvoiceLines>,
     pub livemode: Option<bool>,
-    // pub metadata: Metadata3,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
     #[serde(rename = "next_payment_attempt")]
     pub next_payment_attempt: Option<i64>,
     // pub number: Value,
@@ -2762,6 +4023,8 @@ pub struct Invoice {
     pub post_payment_credit_notes_amount: Option<i64>,
     #[serde(rename = "pre_payment_credit_notes_amount")]
     pub pre_payment_credit_notes_amount: Option<i64>,
+    /// Id of a promotion code to apply to the invoice. Only sent when creating or updating.
+    pub promotion_code: Option<String>,
     // pub quote: Value,
     // #[serde(rename = "receipt_number")]
     // pub receipt_number: Value,
@@ -2774,7 +4037,7 @@ pub struct Invoice {
     pub status_transitions: Option<StatusTransitions>,
     // pub subscription: Value,
     pub subtotal: Option<i64>,
-    pub subscription: Option<String>,
+    pub subscription: Option<Expandable<Subscription>>,
     
     // pub tax: Value,
     pub total: Option<i64>,
@@ -2786,6 +4049,9 @@ pub struct Invoice {
     // pub transfer_data: Value,
     // #[serde(rename = "webhooks_delivered_at")]
     // pub webhooks_delivered_at: Value,
+    /// Set when Stripe answered with the stored result of an earlier request with the same idempotency key.
+    #[serde(skip_serializing)]
+    pub idempotent_replayed: Option<bool>,
 }
 impl Invoice {
 
@@ -2793,967 +4059,8202 @@ impl Invoice {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
     /// let mut invoice = payup::stripe::Invoice::new();
-    /// invoice.customer = Some(format!("cust_"));
+    /// invoice.customer = Some(format!("cust_").into());
     /// invoice.collection_method = Some(format!("charge_automatically"));
     /// invoice.auto_advance = Some(true);
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn new() -> Self {
-        return Invoice{
-            id: None,
-            object: None,
-            account_country: None,
-            account_name: None,
-            account_tax_ids: None,
-            amount_due: None,
-            amount_paid: None,
-            amount_remaining: None,
-            application_fee_amount: None,
-            attempt_count: None,
-            attempted: None,
-            auto_advance: None,
-            billing_reason: None,
-            collection_method: None,
-            created: None,
-            currency: None,
-            customer: None,
-            customer_address: None,
-            customer_email: None,
-            customer_name: None,
-            customer_phone: None,
-            customer_shipping: None,
-            customer_tax_exempt: None,
-            customer_tax_ids: None,
-            default_payment_method: None,
-            default_source: None,
-            description: None,
-            hosted_invoice_url: None,
-            invoice_pdf: None,
-            lines: None,
-            livemode: None,
-            next_payment_attempt: None,
-            paid: None,
-            paid_out_of_band: None,
-            payment_settings: None,
-            period_end: None,
-            period_start: None,
-            post_payment_credit_notes_amount: None,
-            pre_payment_credit_notes_amount: None,
-            subscription: None,
-            status: None,
-            starting_balance: None,
-            status_transitions: None,
-            subtotal: None,
-            total: None
-        };
+        Invoice::default()
     }
 
-    /// Asynchronously retrieves the Invoice by the id.
-    /// 
+    /// Asynchronously deletes a draft invoice, finalized invoices can only be voided.
+    ///
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
-    /// * `id` - The id of the invoice you want to retrieve.
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the draft invoice you want to delete.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
-    /// // Fetch customer using id
-    /// let invoice = payup::stripe::Invoice::async_get(auth, "in_").await?;
+    /// let invoice = payup::stripe::Invoice::async_delete(&client, format!("in_")).await?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_get(creds: Auth, id: String) -> Result<Self, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/invoices/{}", id.clone());
-        let request = reqwest::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send().await?;
-        let json = request.json::<Self>().await?;
-        return Ok(json);
+    pub async fn async_delete(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/invoices/{}", id.clone());
+        client.async_delete(&path).await
     }
 
-    /// Asynchronously returns all stripe Invoices.
+    /// Asynchronously finalizes a draft invoice so it can be paid or sent, `auto_advance` controls whether Stripe collects it on its own.
     ///
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
-    /// * `status` - Ex: draft, open, paid, uncollectible, void (optional)
-    /// * `customer` - Limit to invoices belonging to a Customer's id (optional)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
-    /// // Fetch all customers from stripe
-    /// let invoices = payup::stripe::Invoice::async_list(auth).await?;
+    /// let mut invoice = payup::stripe::Invoice::async_get(&client, format!("in_")).await?;
+    /// invoice.auto_advance = Some(false);
+    /// let invoice = invoice.async_finalize(&client).await?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_list(creds: Auth, status: Option<String>, customer: Option<String>) -> Result<Vec<Self>, reqwest::Error>{
-        let mut objects: Vec<Self> = Vec::new();
-
-        let mut has_more = true;
-        let mut starting_after: Option<String> = None;
-        while has_more{
-            let json = Self::list_chunk_async(creds.clone(), starting_after, status.clone(), customer.clone()).await?;
-            for json_object in json.data{
-                objects.push(json_object);
-            }
-            has_more = json.has_more;
-            starting_after = Some(objects[objects.len() - 1].id.clone().unwrap());
-        }
-        return Ok(objects);
+    pub async fn async_finalize(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/invoices/{}/finalize", id);
+        client.async_post(&path, &self.to_finalize_params()).await
     }
 
-    /// Asynchronously POSTs a new Invoice to the stripe api
-    /// # Examples
+    /// Asynchronously retrieves the Invoice by the id.
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the invoice you want to retrieve.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
+    /// // Fetch customer using id
+    /// let invoice = payup::stripe::Invoice::async_get(&client, format!("in_")).await?;
+    /// # Ok(())
+    /// # }
     /// ```
+    pub async fn async_get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/invoices/{}", id.clone());
+        client.async_get(&path).await
+    }
+
+    /// Asynchronously and lazily iterates over invoices, fetching pages from the stripe api only as they are needed
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::InvoiceListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
-    /// let mut invoice = payup::stripe::Invoice::new();
-    /// invoice.customer = Some(format!("cust_"));
-    /// invoice.collection_method = Some(format!("charge_automatically"));
-    /// invoice.auto_advance = Some(true);
-    /// invoice = invoice.async_post(auth).await?;
+    /// // Walk the 50 most recent invoices, fetching 25 per request
+    /// let mut params = payup::stripe::InvoiceListParams::new();
+    /// params.limit = Some(25);
+    /// let mut invoices = payup::stripe::Invoice::async_iter(&client, params).take(50);
+    /// while let Some(invoice) = invoices.next().await {
+    ///     let invoice = invoice?;
+    /// }
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_post(&self, creds: Auth) ->  Result<Self, reqwest::Error> {
-        let request = reqwest::Client::new()
-            .post("https://api.stripe.com/v1/invoices")
-            .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-            .form(&self.to_params())
-            .send().await?;
+    pub fn async_iter(client: &StripeClient, params: InvoiceListParams) -> ListStream<Self> {
+        list::stream(client, "/invoices", &params)
+    }
+
+    /// Asynchronously returns all stripe Invoices.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::InvoiceListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Fetch all customers from stripe
+    /// let invoices = payup::stripe::Invoice::async_list(&client, payup::stripe::InvoiceListParams::new()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_list(client: &StripeClient, params: InvoiceListParams) -> Result<Vec<Self>, StripeError>{
+        let params = InvoiceListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::async_iter(client, params).try_collect().await
+    }
 
-        let json = request.json::<Self>().await?;
-        return Ok(json);
+    /// Asynchronously marks an open invoice as uncollectible, e.g. to write off a debt.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let invoice = payup::stripe::Invoice::async_get(&client, format!("in_")).await?;
+    /// let invoice = invoice.async_mark_uncollectible(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_mark_uncollectible(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/invoices/{}/mark_uncollectible", id);
+        client.async_post(&path, &()).await
     }
 
-    /// Asynchronously POSTs an update to an existing Invoice
+    /// Asynchronously pays an open invoice right away instead of waiting for the next automatic attempt.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::InvoicePayParams with the payment method to charge, or how it was paid otherwise
+    ///
     /// # Examples
     ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let invoice = payup::stripe::Invoice::async_get(&client, format!("in_")).await?;
+    ///
+    /// // Charge another card of the customer
+    /// let mut params = payup::stripe::InvoicePayParams::new();
+    /// params.payment_method = Some(format!("pm_"));
+    /// let invoice = invoice.async_pay(&client, params).await?;
+    ///
+    /// // Or record a payment made by check
+    /// let params = payup::stripe::InvoicePayParams::paid_out_of_band();
+    /// let invoice = invoice.async_pay(&client, params).await?;
+    /// # Ok(())
+    /// # }
     /// ```
+    pub async fn async_pay(&self, client: &StripeClient, params: InvoicePayParams) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/invoices/{}/pay", id);
+        client.async_post(&path, &params).await
+    }
+
+    /// Asynchronously POSTs a new Invoice to the stripe api
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// let mut invoice = payup::stripe::Invoice::new();
-    /// invoice.customer = Some(format!("cust_"));
+    /// invoice.customer = Some(format!("cust_").into());
     /// invoice.collection_method = Some(format!("charge_automatically"));
-    /// invoice.auto_advance = Some(false);
-    /// invoice = invoice.async_post(auth).await?;
-    ///
     /// invoice.auto_advance = Some(true);
-    /// invoice = invoice.async_update(auth).await?;
+    /// invoice = invoice.async_post(&client).await?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_update(&self, creds: Auth) ->  Result<Self, reqwest::Error> {
-        let request = reqwest::Client::new().post(format!("https://api.stripe.com/v1/invoices/{}", self.clone().id.unwrap()))
-            .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-            .form(&self.to_params())
-            .send().await?;
-
-        let json = request.json::<Self>().await?;
-        return Ok(json);
+    pub async fn async_post(&self, client: &StripeClient) ->  Result<Self, StripeError> {
+        client.async_post("/invoices", &self.to_create_params()).await
     }
 
-    /// Retrieves the details of an Invoice by the id.
+    /// Asynchronously and lazily iterates over the invoices matching a search query, e.g. by metadata
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
-    /// * `id` - The id of the invoice you want to retrieve.
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SearchParams holding the query
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
-    /// // Fetch customer using id
-    /// let invoice = payup::stripe::Invoice::get(auth, "in_");
+    /// // Find the invoices of an order
+    /// let params = payup::stripe::SearchParams::metadata("order_id", "6735");
+    /// let mut invoices = payup::stripe::Invoice::async_search(&client, params);
+    /// while let Some(invoice) = invoices.next().await {
+    ///     let invoice = invoice?;
+    /// }
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn get(creds: Auth, id: String) -> Result<Self, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/invoices/{}", id.clone());
-        let request = reqwest::blocking::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send()?;
-        let json = request.json::<Self>()?;
-        return Ok(json);
+    pub fn async_search(client: &StripeClient, params: SearchParams) -> ListStream<Self> {
+        list::search_stream(client, "/invoices/search", &params)
     }
 
-    /// Returns all stripe invoices.
+    /// Asynchronously emails an open invoice collected with `send_invoice` to the customer.
     ///
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
-    /// * `status` - Ex: draft, open, paid, uncollectible, void (optional)
-    /// * `customer` - Limit to invoices belonging to a Customer's id (optional)
+    /// * `client` - payup::stripe::StripeClient::new(auth)
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
-    /// // Fetch all customers from stripe
-    /// let invoices = payup::stripe::Invoice::list(auth)?;
+    /// let invoice = payup::stripe::Invoice::async_get(&client, format!("in_")).await?;
+    /// let invoice = invoice.async_send_invoice(&client).await?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn list(creds: Auth, status: Option<String>, customer: Option<String>) -> Result<Vec<Self>, reqwest::Error>{
-        let mut objects: Vec<Self> = Vec::new();
-
-        let mut has_more = true;
-        let mut starting_after: Option<String> = None;
-        while has_more{
-            let json = Self::list_chunk(creds.clone(), starting_after, status.clone(), customer.clone())?;
-            for json_object in json.data{
-                objects.push(json_object);
-            }
-            has_more = json.has_more;
-            starting_after = Some(objects[objects.len() - 1].id.clone().unwrap());
-        }
-        return Ok(objects);
+    pub async fn async_send_invoice(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/invoices/{}/send", id);
+        client.async_post(&path, &()).await
     }
 
-    /// POSTs a new Invoice to the stripe api
+    /// Asynchronously previews the next invoice of a customer, e.g. to quote the proration of a subscription change before making it.
+    ///
+    /// The invoice is not created, it has no id and can not be paid.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::UpcomingInvoiceParams with the customer or subscription and the changes to preview
+    ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
-    /// let mut invoice = payup::stripe::Invoice::new();
-    /// invoice.customer = Some(format!("cust_"));
-    /// invoice.collection_method = Some(format!("charge_automatically"));
-    /// invoice.auto_advance = Some(true);
-    /// invoice = invoice.post(auth)?;
+    /// // Preview what switching to the gold plan costs
+    /// let subscription = payup::stripe::Subscription::async_get(&client, format!("sub_")).await?;
+    /// let mut params = payup::stripe::UpcomingInvoiceParams::from_subscription(&subscription);
+    /// for item in params.subscription_items.iter_mut() {
+    ///     item.price = Some(format!("price_gold").into());
+    /// }
+    /// params.subscription_proration_date = Some(1640995200);
+    ///
+    /// let invoice = payup::stripe::Invoice::async_upcoming(&client, params).await?;
+    /// println!("due: {:?}", invoice.amount_due);
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn post(&self, creds: Auth) ->  Result<Self, reqwest::Error> {
-        let request = reqwest::blocking::Client::new()
-            .post("https://api.stripe.com/v1/invoices")
-            .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-            .form(&self.to_params())
-            .send()?;
-
-        let json = request.json::<Self>()?;
-        return Ok(json);
+    pub async fn async_upcoming(client: &StripeClient, params: UpcomingInvoiceParams) -> Result<Self, StripeError> {
+        let query = form::to_pairs(&params.to_query())?;
+        client.async_get_query("/invoices/upcoming", &query).await
     }
 
-    /// POSTs an update to an existing Charge
+    /// Asynchronously returns the line items of the next invoice of a customer, one page at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::UpcomingInvoiceParams with the customer or subscription, the changes to preview and the page to fetch
+    ///
     /// # Examples
     ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut params = payup::stripe::UpcomingInvoiceParams::new();
+    /// params.customer = Some(format!("cus_"));
+    /// params.limit = Some(100);
+    ///
+    /// let lines = payup::stripe::Invoice::async_upcoming_lines(&client, params).await?;
+    /// for line in lines.data {
+    ///     println!("{:?}: {:?}", line.description, line.amount);
+    /// }
+    /// # Ok(())
+    /// # }
     /// ```
+    pub async fn async_upcoming_lines(client: &StripeClient, params: UpcomingInvoiceParams) -> Result<InvoiceLines, StripeError> {
+        let query = form::to_pairs(&params.to_lines_query())?;
+        client.async_get_query("/invoices/upcoming/lines", &query).await
+    }
+
+    /// Asynchronously POSTs an update to an existing Invoice
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// let mut invoice = payup::stripe::Invoice::new();
-    /// invoice.customer = Some(format!("cust_"));
+    /// invoice.customer = Some(format!("cust_").into());
     /// invoice.collection_method = Some(format!("charge_automatically"));
     /// invoice.auto_advance = Some(false);
-    /// invoice = invoice.post(auth)?;
+    /// invoice = invoice.async_post(&client).await?;
     ///
     /// invoice.auto_advance = Some(true);
-    /// invoice = invoice.update(auth)?;
+    /// invoice = invoice.async_update(&client).await?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn update(&self, creds: Auth) ->  Result<Self, reqwest::Error> {
-        let request = reqwest::blocking::Client::new().post(format!("https://api.stripe.com/v1/invoices/{}", self.clone().id.unwrap()))
-            .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-            .form(&self.to_params())
-            .send()?;
-
-        let json = request.json::<Self>()?;
-        return Ok(json);
+    pub async fn async_update(&self, client: &StripeClient) ->  Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        client.async_post(&format!("/invoices/{}", id), &self.to_params()).await
     }
 
-    fn list_chunk(creds: Auth, starting_after: Option<String>, status: Option<String>, customer: Option<String>) -> Result<Invoices, reqwest::Error> {
-        let mut url = "https://api.stripe.com/v1/invoices".to_string();
-
-        if starting_after.is_some() {
-            url = format!("https://api.stripe.com/v1/invoices?starting_after={}", starting_after.unwrap());
-        }
-
-        if status.is_some(){
-            if url.contains("?"){
-                url = format!("{}{}={}", url, "&status", status.unwrap());
-            } else {
-                url = format!("{}{}={}", url, "?status", status.unwrap());
-            }
-        }
-
-        if customer.is_some(){
-            if url.contains("?"){
-                url = format!("{}{}={}", url, "&customer", customer.unwrap());
-            } else {
-                url = format!("{}{}={}", url, "?customer", customer.unwrap());
-            }
-        }
-
-        let request = reqwest::blocking::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send()?;
-
-        let json = request.json::<Invoices>()?;
-        return Ok(json);
+    /// Asynchronously voids an open invoice, it stays on record but can no longer be paid.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let invoice = payup::stripe::Invoice::async_get(&client, format!("in_")).await?;
+    /// let invoice = invoice.async_void(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_void(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/invoices/{}/void", id);
+        client.async_post(&path, &()).await
     }
 
-    async fn list_chunk_async(creds: Auth, starting_after: Option<String>, status: Option<String>, customer: Option<String>) -> Result<Invoices, reqwest::Error> {
-        let mut url = "https://api.stripe.com/v1/invoices".to_string();
-
-        if starting_after.is_some() {
-            url = format!("https://api.stripe.com/v1/invoices?starting_after={}", starting_after.unwrap());
-        }
-
-        if status.is_some(){
-            if url.contains("?"){
-                url = format!("{}{}={}", url, "&status", status.unwrap());
-            } else {
-                url = format!("{}{}={}", url, "?status", status.unwrap());
-            }
-        }
-
-        if customer.is_some(){
-            if url.contains("?"){
-                url = format!("{}{}={}", url, "&customer", customer.unwrap());
-            } else {
-                url = format!("{}{}={}", url, "?customer", customer.unwrap());
-            }
-        }
-
-        let request = reqwest::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send().await?;
-
-        let json = request.json::<Invoices>().await?;
-        return Ok(json);
+    /// Deletes a draft invoice, finalized invoices can only be voided.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the draft invoice you want to delete.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let invoice = payup::stripe::Invoice::delete(&client, format!("in_"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/invoices/{}", id.clone());
+        client.delete(&path)
     }
 
-    fn to_params(&self) -> Vec<(&str, &str)> {
-        let mut params = vec![];
-        match &self.customer{
-            Some(customer) => params.push(("customer", customer.as_str())),
-            None => {}
-        }
-        // match &self.auto_advance{
-        //     Some(auto_advance) => params.push(("auto_advance", auto_advance.as_str())),
-        //     None => {}
-        // }
-        match &self.collection_method{
-            Some(collection_method) => params.push(("collection_method", collection_method.as_str())),
-            None => {}
-        }
-        match &self.description{
-            Some(description) => params.push(("description", description.as_str())),
-            None => {}
-        }
-        // match &self.metadata{
-        //     Some(metadata) => params.push(("metadata", metadata.as_str())),
-        //     None => {}
-        // }
-
-        match &self.subscription{
-            Some(subscription) => params.push(("subscription", subscription.as_str())),
-            None => {}
-        }
-
-        return params;
+    /// Finalizes a draft invoice so it can be paid or sent, `auto_advance` controls whether Stripe collects it on its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut invoice = payup::stripe::Invoice::get(&client, format!("in_"))?;
+    /// invoice.auto_advance = Some(false);
+    /// let invoice = invoice.finalize(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn finalize(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/invoices/{}/finalize", id);
+        client.post(&path, &self.to_finalize_params())
     }
 
-}
-
-/// A Mandate is a record of the permission a customer has given you to debit their payment method.
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct Mandate {
-    pub id: String,
-    pub object: String,
-    #[serde(rename = "customer_acceptance")]
-    pub customer_acceptance: CustomerAcceptance,
-    pub livemode: bool,
-    // #[serde(rename = "multi_use")]
-    // pub multi_use: MultiUse,
-    #[serde(rename = "payment_method")]
-    pub payment_method: String,
-    #[serde(rename = "payment_method_details")]
-    pub payment_method_details: PaymentMethodDetails,
-    pub status: String,
-    #[serde(rename = "type")]
-    pub type_field: String,
-}
-impl Mandate {
-    /// Asynchronously retrieves a Mandate with the given ID.
+    /// Retrieves the details of an Invoice by the id.
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
-    /// * `id` - The id of the Mandate you want to retrieve.
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the invoice you want to retrieve.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
     /// // Fetch customer using id
-    /// let file = payup::stripe::Mandate::async_get(auth, "mandate_").await?;
+    /// let invoice = payup::stripe::Invoice::get(&client, format!("in_"));
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn async_get(creds: Auth, id: String) -> Result<Self, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/file_links/{}", id.clone());
-        let request = reqwest::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send().await?;
-        let json = request.json::<Self>().await?;
-        return Ok(json);
+    pub fn get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/invoices/{}", id.clone());
+        client.get(&path)
     }
 
-
-    /// Retrieves a Mandate with the given ID.
+    /// Lazily iterates over invoices, fetching pages from the stripe api only as they are needed
     /// 
     /// # Arguments
     ///
-    /// * `auth` - payup::stripe::Auth::new(client, secret)
-    /// * `id` - The id of the Mandate you want to retrieve.
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::InvoiceListParams with the filters, page size and the object to start from
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
     /// // Create the Authentication refererence
     /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
     ///
-    /// // Fetch customer using id
-    /// let mandate = payup::stripe::Mandate::get(auth, "mandate_")?;
+    /// // Walk the 50 most recent invoices, fetching 25 per request
+    /// let mut params = payup::stripe::InvoiceListParams::new();
+    /// params.limit = Some(25);
+    /// for invoice in payup::stripe::Invoice::iter(&client, params).take(50) {
+    ///     let invoice = invoice?;
+    /// }
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn get(creds: Auth, id: String) -> Result<Self, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/file_links/{}", id.clone());
-        let request = reqwest::blocking::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send()?;
-        let json = request.json::<Self>()?;
-        return Ok(json);
+    pub fn iter(client: &StripeClient, params: InvoiceListParams) -> ListIter<Self> {
+        list::iter(client, "/invoices", &params)
     }
-}
 
+    /// Returns all stripe invoices.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::InvoiceListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Fetch all open invoices from stripe
+    /// let mut params = payup::stripe::InvoiceListParams::new();
+    /// params.status = Some(format!("open"));
+    /// let invoices = payup::stripe::Invoice::list(&client, params)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(client: &StripeClient, params: InvoiceListParams) -> Result<Vec<Self>, StripeError>{
+        let params = InvoiceListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::iter(client, params).collect()
+    }
 
+    /// Marks an open invoice as uncollectible, e.g. to write off a debt.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let invoice = payup::stripe::Invoice::get(&client, format!("in_"))?;
+    /// let invoice = invoice.mark_uncollectible(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn mark_uncollectible(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/invoices/{}/mark_uncollectible", id);
+        client.post(&path, &())
+    }
 
+    /// Pays an open invoice right away instead of waiting for the next automatic attempt.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::InvoicePayParams with the payment method to charge, or how it was paid otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let invoice = payup::stripe::Invoice::get(&client, format!("in_"))?;
+    ///
+    /// // Charge another card of the customer
+    /// let mut params = payup::stripe::InvoicePayParams::new();
+    /// params.payment_method = Some(format!("pm_"));
+    /// let invoice = invoice.pay(&client, params)?;
+    ///
+    /// // Or record a payment made by check
+    /// let params = payup::stripe::InvoicePayParams::paid_out_of_band();
+    /// let invoice = invoice.pay(&client, params)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn pay(&self, client: &StripeClient, params: InvoicePayParams) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/invoices/{}/pay", id);
+        client.post(&path, &params)
+    }
 
+    /// POSTs a new Invoice to the stripe api
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut invoice = payup::stripe::Invoice::new();
+    /// invoice.customer = Some(format!("cust_").into());
+    /// invoice.collection_method = Some(format!("charge_automatically"));
+    /// invoice.auto_advance = Some(true);
+    /// invoice = invoice.post(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn post(&self, client: &StripeClient) ->  Result<Self, StripeError> {
+        client.post("/invoices", &self.to_create_params())
+    }
 
-// TODO - Finish Implementation
-/// PaymentMethod objects represent your customer's payment instruments.
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct PaymentMethod {
-    pub id: Option<String>,
-    pub method_type: Option<String>,
-    pub created: Option<String>,
-    pub customer: Option<String>,
-    pub livemode:  Option<bool>,
-    pub name: Option<String>,
-    pub phone: Option<String>,
-    pub billing_details: Option<crate::stripe::response::BillingDetails>,
-    pub card: Option<Card>,
-    pub type_field: Option<String>,
-}
+    /// Lazily iterates over the invoices matching a search query, e.g. by metadata
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SearchParams holding the query
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Find the invoices of an order
+    /// let params = payup::stripe::SearchParams::metadata("order_id", "6735");
+    /// for invoice in payup::stripe::Invoice::search(&client, params) {
+    ///     let invoice = invoice?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search(client: &StripeClient, params: SearchParams) -> ListIter<Self> {
+        list::search(client, "/invoices/search", &params)
+    }
+
+    /// Emails an open invoice collected with `send_invoice` to the customer.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let invoice = payup::stripe::Invoice::get(&client, format!("in_"))?;
+    /// let invoice = invoice.send_invoice(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_invoice(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/invoices/{}/send", id);
+        client.post(&path, &())
+    }
+
+    /// Previews the next invoice of a customer, e.g. to quote the proration of a subscription change before making it.
+    ///
+    /// The invoice is not created, it has no id and can not be paid.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::UpcomingInvoiceParams with the customer or subscription and the changes to preview
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Preview what switching to the gold plan costs
+    /// let subscription = payup::stripe::Subscription::get(&client, format!("sub_"))?;
+    /// let mut params = payup::stripe::UpcomingInvoiceParams::from_subscription(&subscription);
+    /// for item in params.subscription_items.iter_mut() {
+    ///     item.price = Some(format!("price_gold").into());
+    /// }
+    /// params.subscription_proration_date = Some(1640995200);
+    ///
+    /// let invoice = payup::stripe::Invoice::upcoming(&client, params)?;
+    /// println!("due: {:?}", invoice.amount_due);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn upcoming(client: &StripeClient, params: UpcomingInvoiceParams) -> Result<Self, StripeError> {
+        let query = form::to_pairs(&params.to_query())?;
+        client.get_query("/invoices/upcoming", &query)
+    }
+
+    /// Returns the line items of the next invoice of a customer, one page at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::UpcomingInvoiceParams with the customer or subscription, the changes to preview and the page to fetch
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut params = payup::stripe::UpcomingInvoiceParams::new();
+    /// params.customer = Some(format!("cus_"));
+    /// params.limit = Some(100);
+    ///
+    /// let lines = payup::stripe::Invoice::upcoming_lines(&client, params)?;
+    /// for line in lines.data {
+    ///     println!("{:?}: {:?}", line.description, line.amount);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn upcoming_lines(client: &StripeClient, params: UpcomingInvoiceParams) -> Result<InvoiceLines, StripeError> {
+        let query = form::to_pairs(&params.to_lines_query())?;
+        client.get_query("/invoices/upcoming/lines", &query)
+    }
+
+    /// POSTs an update to an existing Charge
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut invoice = payup::stripe::Invoice::new();
+    /// invoice.customer = Some(format!("cust_").into());
+    /// invoice.collection_method = Some(format!("charge_automatically"));
+    /// invoice.auto_advance = Some(false);
+    /// invoice = invoice.post(&client)?;
+    ///
+    /// invoice.auto_advance = Some(true);
+    /// invoice = invoice.update(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self, client: &StripeClient) ->  Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        client.post(&format!("/invoices/{}", id), &self.to_params())
+    }
+
+    /// Voids an open invoice, it stays on record but can no longer be paid.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let invoice = payup::stripe::Invoice::get(&client, format!("in_"))?;
+    /// let invoice = invoice.void(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn void(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/invoices/{}/void", id);
+        client.post(&path, &())
+    }
+    fn to_create_params(&self) -> InvoiceCreateParams<'_> {
+        InvoiceCreateParams {
+            params: self.to_params(),
+            customer: self.customer.as_ref().and_then(Expandable::id),
+            subscription: self.subscription.as_ref().and_then(Expandable::id),
+        }
+    }
+    fn to_finalize_params(&self) -> InvoiceFinalizeParams<'_> {
+        InvoiceFinalizeParams {
+            auto_advance: &self.auto_advance,
+        }
+    }
+    fn to_params(&self) -> InvoiceParams<'_> {
+        let mut discounts = Vec::new();
+        if let Some(coupon) = &self.coupon {
+            discounts.push(InvoiceDiscountParams { coupon: Some(coupon), promotion_code: None });
+        }
+        if let Some(promotion_code) = &self.promotion_code {
+            discounts.push(InvoiceDiscountParams { coupon: None, promotion_code: Some(promotion_code) });
+        }
+
+        InvoiceParams {
+            auto_advance: &self.auto_advance,
+            collection_method: &self.collection_method,
+            description: &self.description,
+            discounts: if discounts.is_empty() { None } else { Some(discounts) },
+            metadata: &self.metadata,
+        }
+    }
+
+}
+
+impl Object for Invoice {
+    fn object_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+}
+
+/// Filters and pagination for listing invoices with `Invoice::list` and `Invoice::iter`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct InvoiceListParams {
+    /// Only invoices collected with this method, `charge_automatically` or `send_invoice`.
+    pub collection_method: Option<String>,
+    /// Only invoices created in this range.
+    pub created: Option<RangeQuery>,
+    /// Only invoices for this customer id.
+    pub customer: Option<String>,
+    /// Only invoices due in this range.
+    pub due_date: Option<RangeQuery>,
+    /// Only invoices with this status: `draft`, `open`, `paid`, `uncollectible` or `void`.
+    pub status: Option<String>,
+    /// Only invoices for this subscription id.
+    pub subscription: Option<String>,
+    /// Number of objects fetched per page, between 1 and 100.
+    pub limit: Option<u32>,
+    /// Starts the list after the object with this id.
+    pub starting_after: Option<String>,
+    /// Starts the list before the object with this id and walks towards newer objects.
+    pub ending_before: Option<String>,
+}
+impl InvoiceListParams {
+    pub fn new() -> Self {
+        InvoiceListParams::default()
+    }
+}
+
+/// How an open invoice is paid with `Invoice::pay`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct InvoicePayParams {
+    /// Settle the invoice with a partial payment when the payment method does not cover the full amount.
+    pub forgive: Option<bool>,
+    /// Whether the customer is not in your checkout flow, Stripe defaults to true.
+    pub off_session: Option<bool>,
+    /// Marks the invoice as paid without charging anything, e.g. when the customer paid by check.
+    pub paid_out_of_band: Option<bool>,
+    /// Id of the PaymentMethod to charge instead of the default one of the invoice.
+    pub payment_method: Option<String>,
+}
+impl InvoicePayParams {
+    pub fn new() -> Self {
+        InvoicePayParams::default()
+    }
+
+    /// Records a payment made outside of Stripe.
+    pub fn paid_out_of_band() -> Self {
+        InvoicePayParams {
+            paid_out_of_band: Some(true),
+            ..InvoicePayParams::default()
+        }
+    }
+}
+
+/// The customer or subscription to preview the next invoice of with `Invoice::upcoming`, plus the changes to preview.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct UpcomingInvoiceParams {
+    /// Id of a coupon to preview on the invoice.
+    pub coupon: Option<String>,
+    /// Id of the customer, required unless a subscription is given.
+    pub customer: Option<String>,
+    /// Id of the subscription to preview the invoice of, the next invoice of the customer when empty.
+    pub subscription: Option<String>,
+    /// Preview canceling the subscription at the end of the period.
+    pub subscription_cancel_at_period_end: Option<bool>,
+    /// The items to preview, as they would be sent with `Subscription::update`.
+    #[serde(default)]
+    pub subscription_items: Vec<SubscriptionItem>,
+    /// `create_prorations`, `none` or `always_invoice`, how the previewed change is prorated.
+    pub subscription_proration_behavior: Option<String>,
+    /// Prorate as if the change happened at this unix timestamp, pass the same date to the update to get the quoted amount.
+    pub subscription_proration_date: Option<i64>,
+    /// Preview moving the end of the trial to this unix timestamp.
+    pub subscription_trial_end: Option<i64>,
+    /// Number of lines fetched by `Invoice::upcoming_lines`, between 1 and 100.
+    pub limit: Option<u32>,
+    /// Starts the lines after the line with this id.
+    pub starting_after: Option<String>,
+    /// Starts the lines before the line with this id.
+    pub ending_before: Option<String>,
+}
+impl UpcomingInvoiceParams {
+    pub fn new() -> Self {
+        UpcomingInvoiceParams::default()
+    }
+
+    /// Previews the changes made to a subscription that was not updated yet, its items, `price_items`, coupon and proration behavior.
+    pub fn from_subscription(subscription: &Subscription) -> Self {
+        let mut items = subscription.items.clone();
+        if let Some(price_items) = &subscription.price_items {
+            items.extend(price_items.iter().map(|price| SubscriptionItem {
+                price: Some(price.clone().into()),
+                ..SubscriptionItem::default()
+            }));
+        }
+
+        UpcomingInvoiceParams {
+            coupon: subscription.coupon.clone(),
+            customer: subscription.customer.as_ref().and_then(Expandable::id).map(String::from),
+            subscription: subscription.id.clone(),
+            subscription_cancel_at_period_end: subscription.cancel_at_period_end,
+            subscription_items: items,
+            subscription_proration_behavior: subscription.proration_behavior.clone(),
+            ..UpcomingInvoiceParams::default()
+        }
+    }
+    fn to_lines_query(&self) -> UpcomingLinesQuery<'_> {
+        UpcomingLinesQuery {
+            query: self.to_query(),
+            limit: &self.limit,
+            starting_after: &self.starting_after,
+            ending_before: &self.ending_before,
+        }
+    }
+    fn to_query(&self) -> UpcomingInvoiceQuery<'_> {
+        UpcomingInvoiceQuery {
+            coupon: &self.coupon,
+            customer: &self.customer,
+            subscription: &self.subscription,
+            subscription_cancel_at_period_end: &self.subscription_cancel_at_period_end,
+            subscription_items: if self.subscription_items.is_empty() {
+                None
+            } else {
+                Some(self.subscription_items.iter().map(SubscriptionItemParams::from).collect())
+            },
+            subscription_proration_behavior: &self.subscription_proration_behavior,
+            subscription_proration_date: &self.subscription_proration_date,
+            subscription_trial_end: &self.subscription_trial_end,
+        }
+    }
+}
+
+
+/// Invoice items are one-off line items, added to the next invoice of a customer or to a draft invoice.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct InvoiceItem {
+    pub id: Option<String>,
+    pub object: Option<String>,
+    /// The amount in cents, or the unit price times the quantity when a price is given.
+    pub amount: Option<i64>,
+    pub currency: Option<String>,
+    pub customer: Option<Expandable<Customer>>,
+    pub date: Option<i64>,
+    /// Set once the invoice item was deleted.
+    pub deleted: Option<bool>,
+    pub description: Option<String>,
+    /// Whether discounts of the invoice or customer apply to the item.
+    pub discountable: Option<bool>,
+    /// The draft invoice the item is on, the next invoice of the customer when empty.
+    pub invoice: Option<Expandable<Invoice>>,
+    pub livemode: Option<bool>,
+    /// The period the item covers, shown on the invoice.
+    pub period: Option<Period>,
+    pub price: Option<Expandable<Price>>,
+    pub proration: Option<bool>,
+    pub quantity: Option<i64>,
+    /// Id of the subscription whose next invoice the item is added to.
+    pub subscription: Option<String>,
+    pub subscription_item: Option<String>,
+    /// The tax rates applied to the item, sent as their ids.
+    pub tax_rates: Option<Vec<Expandable<TaxRate>>>,
+    pub unit_amount: Option<i64>,
+    pub unit_amount_decimal: Option<String>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    /// Set when Stripe answered with the stored result of an earlier request with the same idempotency key.
+    #[serde(skip_serializing)]
+    pub idempotent_replayed: Option<bool>,
+}
+impl InvoiceItem {
+
+    /// Returns an empty InvoiceItem object
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// let mut invoice_item = payup::stripe::InvoiceItem::new();
+    /// invoice_item.customer = Some(format!("cus_").into());
+    /// invoice_item.amount = Some(2500);
+    /// invoice_item.currency = Some(format!("usd"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new() -> Self {
+        InvoiceItem::default()
+    }
+
+    /// Asynchronously deletes an invoice item that is not on an invoice yet, or is on a draft invoice.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the invoice item you want to delete.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let invoice_item = payup::stripe::InvoiceItem::async_delete(&client, format!("ii_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_delete(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/invoiceitems/{}", id.clone());
+        client.async_delete(&path).await
+    }
+
+    /// Asynchronously retrieves an invoice item.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the invoice item you want to retrieve.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let invoice_item = payup::stripe::InvoiceItem::async_get(&client, format!("ii_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/invoiceitems/{}", id.clone());
+        client.async_get(&path).await
+    }
+
+    /// Asynchronously and lazily iterates over invoice items, fetching pages from the stripe api only as they are needed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::InvoiceItemListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut params = payup::stripe::InvoiceItemListParams::new();
+    /// params.invoice = Some(format!("in_"));
+    /// let mut invoice_items = payup::stripe::InvoiceItem::async_iter(&client, params);
+    /// while let Some(invoice_item) = invoice_items.next().await {
+    ///     let invoice_item = invoice_item?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn async_iter(client: &StripeClient, params: InvoiceItemListParams) -> ListStream<Self> {
+        list::stream(client, "/invoiceitems", &params)
+    }
+
+    /// Asynchronously returns all invoice items.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::InvoiceItemListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Fetch the items that will go on the next invoice of a customer
+    /// let mut params = payup::stripe::InvoiceItemListParams::new();
+    /// params.customer = Some(format!("cus_"));
+    /// params.pending = Some(true);
+    /// let invoice_items = payup::stripe::InvoiceItem::async_list(&client, params).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_list(client: &StripeClient, params: InvoiceItemListParams) -> Result<Vec<Self>, StripeError>{
+        let params = InvoiceItemListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::async_iter(client, params).try_collect().await
+    }
+
+    /// Asynchronously POSTs a new InvoiceItem to the stripe api, it is added to the next invoice of the customer unless `invoice` is set
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // A one-off setup fee
+    /// let mut setup_fee = payup::stripe::InvoiceItem::new();
+    /// setup_fee.customer = Some(format!("cus_").into());
+    /// setup_fee.amount = Some(5000);
+    /// setup_fee.currency = Some(format!("usd"));
+    /// setup_fee.description = Some(format!("Setup fee"));
+    /// setup_fee.discountable = Some(false);
+    /// setup_fee = setup_fee.async_post(&client).await?;
+    ///
+    /// // Three hours of support at the price of an hour
+    /// let mut support = payup::stripe::InvoiceItem::new();
+    /// support.customer = Some(format!("cus_").into());
+    /// support.price = Some(format!("price_support_hour").into());
+    /// support.quantity = Some(3);
+    /// support.tax_rates = Some(vec![format!("txr_").into()]);
+    /// support = support.async_post(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.async_post("/invoiceitems", &self.to_create_params()).await
+    }
+
+    /// Asynchronously POSTs an update to an existing InvoiceItem
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut invoice_item = payup::stripe::InvoiceItem::async_get(&client, format!("ii_")).await?;
+    /// invoice_item.description = Some(format!("Support, billed in advance"));
+    /// invoice_item.quantity = Some(5);
+    /// invoice_item = invoice_item.async_update(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_update(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.async_post(&format!("/invoiceitems/{}", self.clone().id.unwrap()), &self.to_params()).await
+    }
+
+    /// Deletes an invoice item that is not on an invoice yet, or is on a draft invoice.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the invoice item you want to delete.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let invoice_item = payup::stripe::InvoiceItem::delete(&client, format!("ii_"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/invoiceitems/{}", id.clone());
+        client.delete(&path)
+    }
+
+    /// Retrieves an invoice item.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the invoice item you want to retrieve.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let invoice_item = payup::stripe::InvoiceItem::get(&client, format!("ii_"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/invoiceitems/{}", id.clone());
+        client.get(&path)
+    }
+
+    /// Lazily iterates over invoice items, fetching pages from the stripe api only as they are needed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::InvoiceItemListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut params = payup::stripe::InvoiceItemListParams::new();
+    /// params.invoice = Some(format!("in_"));
+    /// for invoice_item in payup::stripe::InvoiceItem::iter(&client, params) {
+    ///     let invoice_item = invoice_item?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter(client: &StripeClient, params: InvoiceItemListParams) -> ListIter<Self> {
+        list::iter(client, "/invoiceitems", &params)
+    }
+
+    /// Returns all invoice items.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::InvoiceItemListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Fetch the items that will go on the next invoice of a customer
+    /// let mut params = payup::stripe::InvoiceItemListParams::new();
+    /// params.customer = Some(format!("cus_"));
+    /// params.pending = Some(true);
+    /// let invoice_items = payup::stripe::InvoiceItem::list(&client, params)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(client: &StripeClient, params: InvoiceItemListParams) -> Result<Vec<Self>, StripeError>{
+        let params = InvoiceItemListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::iter(client, params).collect()
+    }
+
+    /// POSTs a new InvoiceItem to the stripe api, it is added to the next invoice of the customer unless `invoice` is set
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // A one-off setup fee
+    /// let mut setup_fee = payup::stripe::InvoiceItem::new();
+    /// setup_fee.customer = Some(format!("cus_").into());
+    /// setup_fee.amount = Some(5000);
+    /// setup_fee.currency = Some(format!("usd"));
+    /// setup_fee.description = Some(format!("Setup fee"));
+    /// setup_fee.discountable = Some(false);
+    /// setup_fee = setup_fee.post(&client)?;
+    ///
+    /// // Three hours of support at the price of an hour
+    /// let mut support = payup::stripe::InvoiceItem::new();
+    /// support.customer = Some(format!("cus_").into());
+    /// support.price = Some(format!("price_support_hour").into());
+    /// support.quantity = Some(3);
+    /// support.tax_rates = Some(vec![format!("txr_").into()]);
+    /// support = support.post(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.post("/invoiceitems", &self.to_create_params())
+    }
+
+    /// POSTs an update to an existing InvoiceItem
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut invoice_item = payup::stripe::InvoiceItem::get(&client, format!("ii_"))?;
+    /// invoice_item.description = Some(format!("Support, billed in advance"));
+    /// invoice_item.quantity = Some(5);
+    /// invoice_item = invoice_item.update(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.post(&format!("/invoiceitems/{}", self.clone().id.unwrap()), &self.to_params())
+    }
+    fn to_create_params(&self) -> InvoiceItemCreateParams<'_> {
+        InvoiceItemCreateParams {
+            params: self.to_params(),
+            currency: &self.currency,
+            customer: self.customer.as_ref().and_then(Expandable::id),
+            invoice: self.invoice.as_ref().and_then(Expandable::id),
+            subscription: &self.subscription,
+        }
+    }
+    fn to_params(&self) -> InvoiceItemParams<'_> {
+        // An item fetched earlier carries the amounts computed from its price, Stripe rejects both being sent.
+        let price = self.price.as_ref().and_then(Expandable::id);
+        let priced = price.is_some();
+
+        InvoiceItemParams {
+            amount: if priced { &None } else { &self.amount },
+            description: &self.description,
+            discountable: &self.discountable,
+            period: &self.period,
+            price,
+            quantity: &self.quantity,
+            tax_rates: self.tax_rates.as_ref().map(|rates| rates.iter().filter_map(Expandable::id).collect()),
+            unit_amount: if priced { &None } else { &self.unit_amount },
+            unit_amount_decimal: if priced { &None } else { &self.unit_amount_decimal },
+            metadata: &self.metadata,
+        }
+    }
+
+}
+
+impl Object for InvoiceItem {
+    fn object_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+}
+
+/// Filters and pagination for listing invoice items with `InvoiceItem::list` and `InvoiceItem::iter`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct InvoiceItemListParams {
+    /// Only invoice items created in this range.
+    pub created: Option<RangeQuery>,
+    /// Only invoice items of this customer id.
+    pub customer: Option<String>,
+    /// Only invoice items on this invoice id.
+    pub invoice: Option<String>,
+    /// Only invoice items that are not on an invoice yet.
+    pub pending: Option<bool>,
+    /// Number of objects fetched per page, between 1 and 100.
+    pub limit: Option<u32>,
+    /// Starts the list after the object with this id.
+    pub starting_after: Option<String>,
+    /// Starts the list before the object with this id and walks towards newer objects.
+    pub ending_before: Option<String>,
+}
+impl InvoiceItemListParams {
+    pub fn new() -> Self {
+        InvoiceItemListParams::default()
+    }
+}
+
+/// The start and end of the period a line item covers, as unix timestamps.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Period {
+    pub end: Option<i64>,
+    pub start: Option<i64>,
+}
+
+/// A Mandate is a record of the permission a customer has given you to debit their payment method.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Mandate {
+    pub id: String,
+    pub object: String,
+    #[serde(rename = "customer_acceptance")]
+    pub customer_acceptance: CustomerAcceptance,
+    pub livemode: bool,
+    // #[serde(rename = "multi_use")]
+    // pub multi_use: MultiUse,
+    #[serde(rename = "payment_method")]
+    pub payment_method: String,
+    #[serde(rename = "payment_method_details")]
+    pub payment_method_details: PaymentMethodDetails,
+    pub status: String,
+    #[serde(rename = "type")]
+    pub type_field: String,
+}
+impl Mandate {
+    /// Asynchronously retrieves a Mandate with the given ID.
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the Mandate you want to retrieve.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Fetch customer using id
+    /// let file = payup::stripe::Mandate::async_get(&client, format!("mandate_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/mandates/{}", id.clone());
+        client.async_get(&path).await
+    }
+
+
+    /// Retrieves a Mandate with the given ID.
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the Mandate you want to retrieve.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Fetch customer using id
+    /// let mandate = payup::stripe::Mandate::get(&client, format!("mandate_"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/mandates/{}", id.clone());
+        client.get(&path)
+    }
+}
+impl Object for Mandate {
+    fn object_id(&self) -> Option<&str> {
+        Some(&self.id)
+    }
+}
+
+
+
+
+
+/// A PaymentIntent guides you through the process of collecting a payment from your customer, including 3D Secure authentication.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct PaymentIntent {
+    pub id: Option<String>,
+    pub object: Option<String>,
+    pub amount: Option<i64>,
+    #[serde(rename = "amount_capturable")]
+    pub amount_capturable: Option<i64>,
+    #[serde(rename = "amount_received")]
+    pub amount_received: Option<i64>,
+    /// Amount to capture with `capture`, defaults to the full `amount_capturable`. Only sent when capturing.
+    pub amount_to_capture: Option<i64>,
+    #[serde(rename = "canceled_at")]
+    pub canceled_at: Option<i64>,
+    /// Why the PaymentIntent was canceled, sent with `cancel`: duplicate, fraudulent, requested_by_customer or abandoned.
+    #[serde(rename = "cancellation_reason")]
+    pub cancellation_reason: Option<String>,
+    /// `automatic` or `manual`, set to manual to capture the funds later with `capture`.
+    #[serde(rename = "capture_method")]
+    pub capture_method: Option<String>,
+    /// The secret handed to Stripe.js on the client side to confirm the payment there.
+    #[serde(rename = "client_secret")]
+    pub client_secret: Option<String>,
+    /// Confirm the PaymentIntent right away when creating it. Only sent when creating.
+    pub confirm: Option<bool>,
+    pub created: Option<i64>,
+    pub currency: Option<String>,
+    pub customer: Option<Expandable<Customer>>,
+    pub description: Option<String>,
+    #[serde(rename = "last_payment_error")]
+    pub last_payment_error: Option<ApiError>,
+    #[serde(rename = "latest_charge")]
+    pub latest_charge: Option<Expandable<Charge>>,
+    pub livemode: Option<bool>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    /// What the customer has to do before the payment can go on, e.g. authenticate with 3D Secure.
+    #[serde(rename = "next_action")]
+    pub next_action: Option<NextAction>,
+    /// Set when the customer is not in your checkout flow, e.g. for a recurring charge. Only sent when creating or confirming.
+    pub off_session: Option<bool>,
+    #[serde(rename = "payment_method")]
+    pub payment_method: Option<String>,
+    #[serde(rename = "payment_method_types")]
+    pub payment_method_types: Option<Vec<String>>,
+    #[serde(rename = "receipt_email")]
+    pub receipt_email: Option<String>,
+    /// Where the customer is sent back to after authenticating on a redirect. Only sent when creating or confirming.
+    pub return_url: Option<String>,
+    #[serde(rename = "setup_future_usage")]
+    pub setup_future_usage: Option<String>,
+    #[serde(rename = "statement_descriptor")]
+    pub statement_descriptor: Option<String>,
+    #[serde(rename = "statement_descriptor_suffix")]
+    pub statement_descriptor_suffix: Option<String>,
+    pub status: Option<PaymentIntentStatus>,
+    /// Set when Stripe answered with the stored result of an earlier request with the same idempotency key.
+    #[serde(skip_serializing)]
+    pub idempotent_replayed: Option<bool>,
+}
+impl PaymentIntent {
+
+    /// Returns an empty PaymentIntent object
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// let mut payment_intent = payup::stripe::PaymentIntent::new();
+    /// payment_intent.amount = Some(100);
+    /// payment_intent.currency = Some(format!("usd"));
+    /// payment_intent.customer = Some(format!("cus_").into());
+    /// payment_intent.payment_method_types = Some(vec![format!("card")]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new() -> Self {
+        PaymentIntent::default()
+    }
+
+    /// Asynchronously applies the customer's cash balance to a PaymentIntent paid with the `customer_balance` payment method.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `amount` - The amount to apply, defaults to the remaining amount of the PaymentIntent.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let payment_intent = payup::stripe::PaymentIntent::async_get(&client, format!("pi_")).await?;
+    /// let payment_intent = payment_intent.async_apply_customer_balance(&client, None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_apply_customer_balance(&self, client: &StripeClient, amount: Option<i64>) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/payment_intents/{}/apply_customer_balance", id);
+        client.async_post(&path, &serde_json::json!({ "amount": amount })).await
+    }
+
+    /// Asynchronously cancels a PaymentIntent that has not succeeded yet, releasing any funds held on the card.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut payment_intent = payup::stripe::PaymentIntent::async_get(&client, format!("pi_")).await?;
+    /// payment_intent.cancellation_reason = Some(format!("requested_by_customer"));
+    /// let payment_intent = payment_intent.async_cancel(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_cancel(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/payment_intents/{}/cancel", id);
+        client.async_post(&path, &self.to_cancel_params()).await
+    }
+
+    /// Asynchronously captures the funds of a PaymentIntent created with `capture_method` manual.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Capture less than was authorized, the rest is released
+    /// let mut payment_intent = payup::stripe::PaymentIntent::async_get(&client, format!("pi_")).await?;
+    /// payment_intent.amount_to_capture = Some(50);
+    /// let payment_intent = payment_intent.async_capture(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_capture(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/payment_intents/{}/capture", id);
+        client.async_post(&path, &self.to_capture_params()).await
+    }
+
+    /// Asynchronously confirms that the customer intends to pay with the attached payment method.
+    ///
+    /// If the card needs 3D Secure the returned PaymentIntent has the status `RequiresAction` and a `next_action` to hand to the customer.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut payment_intent = payup::stripe::PaymentIntent::async_get(&client, format!("pi_")).await?;
+    /// payment_intent.payment_method = Some(format!("pm_card_visa"));
+    /// payment_intent.return_url = Some(format!("https://example.com/checkout/done"));
+    /// let payment_intent = payment_intent.async_confirm(&client).await?;
+    ///
+    /// if let Some(payup::stripe::NextAction::RedirectToUrl { redirect_to_url }) = &payment_intent.next_action {
+    ///     println!("send the customer to {:?}", redirect_to_url.url);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_confirm(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/payment_intents/{}/confirm", id);
+        client.async_post(&path, &self.to_confirm_params()).await
+    }
+
+    /// Asynchronously retrieves the details of a PaymentIntent that has previously been created.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the PaymentIntent you want to retrieve.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let payment_intent = payup::stripe::PaymentIntent::async_get(&client, format!("pi_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/payment_intents/{}", id.clone());
+        client.async_get(&path).await
+    }
+
+    /// Asynchronously raises the authorized amount of an uncaptured PaymentIntent, e.g. when a tip is added.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `amount` - The new total amount to authorize, including what was authorized before.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let payment_intent = payup::stripe::PaymentIntent::async_get(&client, format!("pi_")).await?;
+    /// let payment_intent = payment_intent.async_increment_authorization(&client, 1200).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_increment_authorization(&self, client: &StripeClient, amount: i64) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/payment_intents/{}/increment_authorization", id);
+        client.async_post(&path, &serde_json::json!({ "amount": amount })).await
+    }
+
+    /// Asynchronously and lazily iterates over PaymentIntents, fetching pages from the stripe api only as they are needed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::PaymentIntentListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Walk the 50 most recent PaymentIntents, fetching 25 per request
+    /// let mut params = payup::stripe::PaymentIntentListParams::new();
+    /// params.limit = Some(25);
+    /// let mut payment_intents = payup::stripe::PaymentIntent::async_iter(&client, params).take(50);
+    /// while let Some(payment_intent) = payment_intents.next().await {
+    ///     let payment_intent = payment_intent?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn async_iter(client: &StripeClient, params: PaymentIntentListParams) -> ListStream<Self> {
+        list::stream(client, "/payment_intents", &params)
+    }
+
+    /// Asynchronously returns all PaymentIntents.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::PaymentIntentListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let payment_intents = payup::stripe::PaymentIntent::async_list(&client, payup::stripe::PaymentIntentListParams::new()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_list(client: &StripeClient, params: PaymentIntentListParams) -> Result<Vec<Self>, StripeError>{
+        let params = PaymentIntentListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::async_iter(client, params).try_collect().await
+    }
+
+    /// Asynchronously POSTs a new PaymentIntent to the stripe api
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut payment_intent = payup::stripe::PaymentIntent::new();
+    /// payment_intent.amount = Some(100);
+    /// payment_intent.currency = Some(format!("usd"));
+    /// payment_intent.payment_method_types = Some(vec![format!("card")]);
+    ///
+    /// payment_intent = payment_intent.async_post(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.async_post("/payment_intents", &self.to_create_params()).await
+    }
+
+    /// Asynchronously and lazily iterates over the payment intents matching a search query, e.g. by metadata
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SearchParams holding the query
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Find the payment intents of an order
+    /// let params = payup::stripe::SearchParams::metadata("order_id", "6735");
+    /// let mut payment_intents = payup::stripe::PaymentIntent::async_search(&client, params);
+    /// while let Some(payment_intent) = payment_intents.next().await {
+    ///     let payment_intent = payment_intent?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn async_search(client: &StripeClient, params: SearchParams) -> ListStream<Self> {
+        list::search_stream(client, "/payment_intents/search", &params)
+    }
+
+    /// Asynchronously POSTs an update to an existing PaymentIntent
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut payment_intent = payup::stripe::PaymentIntent::async_get(&client, format!("pi_")).await?;
+    /// payment_intent.amount = Some(250);
+    /// payment_intent = payment_intent.async_update(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_update(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        client.async_post(&format!("/payment_intents/{}", id), &self.to_params()).await
+    }
+
+    /// Applies the customer's cash balance to a PaymentIntent paid with the `customer_balance` payment method.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `amount` - The amount to apply, defaults to the remaining amount of the PaymentIntent.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let payment_intent = payup::stripe::PaymentIntent::get(&client, format!("pi_"))?;
+    /// let payment_intent = payment_intent.apply_customer_balance(&client, Some(500))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn apply_customer_balance(&self, client: &StripeClient, amount: Option<i64>) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/payment_intents/{}/apply_customer_balance", id);
+        client.post(&path, &serde_json::json!({ "amount": amount }))
+    }
+
+    /// Cancels a PaymentIntent that has not succeeded yet, releasing any funds held on the card.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut payment_intent = payup::stripe::PaymentIntent::get(&client, format!("pi_"))?;
+    /// payment_intent.cancellation_reason = Some(format!("requested_by_customer"));
+    /// let payment_intent = payment_intent.cancel(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cancel(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/payment_intents/{}/cancel", id);
+        client.post(&path, &self.to_cancel_params())
+    }
+
+    /// Captures the funds of a PaymentIntent created with `capture_method` manual.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut payment_intent = payup::stripe::PaymentIntent::new();
+    /// payment_intent.amount = Some(100);
+    /// payment_intent.currency = Some(format!("usd"));
+    /// payment_intent.capture_method = Some(format!("manual"));
+    /// payment_intent.payment_method = Some(format!("pm_card_visa"));
+    /// payment_intent.confirm = Some(true);
+    /// payment_intent = payment_intent.post(&client)?;
+    ///
+    /// // Once the order shipped
+    /// let payment_intent = payment_intent.capture(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn capture(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/payment_intents/{}/capture", id);
+        client.post(&path, &self.to_capture_params())
+    }
+
+    /// Confirms that the customer intends to pay with the attached payment method.
+    ///
+    /// If the card needs 3D Secure the returned PaymentIntent has the status `RequiresAction` and a `next_action` to hand to the customer.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut payment_intent = payup::stripe::PaymentIntent::get(&client, format!("pi_"))?;
+    /// payment_intent.payment_method = Some(format!("pm_card_visa"));
+    /// payment_intent.return_url = Some(format!("https://example.com/checkout/done"));
+    /// let payment_intent = payment_intent.confirm(&client)?;
+    ///
+    /// match (payment_intent.status, &payment_intent.next_action) {
+    ///     (Some(payup::stripe::PaymentIntentStatus::Succeeded), _) => println!("paid"),
+    ///     (_, Some(payup::stripe::NextAction::RedirectToUrl { redirect_to_url })) => {
+    ///         println!("send the customer to {:?}", redirect_to_url.url)
+    ///     }
+    ///     (_, Some(payup::stripe::NextAction::UseStripeSdk { .. })) => {
+    ///         println!("finish with stripe.js using {:?}", payment_intent.client_secret)
+    ///     }
+    ///     _ => {}
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn confirm(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/payment_intents/{}/confirm", id);
+        client.post(&path, &self.to_confirm_params())
+    }
+
+    /// Retrieves the details of a PaymentIntent that has previously been created.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the PaymentIntent you want to retrieve.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let payment_intent = payup::stripe::PaymentIntent::get(&client, format!("pi_"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/payment_intents/{}", id.clone());
+        client.get(&path)
+    }
+
+    /// Raises the authorized amount of an uncaptured PaymentIntent, e.g. when a tip is added.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `amount` - The new total amount to authorize, including what was authorized before.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let payment_intent = payup::stripe::PaymentIntent::get(&client, format!("pi_"))?;
+    /// let payment_intent = payment_intent.increment_authorization(&client, 1200)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn increment_authorization(&self, client: &StripeClient, amount: i64) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/payment_intents/{}/increment_authorization", id);
+        client.post(&path, &serde_json::json!({ "amount": amount }))
+    }
+
+    /// Lazily iterates over PaymentIntents, fetching pages from the stripe api only as they are needed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::PaymentIntentListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Walk the PaymentIntents of a customer
+    /// let mut params = payup::stripe::PaymentIntentListParams::new();
+    /// params.customer = Some(format!("cus_"));
+    /// for payment_intent in payup::stripe::PaymentIntent::iter(&client, params) {
+    ///     let payment_intent = payment_intent?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter(client: &StripeClient, params: PaymentIntentListParams) -> ListIter<Self> {
+        list::iter(client, "/payment_intents", &params)
+    }
+
+    /// Returns all PaymentIntents.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::PaymentIntentListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Fetch all PaymentIntents created in January 2022
+    /// let mut params = payup::stripe::PaymentIntentListParams::new();
+    /// params.created = Some(payup::stripe::RangeQuery::between(1640995200, 1643673599));
+    /// let payment_intents = payup::stripe::PaymentIntent::list(&client, params)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(client: &StripeClient, params: PaymentIntentListParams) -> Result<Vec<Self>, StripeError>{
+        let params = PaymentIntentListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::iter(client, params).collect()
+    }
+
+    /// POSTs a new PaymentIntent to the stripe api
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut payment_intent = payup::stripe::PaymentIntent::new();
+    /// payment_intent.amount = Some(100);
+    /// payment_intent.currency = Some(format!("usd"));
+    /// payment_intent.customer = Some(format!("cus_").into());
+    /// payment_intent.payment_method_types = Some(vec![format!("card")]);
+    ///
+    /// payment_intent = payment_intent.post(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.post("/payment_intents", &self.to_create_params())
+    }
+
+    /// Lazily iterates over the payment intents matching a search query, e.g. by metadata
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SearchParams holding the query
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Find the payment intents of an order
+    /// let params = payup::stripe::SearchParams::metadata("order_id", "6735");
+    /// for payment_intent in payup::stripe::PaymentIntent::search(&client, params) {
+    ///     let payment_intent = payment_intent?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search(client: &StripeClient, params: SearchParams) -> ListIter<Self> {
+        list::search(client, "/payment_intents/search", &params)
+    }
+
+    /// POSTs an update to an existing PaymentIntent
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut payment_intent = payup::stripe::PaymentIntent::get(&client, format!("pi_"))?;
+    /// payment_intent.metadata.insert(format!("order_id"), format!("6735"));
+    /// payment_intent = payment_intent.update(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        client.post(&format!("/payment_intents/{}", id), &self.to_params())
+    }
+    fn to_cancel_params(&self) -> PaymentIntentCancelParams<'_> {
+        PaymentIntentCancelParams {
+            cancellation_reason: &self.cancellation_reason,
+        }
+    }
+    fn to_capture_params(&self) -> PaymentIntentCaptureParams<'_> {
+        PaymentIntentCaptureParams {
+            amount_to_capture: &self.amount_to_capture,
+            statement_descriptor: &self.statement_descriptor,
+            statement_descriptor_suffix: &self.statement_descriptor_suffix,
+        }
+    }
+    fn to_confirm_params(&self) -> PaymentIntentConfirmParams<'_> {
+        PaymentIntentConfirmParams {
+            off_session: &self.off_session,
+            payment_method: &self.payment_method,
+            receipt_email: &self.receipt_email,
+            return_url: &self.return_url,
+            setup_future_usage: &self.setup_future_usage,
+        }
+    }
+    fn to_create_params(&self) -> PaymentIntentCreateParams<'_> {
+        PaymentIntentCreateParams {
+            params: self.to_params(),
+            confirm: &self.confirm,
+            off_session: &self.off_session,
+            return_url: &self.return_url,
+        }
+    }
+    fn to_params(&self) -> PaymentIntentParams<'_> {
+        PaymentIntentParams {
+            amount: &self.amount,
+            capture_method: &self.capture_method,
+            currency: &self.currency,
+            customer: self.customer.as_ref().and_then(Expandable::id),
+            description: &self.description,
+            payment_method: &self.payment_method,
+            payment_method_types: &self.payment_method_types,
+            receipt_email: &self.receipt_email,
+            setup_future_usage: &self.setup_future_usage,
+            statement_descriptor: &self.statement_descriptor,
+            statement_descriptor_suffix: &self.statement_descriptor_suffix,
+            metadata: &self.metadata,
+        }
+    }
+
+}
+
+impl Object for PaymentIntent {
+    fn object_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+}
+
+/// Filters and pagination for listing PaymentIntents with `PaymentIntent::list` and `PaymentIntent::iter`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct PaymentIntentListParams {
+    /// Only PaymentIntents created in this range.
+    pub created: Option<RangeQuery>,
+    /// Only PaymentIntents for this customer id.
+    pub customer: Option<String>,
+    /// Number of objects fetched per page, between 1 and 100.
+    pub limit: Option<u32>,
+    /// Starts the list after the object with this id.
+    pub starting_after: Option<String>,
+    /// Starts the list before the object with this id and walks towards newer objects.
+    pub ending_before: Option<String>,
+}
+impl PaymentIntentListParams {
+    pub fn new() -> Self {
+        PaymentIntentListParams::default()
+    }
+}
+
+/// Where a PaymentIntent is in its lifecycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaymentIntentStatus {
+    /// A payment method has to be attached, or the last one failed.
+    RequiresPaymentMethod,
+    /// The payment method is attached and the PaymentIntent waits for `confirm`.
+    RequiresConfirmation,
+    /// The customer has to act first, see `next_action`.
+    RequiresAction,
+    Processing,
+    /// The funds are held and wait for `capture`.
+    RequiresCapture,
+    Canceled,
+    Succeeded,
+    /// A status this version of the crate does not know yet, with its name.
+    Other(String),
+}
+
+impl PaymentIntentStatus {
+    /// The name Stripe uses for the status, e.g. `requires_action`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            PaymentIntentStatus::RequiresPaymentMethod => "requires_payment_method",
+            PaymentIntentStatus::RequiresConfirmation => "requires_confirmation",
+            PaymentIntentStatus::RequiresAction => "requires_action",
+            PaymentIntentStatus::Processing => "processing",
+            PaymentIntentStatus::RequiresCapture => "requires_capture",
+            PaymentIntentStatus::Canceled => "canceled",
+            PaymentIntentStatus::Succeeded => "succeeded",
+            PaymentIntentStatus::Other(name) => name,
+        }
+    }
+}
+
+impl From<&str> for PaymentIntentStatus {
+    fn from(name: &str) -> Self {
+        match name {
+            "requires_payment_method" => PaymentIntentStatus::RequiresPaymentMethod,
+            "requires_confirmation" => PaymentIntentStatus::RequiresConfirmation,
+            "requires_action" => PaymentIntentStatus::RequiresAction,
+            "processing" => PaymentIntentStatus::Processing,
+            "requires_capture" => PaymentIntentStatus::RequiresCapture,
+            "canceled" => PaymentIntentStatus::Canceled,
+            "succeeded" => PaymentIntentStatus::Succeeded,
+            _ => PaymentIntentStatus::Other(name.to_string()),
+        }
+    }
+}
+
+impl Serialize for PaymentIntentStatus {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentIntentStatus {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(|name| PaymentIntentStatus::from(name.as_str()))
+    }
+}
+
+/// What the customer has to do before a PaymentIntent or SetupIntent can go on.
+///
+/// # Examples
+///
+/// ```
+/// let next_action: payup::stripe::NextAction = serde_json::from_str(r#"{
+///   "type": "verify_with_microdeposits",
+///   "verify_with_microdeposits": {"arrival_date": 1647586800, "microdeposit_type": "descriptor_code"}
+/// }"#).unwrap();
+///
+/// match next_action {
+///     payup::stripe::NextAction::Other(action) => {
+///         assert_eq!(action["verify_with_microdeposits"]["microdeposit_type"], "descriptor_code");
+///     }
+///     next_action => panic!("expected an action the crate does not model, got {:?}", next_action),
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum NextAction {
+    /// Send the customer to `redirect_to_url.url` to authenticate, e.g. for 3D Secure.
+    RedirectToUrl {
+        redirect_to_url: RedirectToUrl,
+    },
+    /// Hand the `client_secret` to Stripe.js or a mobile SDK, which finishes the authentication.
+    UseStripeSdk {
+        use_stripe_sdk: serde_json::Value,
+    },
+    /// An action this version of the crate does not know yet, e.g. `verify_with_microdeposits`, with the
+    /// raw JSON including its `type`.
+    Other(serde_json::Value),
+}
+
+impl Serialize for NextAction {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        if let NextAction::Other(value) = self {
+            return value.serialize(serializer);
+        }
+        let mut map = serializer.serialize_map(Some(2))?;
+        match self {
+            NextAction::RedirectToUrl { redirect_to_url } => {
+                map.serialize_entry("type", "redirect_to_url")?;
+                map.serialize_entry("redirect_to_url", redirect_to_url)?;
+            }
+            NextAction::UseStripeSdk { use_stripe_sdk } => {
+                map.serialize_entry("type", "use_stripe_sdk")?;
+                map.serialize_entry("use_stripe_sdk", use_stripe_sdk)?;
+            }
+            NextAction::Other(_) => {}
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for NextAction {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let details = |action: &str| value.get(action).cloned().unwrap_or_default();
+        match value.get("type").and_then(serde_json::Value::as_str) {
+            Some("redirect_to_url") => serde_json::from_value(details("redirect_to_url"))
+                .map(|redirect_to_url| NextAction::RedirectToUrl { redirect_to_url })
+                .map_err(serde::de::Error::custom),
+            Some("use_stripe_sdk") => Ok(NextAction::UseStripeSdk { use_stripe_sdk: details("use_stripe_sdk") }),
+            _ => Ok(NextAction::Other(value)),
+        }
+    }
+}
+
+/// The page a customer is sent to for a `NextAction::RedirectToUrl`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct RedirectToUrl {
+    /// Where to send the customer.
+    pub url: Option<String>,
+    /// Where the customer comes back to once done.
+    pub return_url: Option<String>,
+}
+
+
+// TODO - Finish Implementation
+/// PaymentMethod objects represent your customer's payment instruments.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct PaymentMethod {
+    pub id: Option<String>,
+    pub method_type: Option<String>,
+    pub created: Option<i64>,
+    pub customer: Option<String>,
+    pub livemode:  Option<bool>,
+    pub name: Option<String>,
+    pub phone: Option<String>,
+    pub billing_details: Option<crate::stripe::response::BillingDetails>,
+    pub card: Option<Card>,
+    #[serde(rename = "type")]
+    pub type_field: Option<String>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    /// Set when Stripe answered with the stored result of an earlier request with the same idempotency key.
+    #[serde(skip_serializing)]
+    pub idempotent_replayed: Option<bool>,
+}
 impl PaymentMethod {
     pub fn new() -> Self {
-        return PaymentMethod{
-            id: None, 
-            method_type: None, 
-            created: None, 
-            customer: None, 
-            livemode: None, 
-            name: None,
-            phone: None,
-            billing_details: None, 
-            card: None,
-            type_field: None
+        PaymentMethod::default()
+    }
+    pub fn attach(&self, customer: Customer, client: &StripeClient) ->  Result<bool, StripeError>{
+        let (id, cust_id) = match (&self.id, &customer.id) {
+            (Some(id), Some(cust_id)) => (id, cust_id),
+            _ => return Ok(false)
+        };
+
+        let path = format!("/payment_methods/{}/attach", id);
+        let params = serde_json::json!({ "customer": cust_id });
+        client.post::<serde_json::Value, _>(&path, &params)?;
+        Ok(true)
+    }
+    pub fn get(client: &StripeClient, id: String) -> Result<crate::stripe::response::PaymentMethod, StripeError> {
+        let path = format!("/payment_methods/{}", id);
+        client.get(&path)
+    }
+    pub fn post(&self, client: &StripeClient) ->  Result<PaymentMethod, StripeError> {
+        let json: crate::stripe::response::PaymentMethod = client.post("/payment_methods", &self.to_params())?;
+        let mut payment_method = self.clone();
+        payment_method.id = Some(json.id);
+        payment_method.idempotent_replayed = json.idempotent_replayed;
+        Ok(payment_method)
+    }
+    fn to_params(&self) -> PaymentMethodParams<'_> {
+        PaymentMethodParams {
+            method_type: &self.method_type,
+            card: self.card.as_ref().map(CardParams::from),
+            metadata: &self.metadata,
+        }
+    }
+}
+
+
+// TODO - Finish Implementation
+/// Plans define the base price, currency, and billing cycle for recurring purchases of products. 
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Plan {
+    pub id: Option<String>,
+    pub active: Option<bool>,
+    pub amount: Option<i64>,
+    pub amount_decimal: Option<String>,
+    pub billing_scheme: Option<String>,
+    pub created: Option<i64>,
+    pub currency: Option<String>,
+    pub interval: Option<String>,
+    pub interval_count: Option<i64>,
+    pub product: Option<String>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+}
+impl Plan {
+
+    pub fn new() -> Self {
+        Plan::default()
+    }
+
+    pub async fn async_delete(client: &StripeClient, id: String) -> Result<crate::stripe::response::Plan, StripeError> {
+        let path = format!("/plans/{}", id.clone());
+        
+        client.async_delete(&path).await
+    }
+
+    pub async fn async_get(client: &StripeClient, id: String) -> Result<crate::stripe::response::Plan, StripeError> {
+        let path = format!("/plans/{}", id.clone());
+        client.async_get(&path).await
+    }
+
+    /// Asynchronously and lazily iterates over plans, fetching pages from the stripe api only as they are needed
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::PlanListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Walk the 50 most recent plans, fetching 25 per request
+    /// let mut params = payup::stripe::PlanListParams::new();
+    /// params.limit = Some(25);
+    /// let mut plans = payup::stripe::Plan::async_iter(&client, params).take(50);
+    /// while let Some(plan) = plans.next().await {
+    ///     let plan = plan?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn async_iter(client: &StripeClient, params: PlanListParams) -> ListStream<crate::stripe::response::Plan> {
+        list::stream(client, "/plans", &params)
+    }
+
+    pub async fn async_list(client: &StripeClient, params: PlanListParams) -> Result<Vec<crate::stripe::response::Plan>, StripeError>{
+        let params = PlanListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::async_iter(client, params).try_collect().await
+    }
+
+    pub async fn async_post(&self, client: &StripeClient) ->  Result<crate::stripe::response::Plan, StripeError> {
+        client.async_post("/plans", &self.to_params()).await
+    }
+
+    pub fn delete(client: &StripeClient, id: String) -> Result<crate::stripe::response::Plan, StripeError> {
+        let path = format!("/plans/{}", id.clone());
+        
+        client.delete(&path)
+    }
+
+
+    pub fn get(client: &StripeClient, id: String) -> Result<crate::stripe::response::Plan, StripeError> {
+        let path = format!("/plans/{}", id.clone());
+        client.get(&path)
+    }
+
+    /// Lazily iterates over plans, fetching pages from the stripe api only as they are needed
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::PlanListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Walk the 50 most recent plans, fetching 25 per request
+    /// let mut params = payup::stripe::PlanListParams::new();
+    /// params.limit = Some(25);
+    /// for plan in payup::stripe::Plan::iter(&client, params).take(50) {
+    ///     let plan = plan?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter(client: &StripeClient, params: PlanListParams) -> ListIter<crate::stripe::response::Plan> {
+        list::iter(client, "/plans", &params)
+    }
+
+    pub fn list(client: &StripeClient, params: PlanListParams) -> Result<Vec<crate::stripe::response::Plan>, StripeError>{
+        let params = PlanListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::iter(client, params).collect()
+    }
+
+    pub fn post(&self, client: &StripeClient) ->  Result<crate::stripe::response::Plan, StripeError> {
+        client.post("/plans", &self.to_params())
+    }
+
+    fn to_params(&self) -> PlanParams<'_> {
+        PlanParams {
+            active: &self.active,
+            amount: &self.amount,
+            currency: &self.currency,
+            interval: &self.interval,
+            product: &self.product,
+            metadata: &self.metadata,
+        }
+    }
+}
+
+/// Filters and pagination for listing plans with `Plan::list` and `Plan::iter`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct PlanListParams {
+    /// Only active (true) or inactive (false) plans.
+    pub active: Option<bool>,
+    /// Only plans created in this range.
+    pub created: Option<RangeQuery>,
+    /// Only plans for this product id.
+    pub product: Option<String>,
+    /// Number of objects fetched per page, between 1 and 100.
+    pub limit: Option<u32>,
+    /// Starts the list after the object with this id.
+    pub starting_after: Option<String>,
+    /// Starts the list before the object with this id and walks towards newer objects.
+    pub ending_before: Option<String>,
+}
+impl PlanListParams {
+    pub fn new() -> Self {
+        PlanListParams::default()
+    }
+}
+
+
+/// Prices define the unit cost, currency, and (optional) billing cycle.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Price {
+    pub id: Option<String>,
+    pub object: Option<String>,
+    pub active: Option<bool>,
+    /// `per_unit` or `tiered`, set to tiered together with `tiers` and `tiers_mode`.
+    pub billing_scheme: Option<String>,
+    pub created: Option<i64>,
+    pub currency: Option<String>,
+    pub livemode: Option<bool>,
+    /// A key to fetch the price by instead of its id, e.g. `standard_monthly`.
+    pub lookup_key: Option<String>,
+    /// A brief description of the price, hidden from customers.
+    pub nickname: Option<String>,
+    pub product: Option<Expandable<Product>>,
+    /// Creates a new product together with the price. Only sent when creating.
+    pub product_data: Option<ProductData>,
+    /// The billing interval of a recurring price, None for one-time prices.
+    pub recurring: Option<Recurring>,
+    pub tax_behavior: Option<String>,
+    /// The tiers of a price with the billing scheme `tiered`.
+    pub tiers: Option<Vec<PriceTier>>,
+    /// `graduated` or `volume`, how the tiers are applied.
+    pub tiers_mode: Option<String>,
+    /// Divides the quantity before it is billed, e.g. to charge per 1000 API calls.
+    pub transform_quantity: Option<TransformQuantity>,
+    /// `one_time` or `recurring`.
+    #[serde(rename = "type")]
+    pub type_field: Option<String>,
+    pub unit_amount: Option<i64>,
+    pub unit_amount_decimal: Option<String>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    /// Set when Stripe answered with the stored result of an earlier request with the same idempotency key.
+    #[serde(skip_serializing)]
+    pub idempotent_replayed: Option<bool>,
+}
+impl Price {
+
+    /// Returns an empty Price object
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// let mut price = payup::stripe::Price::new();
+    /// price.currency = Some(format!("usd"));
+    /// price.unit_amount = Some(1500);
+    /// price.product = Some(format!("prod_").into());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new() -> Self {
+        Price::default()
+    }
+
+    /// Asynchronously retrieves the details of an existing price.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the price you want to retrieve.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let price = payup::stripe::Price::async_get(&client, format!("price_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/prices/{}", id.clone());
+        client.async_get(&path).await
+    }
+
+    /// Asynchronously and lazily iterates over prices, fetching pages from the stripe api only as they are needed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::PriceListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Walk the active prices of a product
+    /// let mut params = payup::stripe::PriceListParams::new();
+    /// params.product = Some(format!("prod_"));
+    /// params.active = Some(true);
+    /// let mut prices = payup::stripe::Price::async_iter(&client, params);
+    /// while let Some(price) = prices.next().await {
+    ///     let price = price?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn async_iter(client: &StripeClient, params: PriceListParams) -> ListStream<Self> {
+        list::stream(client, "/prices", &params)
+    }
+
+    /// Asynchronously returns all prices.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::PriceListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let prices = payup::stripe::Price::async_list(&client, payup::stripe::PriceListParams::new()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_list(client: &StripeClient, params: PriceListParams) -> Result<Vec<Self>, StripeError>{
+        let params = PriceListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::async_iter(client, params).try_collect().await
+    }
+
+    /// Asynchronously POSTs a new Price to the stripe api
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut price = payup::stripe::Price::new();
+    /// price.currency = Some(format!("usd"));
+    /// price.unit_amount = Some(1500);
+    /// price.product = Some(format!("prod_").into());
+    ///
+    /// price = price.async_post(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.async_post("/prices", &self.to_params()).await
+    }
+
+    /// Asynchronously and lazily iterates over the prices matching a search query, e.g. by metadata
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SearchParams holding the query
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Find the active prices in euro
+    /// let params = payup::stripe::SearchParams::new("active:'true' AND currency:'eur'");
+    /// let mut prices = payup::stripe::Price::async_search(&client, params);
+    /// while let Some(price) = prices.next().await {
+    ///     let price = price?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn async_search(client: &StripeClient, params: SearchParams) -> ListStream<Self> {
+        list::search_stream(client, "/prices/search", &params)
+    }
+
+    /// Asynchronously POSTs an update to an existing Price
+    ///
+    /// Only `active`, `lookup_key`, `nickname`, `tax_behavior` and `metadata` can be changed, create a new price to change the amount.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Archive a price so no new subscriptions use it
+    /// let mut price = payup::stripe::Price::async_get(&client, format!("price_")).await?;
+    /// price.active = Some(false);
+    /// price = price.async_update(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_update(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.async_post(&format!("/prices/{}", self.clone().id.unwrap()), &self.to_update_params()).await
+    }
+
+    /// Retrieves the details of an existing price.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the price you want to retrieve.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let price = payup::stripe::Price::get(&client, format!("price_"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/prices/{}", id.clone());
+        client.get(&path)
+    }
+
+    /// Lazily iterates over prices, fetching pages from the stripe api only as they are needed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::PriceListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Walk the active prices of a product
+    /// let mut params = payup::stripe::PriceListParams::new();
+    /// params.product = Some(format!("prod_"));
+    /// params.active = Some(true);
+    /// for price in payup::stripe::Price::iter(&client, params) {
+    ///     let price = price?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter(client: &StripeClient, params: PriceListParams) -> ListIter<Self> {
+        list::iter(client, "/prices", &params)
+    }
+
+    /// Returns all prices.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::PriceListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Fetch prices by their lookup keys
+    /// let mut params = payup::stripe::PriceListParams::new();
+    /// params.lookup_keys = Some(vec![format!("standard_monthly"), format!("standard_yearly")]);
+    /// let prices = payup::stripe::Price::list(&client, params)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(client: &StripeClient, params: PriceListParams) -> Result<Vec<Self>, StripeError>{
+        let params = PriceListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::iter(client, params).collect()
+    }
+
+    /// POSTs a new Price to the stripe api
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // A monthly price with graduated tiers, creating its product on the way
+    /// let mut product = payup::stripe::ProductData::new();
+    /// product.name = Some(format!("API calls"));
+    ///
+    /// let mut recurring = payup::stripe::Recurring::new();
+    /// recurring.interval = Some(format!("month"));
+    /// recurring.usage_type = Some(format!("metered"));
+    ///
+    /// let mut first = payup::stripe::PriceTier::new();
+    /// first.up_to = Some(1000);
+    /// first.unit_amount = Some(0);
+    /// let mut rest = payup::stripe::PriceTier::new();
+    /// rest.unit_amount = Some(2);
+    ///
+    /// let mut price = payup::stripe::Price::new();
+    /// price.currency = Some(format!("usd"));
+    /// price.product_data = Some(product);
+    /// price.recurring = Some(recurring);
+    /// price.billing_scheme = Some(format!("tiered"));
+    /// price.tiers_mode = Some(format!("graduated"));
+    /// price.tiers = Some(vec![first, rest]);
+    /// price.lookup_key = Some(format!("api_calls_monthly"));
+    ///
+    /// price = price.post(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.post("/prices", &self.to_params())
+    }
+
+    /// Lazily iterates over the prices matching a search query, e.g. by metadata
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SearchParams holding the query
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Find the prices of a campaign
+    /// let params = payup::stripe::SearchParams::metadata("campaign", "spring");
+    /// for price in payup::stripe::Price::search(&client, params) {
+    ///     let price = price?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search(client: &StripeClient, params: SearchParams) -> ListIter<Self> {
+        list::search(client, "/prices/search", &params)
+    }
+
+    /// POSTs an update to an existing Price
+    ///
+    /// Only `active`, `lookup_key`, `nickname`, `tax_behavior` and `metadata` can be changed, create a new price to change the amount.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut price = payup::stripe::Price::get(&client, format!("price_"))?;
+    /// price.nickname = Some(format!("Standard monthly"));
+    /// price = price.update(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.post(&format!("/prices/{}", self.clone().id.unwrap()), &self.to_update_params())
+    }
+    fn to_params(&self) -> PriceParams<'_> {
+        PriceParams {
+            active: &self.active,
+            billing_scheme: &self.billing_scheme,
+            currency: &self.currency,
+            lookup_key: &self.lookup_key,
+            nickname: &self.nickname,
+            product: self.product.as_ref().and_then(Expandable::id),
+            product_data: &self.product_data,
+            recurring: &self.recurring,
+            tax_behavior: &self.tax_behavior,
+            tiers: self.tiers.as_ref().map(|tiers| tiers.iter().map(PriceTierParams::from).collect()),
+            tiers_mode: &self.tiers_mode,
+            transform_quantity: &self.transform_quantity,
+            unit_amount: &self.unit_amount,
+            unit_amount_decimal: &self.unit_amount_decimal,
+            metadata: &self.metadata,
+        }
+    }
+    fn to_update_params(&self) -> PriceUpdateParams<'_> {
+        PriceUpdateParams {
+            active: &self.active,
+            lookup_key: &self.lookup_key,
+            nickname: &self.nickname,
+            tax_behavior: &self.tax_behavior,
+            metadata: &self.metadata,
+        }
+    }
+
+}
+
+impl Object for Price {
+    fn object_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+}
+
+/// Filters and pagination for listing prices with `Price::list` and `Price::iter`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct PriceListParams {
+    /// Only active or only archived prices.
+    pub active: Option<bool>,
+    /// Only prices created in this range.
+    pub created: Option<RangeQuery>,
+    /// Only prices in this currency.
+    pub currency: Option<String>,
+    /// Only prices with one of these lookup keys.
+    pub lookup_keys: Option<Vec<String>>,
+    /// Only prices of this product id.
+    pub product: Option<String>,
+    /// Only prices of this type, `one_time` or `recurring`.
+    #[serde(rename = "type")]
+    pub type_field: Option<String>,
+    /// Number of objects fetched per page, between 1 and 100.
+    pub limit: Option<u32>,
+    /// Starts the list after the object with this id.
+    pub starting_after: Option<String>,
+    /// Starts the list before the object with this id and walks towards newer objects.
+    pub ending_before: Option<String>,
+}
+impl PriceListParams {
+    pub fn new() -> Self {
+        PriceListParams::default()
+    }
+}
+
+/// The billing interval of a recurring price.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Recurring {
+    /// `sum`, `last_during_period`, `last_ever` or `max`, how metered usage is added up.
+    pub aggregate_usage: Option<String>,
+    /// `day`, `week`, `month` or `year`.
+    pub interval: Option<String>,
+    /// Number of intervals between two bills, e.g. 3 with a monthly interval bills every quarter.
+    pub interval_count: Option<i64>,
+    /// `licensed` (billed by quantity) or `metered` (billed by reported usage).
+    pub usage_type: Option<String>,
+}
+impl Recurring {
+    pub fn new() -> Self {
+        Recurring::default()
+    }
+}
+
+/// One tier of a price with the billing scheme `tiered`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct PriceTier {
+    pub flat_amount: Option<i64>,
+    pub flat_amount_decimal: Option<String>,
+    pub unit_amount: Option<i64>,
+    pub unit_amount_decimal: Option<String>,
+    /// The last quantity of the tier, None for the last tier that goes up to infinity.
+    pub up_to: Option<i64>,
+}
+impl PriceTier {
+    pub fn new() -> Self {
+        PriceTier::default()
+    }
+}
+
+/// Divides the quantity of a price before it is billed.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct TransformQuantity {
+    /// The number the quantity is divided by.
+    pub divide_by: Option<i64>,
+    /// `up` or `down`, how the result of the division is rounded.
+    pub round: Option<String>,
+}
+impl TransformQuantity {
+    pub fn new() -> Self {
+        TransformQuantity::default()
+    }
+}
+
+/// A product created together with a price, see `Price::product_data`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ProductData {
+    pub active: Option<bool>,
+    pub name: Option<String>,
+    pub statement_descriptor: Option<String>,
+    pub tax_code: Option<String>,
+    pub unit_label: Option<String>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+}
+impl ProductData {
+    pub fn new() -> Self {
+        ProductData::default()
+    }
+}
+
+/// Products describe the specific goods or services you offer to your customers.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Product {
+    pub id: Option<String>,
+    pub object: Option<String>,
+    pub active: Option<bool>,
+    pub created: Option<i64>,
+    #[serde(rename = "default_price")]
+    pub default_price: Option<Expandable<Price>>,
+    /// Set on the object returned by `delete`.
+    pub deleted: Option<bool>,
+    pub description: Option<String>,
+    pub images: Option<Vec<String>>,
+    pub livemode: Option<bool>,
+    pub name: Option<String>,
+    pub shippable: Option<bool>,
+    #[serde(rename = "statement_descriptor")]
+    pub statement_descriptor: Option<String>,
+    #[serde(rename = "tax_code")]
+    pub tax_code: Option<String>,
+    #[serde(rename = "unit_label")]
+    pub unit_label: Option<String>,
+    pub updated: Option<i64>,
+    pub url: Option<String>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    /// Set when Stripe answered with the stored result of an earlier request with the same idempotency key.
+    #[serde(skip_serializing)]
+    pub idempotent_replayed: Option<bool>,
+}
+impl Product {
+
+    /// Returns an empty Product object
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// let mut product = payup::stripe::Product::new();
+    /// product.name = Some(format!("Gold plan"));
+    /// product.description = Some(format!("Everything in silver, plus priority support"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new() -> Self {
+        Product::default()
+    }
+
+    /// Asynchronously deletes a product that has no prices.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the product you want to delete.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let product = payup::stripe::Product::async_delete(&client, format!("prod_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_delete(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/products/{}", id.clone());
+        client.async_delete(&path).await
+    }
+
+    /// Asynchronously retrieves the details of an existing product.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the product you want to retrieve.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let product = payup::stripe::Product::async_get(&client, format!("prod_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/products/{}", id.clone());
+        client.async_get(&path).await
+    }
+
+    /// Asynchronously and lazily iterates over products, fetching pages from the stripe api only as they are needed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::ProductListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Walk the active products
+    /// let mut params = payup::stripe::ProductListParams::new();
+    /// params.active = Some(true);
+    /// let mut products = payup::stripe::Product::async_iter(&client, params);
+    /// while let Some(product) = products.next().await {
+    ///     let product = product?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn async_iter(client: &StripeClient, params: ProductListParams) -> ListStream<Self> {
+        list::stream(client, "/products", &params)
+    }
+
+    /// Asynchronously returns all products.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::ProductListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let products = payup::stripe::Product::async_list(&client, payup::stripe::ProductListParams::new()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_list(client: &StripeClient, params: ProductListParams) -> Result<Vec<Self>, StripeError>{
+        let params = ProductListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::async_iter(client, params).try_collect().await
+    }
+
+    /// Asynchronously POSTs a new Product to the stripe api
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut product = payup::stripe::Product::new();
+    /// product.name = Some(format!("Gold plan"));
+    ///
+    /// product = product.async_post(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.async_post("/products", &self.to_params()).await
+    }
+
+    /// Asynchronously and lazily iterates over the products matching a search query, e.g. by metadata
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SearchParams holding the query
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Find the products with "gold" in their name
+    /// let params = payup::stripe::SearchParams::new("name~'gold'");
+    /// let mut products = payup::stripe::Product::async_search(&client, params);
+    /// while let Some(product) = products.next().await {
+    ///     let product = product?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn async_search(client: &StripeClient, params: SearchParams) -> ListStream<Self> {
+        list::search_stream(client, "/products/search", &params)
+    }
+
+    /// Asynchronously POSTs an update to an existing Product
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut product = payup::stripe::Product::async_get(&client, format!("prod_")).await?;
+    /// product.description = Some(format!("Now with priority support"));
+    /// product = product.async_update(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_update(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.async_post(&format!("/products/{}", self.clone().id.unwrap()), &self.to_params()).await
+    }
+
+    /// Deletes a product that has no prices.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the product you want to delete.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let product = payup::stripe::Product::delete(&client, format!("prod_"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/products/{}", id.clone());
+        client.delete(&path)
+    }
+
+    /// Retrieves the details of an existing product.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the product you want to retrieve.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let product = payup::stripe::Product::get(&client, format!("prod_"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/products/{}", id.clone());
+        client.get(&path)
+    }
+
+    /// Lazily iterates over products, fetching pages from the stripe api only as they are needed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::ProductListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Walk the products that can be shipped
+    /// let mut params = payup::stripe::ProductListParams::new();
+    /// params.shippable = Some(true);
+    /// for product in payup::stripe::Product::iter(&client, params) {
+    ///     let product = product?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter(client: &StripeClient, params: ProductListParams) -> ListIter<Self> {
+        list::iter(client, "/products", &params)
+    }
+
+    /// Returns all products.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::ProductListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Fetch a few products by id
+    /// let mut params = payup::stripe::ProductListParams::new();
+    /// params.ids = Some(vec![format!("prod_a"), format!("prod_b")]);
+    /// let products = payup::stripe::Product::list(&client, params)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(client: &StripeClient, params: ProductListParams) -> Result<Vec<Self>, StripeError>{
+        let params = ProductListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::iter(client, params).collect()
+    }
+
+    /// POSTs a new Product to the stripe api
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut product = payup::stripe::Product::new();
+    /// product.name = Some(format!("Gold plan"));
+    /// product.unit_label = Some(format!("seat"));
+    ///
+    /// product = product.post(&client)?;
+    ///
+    /// // Add a monthly price to it
+    /// let mut recurring = payup::stripe::Recurring::new();
+    /// recurring.interval = Some(format!("month"));
+    ///
+    /// let mut price = payup::stripe::Price::new();
+    /// price.currency = Some(format!("usd"));
+    /// price.unit_amount = Some(2000);
+    /// price.recurring = Some(recurring);
+    /// price.product = product.id.clone().map(Into::into);
+    /// price = price.post(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.post("/products", &self.to_params())
+    }
+
+    /// Lazily iterates over the products matching a search query, e.g. by metadata
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SearchParams holding the query
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Find the products of a catalog
+    /// let params = payup::stripe::SearchParams::metadata("catalog", "2022");
+    /// for product in payup::stripe::Product::search(&client, params) {
+    ///     let product = product?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search(client: &StripeClient, params: SearchParams) -> ListIter<Self> {
+        list::search(client, "/products/search", &params)
+    }
+
+    /// POSTs an update to an existing Product
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Archive a product
+    /// let mut product = payup::stripe::Product::get(&client, format!("prod_"))?;
+    /// product.active = Some(false);
+    /// product = product.update(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.post(&format!("/products/{}", self.clone().id.unwrap()), &self.to_params())
+    }
+    fn to_params(&self) -> ProductParams<'_> {
+        ProductParams {
+            active: &self.active,
+            default_price: self.default_price.as_ref().and_then(Expandable::id),
+            description: &self.description,
+            images: &self.images,
+            name: &self.name,
+            shippable: &self.shippable,
+            statement_descriptor: &self.statement_descriptor,
+            tax_code: &self.tax_code,
+            unit_label: &self.unit_label,
+            url: &self.url,
+            metadata: &self.metadata,
+        }
+    }
+
+}
+
+impl Object for Product {
+    fn object_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+}
+
+/// Filters and pagination for listing products with `Product::list` and `Product::iter`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ProductListParams {
+    /// Only active or only archived products.
+    pub active: Option<bool>,
+    /// Only products created in this range.
+    pub created: Option<RangeQuery>,
+    /// Only the products with these ids.
+    pub ids: Option<Vec<String>>,
+    /// Only products that can or cannot be shipped.
+    pub shippable: Option<bool>,
+    /// Only products with this url.
+    pub url: Option<String>,
+    /// Number of objects fetched per page, between 1 and 100.
+    pub limit: Option<u32>,
+    /// Starts the list after the object with this id.
+    pub starting_after: Option<String>,
+    /// Starts the list before the object with this id and walks towards newer objects.
+    pub ending_before: Option<String>,
+}
+impl ProductListParams {
+    pub fn new() -> Self {
+        ProductListParams::default()
+    }
+}
+
+/// A customer facing code for a coupon, e.g. `SUMMER` for the `SUMMER25` coupon.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct PromotionCode {
+    pub id: Option<String>,
+    pub object: Option<String>,
+    /// Whether the code can be redeemed, promotion codes are deactivated instead of deleted.
+    pub active: Option<bool>,
+    /// The code customers enter, Stripe generates one when empty on create.
+    pub code: Option<String>,
+    /// The coupon applied by the code.
+    pub coupon: Option<Expandable<Coupon>>,
+    pub created: Option<i64>,
+    /// Only this customer can redeem the code.
+    pub customer: Option<Expandable<Customer>>,
+    /// Last time the code can be redeemed, as a unix timestamp.
+    pub expires_at: Option<i64>,
+    pub livemode: Option<bool>,
+    /// How often the code can be redeemed.
+    pub max_redemptions: Option<i64>,
+    pub restrictions: Option<PromotionCodeRestrictions>,
+    pub times_redeemed: Option<i64>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    /// Set when Stripe answered with the stored result of an earlier request with the same idempotency key.
+    #[serde(skip_serializing)]
+    pub idempotent_replayed: Option<bool>,
+}
+impl PromotionCode {
+
+    /// Returns an empty PromotionCode object
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// let mut promotion_code = payup::stripe::PromotionCode::new();
+    /// promotion_code.coupon = Some(format!("SUMMER25").into());
+    /// promotion_code.code = Some(format!("SUMMER"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new() -> Self {
+        PromotionCode::default()
+    }
+
+    /// Asynchronously retrieves a promotion code.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the promotion code you want to retrieve, not the code customers enter.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let promotion_code = payup::stripe::PromotionCode::async_get(&client, format!("promo_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/promotion_codes/{}", id.clone());
+        client.async_get(&path).await
+    }
+
+    /// Asynchronously and lazily iterates over promotion codes, fetching pages from the stripe api only as they are needed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::PromotionCodeListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let params = payup::stripe::PromotionCodeListParams::new();
+    /// let mut promotion_codes = payup::stripe::PromotionCode::async_iter(&client, params);
+    /// while let Some(promotion_code) = promotion_codes.next().await {
+    ///     let promotion_code = promotion_code?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn async_iter(client: &StripeClient, params: PromotionCodeListParams) -> ListStream<Self> {
+        list::stream(client, "/promotion_codes", &params)
+    }
+
+    /// Asynchronously returns all promotion codes.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::PromotionCodeListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Look up the code a customer entered
+    /// let mut params = payup::stripe::PromotionCodeListParams::new();
+    /// params.code = Some(format!("SUMMER"));
+    /// let promotion_codes = payup::stripe::PromotionCode::async_list(&client, params).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_list(client: &StripeClient, params: PromotionCodeListParams) -> Result<Vec<Self>, StripeError>{
+        let params = PromotionCodeListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::async_iter(client, params).try_collect().await
+    }
+
+    /// Asynchronously POSTs a new PromotionCode for a coupon to the stripe api
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // A code for first time customers spending at least $50
+    /// let mut restrictions = payup::stripe::PromotionCodeRestrictions::new();
+    /// restrictions.first_time_transaction = Some(true);
+    /// restrictions.minimum_amount = Some(5000);
+    /// restrictions.minimum_amount_currency = Some(format!("usd"));
+    ///
+    /// let mut promotion_code = payup::stripe::PromotionCode::new();
+    /// promotion_code.coupon = Some(format!("SUMMER25").into());
+    /// promotion_code.code = Some(format!("SUMMER"));
+    /// promotion_code.restrictions = Some(restrictions);
+    /// promotion_code = promotion_code.async_post(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.async_post("/promotion_codes", &self.to_create_params()).await
+    }
+
+    /// Asynchronously POSTs an update to an existing PromotionCode
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Promotion codes can not be deleted, deactivate them instead
+    /// let mut promotion_code = payup::stripe::PromotionCode::async_get(&client, format!("promo_")).await?;
+    /// promotion_code.active = Some(false);
+    /// promotion_code = promotion_code.async_update(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_update(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.async_post(&format!("/promotion_codes/{}", self.clone().id.unwrap()), &self.to_params()).await
+    }
+
+    /// Retrieves a promotion code.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the promotion code you want to retrieve, not the code customers enter.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let promotion_code = payup::stripe::PromotionCode::get(&client, format!("promo_"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/promotion_codes/{}", id.clone());
+        client.get(&path)
+    }
+
+    /// Lazily iterates over promotion codes, fetching pages from the stripe api only as they are needed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::PromotionCodeListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Walk the active codes of a coupon
+    /// let mut params = payup::stripe::PromotionCodeListParams::new();
+    /// params.coupon = Some(format!("SUMMER25"));
+    /// params.active = Some(true);
+    /// for promotion_code in payup::stripe::PromotionCode::iter(&client, params) {
+    ///     let promotion_code = promotion_code?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter(client: &StripeClient, params: PromotionCodeListParams) -> ListIter<Self> {
+        list::iter(client, "/promotion_codes", &params)
+    }
+
+    /// Returns all promotion codes.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::PromotionCodeListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Look up the code a customer entered
+    /// let mut params = payup::stripe::PromotionCodeListParams::new();
+    /// params.code = Some(format!("SUMMER"));
+    /// let promotion_codes = payup::stripe::PromotionCode::list(&client, params)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(client: &StripeClient, params: PromotionCodeListParams) -> Result<Vec<Self>, StripeError>{
+        let params = PromotionCodeListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::iter(client, params).collect()
+    }
+
+    /// POSTs a new PromotionCode for a coupon to the stripe api
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // A code for first time customers spending at least $50
+    /// let mut restrictions = payup::stripe::PromotionCodeRestrictions::new();
+    /// restrictions.first_time_transaction = Some(true);
+    /// restrictions.minimum_amount = Some(5000);
+    /// restrictions.minimum_amount_currency = Some(format!("usd"));
+    ///
+    /// let mut promotion_code = payup::stripe::PromotionCode::new();
+    /// promotion_code.coupon = Some(format!("SUMMER25").into());
+    /// promotion_code.code = Some(format!("SUMMER"));
+    /// promotion_code.restrictions = Some(restrictions);
+    /// promotion_code = promotion_code.post(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.post("/promotion_codes", &self.to_create_params())
+    }
+
+    /// POSTs an update to an existing PromotionCode
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Promotion codes can not be deleted, deactivate them instead
+    /// let mut promotion_code = payup::stripe::PromotionCode::get(&client, format!("promo_"))?;
+    /// promotion_code.active = Some(false);
+    /// promotion_code = promotion_code.update(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.post(&format!("/promotion_codes/{}", self.clone().id.unwrap()), &self.to_params())
+    }
+    fn to_create_params(&self) -> PromotionCodeCreateParams<'_> {
+        PromotionCodeCreateParams {
+            params: self.to_params(),
+            code: &self.code,
+            coupon: self.coupon.as_ref().and_then(Expandable::id),
+            customer: self.customer.as_ref().and_then(Expandable::id),
+            expires_at: &self.expires_at,
+            max_redemptions: &self.max_redemptions,
+            restrictions: &self.restrictions,
+        }
+    }
+    fn to_params(&self) -> PromotionCodeParams<'_> {
+        PromotionCodeParams {
+            active: &self.active,
+            metadata: &self.metadata,
+        }
+    }
+
+}
+
+impl Object for PromotionCode {
+    fn object_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+}
+
+/// Filters and pagination for listing promotion codes with `PromotionCode::list` and `PromotionCode::iter`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct PromotionCodeListParams {
+    /// Only active or only inactive codes.
+    pub active: Option<bool>,
+    /// Only the promotion codes with this code, case insensitive.
+    pub code: Option<String>,
+    /// Only the promotion codes of this coupon id.
+    pub coupon: Option<String>,
+    /// Only promotion codes created in this range.
+    pub created: Option<RangeQuery>,
+    /// Only the promotion codes restricted to this customer id.
+    pub customer: Option<String>,
+    /// Number of objects fetched per page, between 1 and 100.
+    pub limit: Option<u32>,
+    /// Starts the list after the object with this id.
+    pub starting_after: Option<String>,
+    /// Starts the list before the object with this id and walks towards newer objects.
+    pub ending_before: Option<String>,
+}
+impl PromotionCodeListParams {
+    pub fn new() -> Self {
+        PromotionCodeListParams::default()
+    }
+}
+
+/// Conditions an order has to meet to redeem a promotion code.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct PromotionCodeRestrictions {
+    /// Only customers that never paid before can redeem the code.
+    pub first_time_transaction: Option<bool>,
+    /// Minimum amount in cents of the order, requires `minimum_amount_currency`.
+    pub minimum_amount: Option<i64>,
+    pub minimum_amount_currency: Option<String>,
+}
+impl PromotionCodeRestrictions {
+    pub fn new() -> Self {
+        PromotionCodeRestrictions::default()
+    }
+}
+
+
+/// Refund objects allow you to refund a charge that has previously been created but not yet refunded.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Refund {
+    pub id: Option<String>,
+    pub object: Option<String>,
+    /// Amount to refund in cents, defaults to the whole remaining amount of the charge.
+    pub amount: Option<i64>,
+    #[serde(rename = "balance_transaction")]
+    pub balance_transaction: Option<Expandable<BalanceTransaction>>,
+    pub charge: Option<Expandable<Charge>>,
+    pub created: Option<i64>,
+    pub currency: Option<String>,
+    #[serde(rename = "failure_reason")]
+    pub failure_reason: Option<String>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    #[serde(rename = "payment_intent")]
+    pub payment_intent: Option<Expandable<PaymentIntent>>,
+    /// duplicate, fraudulent or requested_by_customer.
+    pub reason: Option<String>,
+    #[serde(rename = "receipt_number")]
+    pub receipt_number: Option<String>,
+    /// Also refund the application fee of a Connect charge. Only sent when creating.
+    #[serde(rename = "refund_application_fee")]
+    pub refund_application_fee: Option<bool>,
+    /// Also reverse the transfer of a Connect charge. Only sent when creating.
+    #[serde(rename = "reverse_transfer")]
+    pub reverse_transfer: Option<bool>,
+    /// pending, requires_action, succeeded, failed or canceled.
+    pub status: Option<String>,
+    /// Set when Stripe answered with the stored result of an earlier request with the same idempotency key.
+    #[serde(skip_serializing)]
+    pub idempotent_replayed: Option<bool>,
+}
+impl Refund {
+
+    /// Returns an empty Refund object
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// let mut refund = payup::stripe::Refund::new();
+    /// refund.charge = Some(format!("ch_").into());
+    /// refund.amount = Some(50);
+    /// refund.reason = Some(format!("requested_by_customer"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new() -> Self {
+        Refund::default()
+    }
+
+    /// Asynchronously cancels a refund that is still waiting for an action, e.g. one with the status requires_action.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the refund you want to cancel.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let refund = payup::stripe::Refund::async_cancel(&client, format!("re_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_cancel(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/refunds/{}/cancel", id.clone());
+        client.async_post(&path, &()).await
+    }
+
+    /// Asynchronously retrieves the details of an existing refund.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the refund you want to retrieve.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let refund = payup::stripe::Refund::async_get(&client, format!("re_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/refunds/{}", id.clone());
+        client.async_get(&path).await
+    }
+
+    /// Asynchronously and lazily iterates over refunds, fetching pages from the stripe api only as they are needed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::RefundListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Walk the refunds of a charge
+    /// let mut params = payup::stripe::RefundListParams::new();
+    /// params.charge = Some(format!("ch_"));
+    /// let mut refunds = payup::stripe::Refund::async_iter(&client, params);
+    /// while let Some(refund) = refunds.next().await {
+    ///     let refund = refund?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn async_iter(client: &StripeClient, params: RefundListParams) -> ListStream<Self> {
+        list::stream(client, "/refunds", &params)
+    }
+
+    /// Asynchronously returns all refunds.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::RefundListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let refunds = payup::stripe::Refund::async_list(&client, payup::stripe::RefundListParams::new()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_list(client: &StripeClient, params: RefundListParams) -> Result<Vec<Self>, StripeError>{
+        let params = RefundListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::async_iter(client, params).try_collect().await
+    }
+
+    /// Asynchronously POSTs a new Refund to the stripe api
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Refund a PaymentIntent in full
+    /// let mut refund = payup::stripe::Refund::new();
+    /// refund.payment_intent = Some(format!("pi_").into());
+    ///
+    /// refund = refund.async_post(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.async_post("/refunds", &self.to_params()).await
+    }
+
+    /// Asynchronously POSTs an update to the metadata of an existing Refund
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut refund = payup::stripe::Refund::async_get(&client, format!("re_")).await?;
+    /// refund.metadata.insert(format!("ticket"), format!("4711"));
+    /// refund = refund.async_update(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_update(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.async_post(&format!("/refunds/{}", self.clone().id.unwrap()), &self.to_update_params()).await
+    }
+
+    /// Cancels a refund that is still waiting for an action, e.g. one with the status requires_action.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the refund you want to cancel.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let refund = payup::stripe::Refund::cancel(&client, format!("re_"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cancel(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/refunds/{}/cancel", id.clone());
+        client.post(&path, &())
+    }
+
+    /// Retrieves the details of an existing refund.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the refund you want to retrieve.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let refund = payup::stripe::Refund::get(&client, format!("re_"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/refunds/{}", id.clone());
+        client.get(&path)
+    }
+
+    /// Lazily iterates over refunds, fetching pages from the stripe api only as they are needed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::RefundListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Walk the refunds of a PaymentIntent
+    /// let mut params = payup::stripe::RefundListParams::new();
+    /// params.payment_intent = Some(format!("pi_"));
+    /// for refund in payup::stripe::Refund::iter(&client, params) {
+    ///     let refund = refund?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter(client: &StripeClient, params: RefundListParams) -> ListIter<Self> {
+        list::iter(client, "/refunds", &params)
+    }
+
+    /// Returns all refunds.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::RefundListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Fetch all refunds made in January 2022
+    /// let mut params = payup::stripe::RefundListParams::new();
+    /// params.created = Some(payup::stripe::RangeQuery::between(1640995200, 1643673599));
+    /// let refunds = payup::stripe::Refund::list(&client, params)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(client: &StripeClient, params: RefundListParams) -> Result<Vec<Self>, StripeError>{
+        let params = RefundListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::iter(client, params).collect()
+    }
+
+    /// POSTs a new Refund to the stripe api
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Refund half of a charge
+    /// let mut refund = payup::stripe::Refund::new();
+    /// refund.charge = Some(format!("ch_").into());
+    /// refund.amount = Some(50);
+    /// refund.reason = Some(format!("requested_by_customer"));
+    ///
+    /// refund = refund.post(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.post("/refunds", &self.to_params())
+    }
+
+    /// POSTs an update to the metadata of an existing Refund
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut refund = payup::stripe::Refund::get(&client, format!("re_"))?;
+    /// refund.metadata.insert(format!("ticket"), format!("4711"));
+    /// refund = refund.update(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.post(&format!("/refunds/{}", self.clone().id.unwrap()), &self.to_update_params())
+    }
+    fn to_params(&self) -> RefundParams<'_> {
+        RefundParams {
+            amount: &self.amount,
+            charge: self.charge.as_ref().and_then(Expandable::id),
+            payment_intent: self.payment_intent.as_ref().and_then(Expandable::id),
+            reason: &self.reason,
+            refund_application_fee: &self.refund_application_fee,
+            reverse_transfer: &self.reverse_transfer,
+            metadata: &self.metadata,
+        }
+    }
+    fn to_update_params(&self) -> RefundUpdateParams<'_> {
+        RefundUpdateParams {
+            metadata: &self.metadata,
+        }
+    }
+
+}
+
+impl Object for Refund {
+    fn object_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+}
+
+/// Filters and pagination for listing refunds with `Refund::list` and `Refund::iter`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct RefundListParams {
+    /// Only refunds of this charge id.
+    pub charge: Option<String>,
+    /// Only refunds created in this range.
+    pub created: Option<RangeQuery>,
+    /// Only refunds of this PaymentIntent id.
+    pub payment_intent: Option<String>,
+    /// Number of objects fetched per page, between 1 and 100.
+    pub limit: Option<u32>,
+    /// Starts the list after the object with this id.
+    pub starting_after: Option<String>,
+    /// Starts the list before the object with this id and walks towards newer objects.
+    pub ending_before: Option<String>,
+}
+impl RefundListParams {
+    pub fn new() -> Self {
+        RefundListParams::default()
+    }
+}
+
+
+/// A SetupIntent guides you through saving a customer's payment method for later payments, including 3D Secure authentication and mandates.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SetupIntent {
+    pub id: Option<String>,
+    pub object: Option<String>,
+    /// Why the SetupIntent was canceled, sent with `cancel`: abandoned, requested_by_customer or duplicate.
+    #[serde(rename = "cancellation_reason")]
+    pub cancellation_reason: Option<String>,
+    /// The secret handed to Stripe.js on the client side to confirm the setup there.
+    #[serde(rename = "client_secret")]
+    pub client_secret: Option<String>,
+    /// Confirm the SetupIntent right away when creating it. Only sent when creating.
+    pub confirm: Option<bool>,
+    pub created: Option<i64>,
+    pub customer: Option<Expandable<Customer>>,
+    pub description: Option<String>,
+    #[serde(rename = "last_setup_error")]
+    pub last_setup_error: Option<ApiError>,
+    #[serde(rename = "latest_attempt")]
+    pub latest_attempt: Option<String>,
+    pub livemode: Option<bool>,
+    /// The Mandate created when the SetupIntent succeeded, if the payment method needs one.
+    pub mandate: Option<Expandable<Mandate>>,
+    /// How the customer accepted the mandate. Only sent when creating with `confirm` or when confirming.
+    #[serde(rename = "mandate_data")]
+    pub mandate_data: Option<MandateData>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    /// What the customer has to do before the setup can go on, e.g. authenticate with 3D Secure.
+    #[serde(rename = "next_action")]
+    pub next_action: Option<NextAction>,
+    #[serde(rename = "payment_method")]
+    pub payment_method: Option<String>,
+    #[serde(rename = "payment_method_types")]
+    pub payment_method_types: Option<Vec<String>>,
+    /// Where the customer is sent back to after authenticating on a redirect. Only sent when creating or confirming.
+    pub return_url: Option<String>,
+    pub status: Option<SetupIntentStatus>,
+    /// `off_session` (the default) or `on_session`, how the saved payment method will be used.
+    pub usage: Option<String>,
+    /// Set when Stripe answered with the stored result of an earlier request with the same idempotency key.
+    #[serde(skip_serializing)]
+    pub idempotent_replayed: Option<bool>,
+}
+impl SetupIntent {
+
+    /// Returns an empty SetupIntent object
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// let mut setup_intent = payup::stripe::SetupIntent::new();
+    /// setup_intent.customer = Some(format!("cus_").into());
+    /// setup_intent.payment_method_types = Some(vec![format!("card")]);
+    /// setup_intent.usage = Some(format!("off_session"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new() -> Self {
+        SetupIntent::default()
+    }
+
+    /// Asynchronously cancels a SetupIntent that has not succeeded yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut setup_intent = payup::stripe::SetupIntent::async_get(&client, format!("seti_")).await?;
+    /// setup_intent.cancellation_reason = Some(format!("abandoned"));
+    /// let setup_intent = setup_intent.async_cancel(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_cancel(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/setup_intents/{}/cancel", id);
+        client.async_post(&path, &self.to_cancel_params()).await
+    }
+
+    /// Asynchronously confirms that the customer intends to save the attached payment method.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut setup_intent = payup::stripe::SetupIntent::async_get(&client, format!("seti_")).await?;
+    /// setup_intent.payment_method = Some(format!("pm_card_visa"));
+    /// setup_intent.return_url = Some(format!("https://example.com/account/cards"));
+    /// let setup_intent = setup_intent.async_confirm(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_confirm(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/setup_intents/{}/confirm", id);
+        client.async_post(&path, &self.to_confirm_params()).await
+    }
+
+    /// Asynchronously retrieves the details of a SetupIntent that has previously been created.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the SetupIntent you want to retrieve.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let setup_intent = payup::stripe::SetupIntent::async_get(&client, format!("seti_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/setup_intents/{}", id.clone());
+        client.async_get(&path).await
+    }
+
+    /// Asynchronously returns the Mandate of a succeeded SetupIntent, fetching it unless it was expanded.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let setup_intent = payup::stripe::SetupIntent::async_get(&client, format!("seti_")).await?;
+    /// if let Some(mandate) = setup_intent.async_get_mandate(&client).await? {
+    ///     println!("mandate {} is {}", mandate.id, mandate.status);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_get_mandate(&self, client: &StripeClient) -> Result<Option<Mandate>, StripeError> {
+        match &self.mandate {
+            Some(Expandable::Object(mandate)) => Ok(Some(*mandate.clone())),
+            Some(Expandable::Id(id)) => Mandate::async_get(client, id.clone()).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Asynchronously and lazily iterates over SetupIntents, fetching pages from the stripe api only as they are needed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SetupIntentListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Walk the SetupIntents of a customer
+    /// let mut params = payup::stripe::SetupIntentListParams::new();
+    /// params.customer = Some(format!("cus_"));
+    /// let mut setup_intents = payup::stripe::SetupIntent::async_iter(&client, params);
+    /// while let Some(setup_intent) = setup_intents.next().await {
+    ///     let setup_intent = setup_intent?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn async_iter(client: &StripeClient, params: SetupIntentListParams) -> ListStream<Self> {
+        list::stream(client, "/setup_intents", &params)
+    }
+
+    /// Asynchronously returns all SetupIntents.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SetupIntentListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let setup_intents = payup::stripe::SetupIntent::async_list(&client, payup::stripe::SetupIntentListParams::new()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_list(client: &StripeClient, params: SetupIntentListParams) -> Result<Vec<Self>, StripeError>{
+        let params = SetupIntentListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::async_iter(client, params).try_collect().await
+    }
+
+    /// Asynchronously POSTs a new SetupIntent to the stripe api
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut setup_intent = payup::stripe::SetupIntent::new();
+    /// setup_intent.customer = Some(format!("cus_").into());
+    /// setup_intent.payment_method_types = Some(vec![format!("card")]);
+    ///
+    /// setup_intent = setup_intent.async_post(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.async_post("/setup_intents", &self.to_create_params()).await
+    }
+
+    /// Asynchronously POSTs an update to an existing SetupIntent
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut setup_intent = payup::stripe::SetupIntent::async_get(&client, format!("seti_")).await?;
+    /// setup_intent.description = Some(format!("Card for the monthly plan"));
+    /// setup_intent = setup_intent.async_update(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_update(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        client.async_post(&format!("/setup_intents/{}", id), &self.to_params()).await
+    }
+
+    /// Asynchronously verifies the bank account of a SetupIntent with the microdeposits Stripe sent to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::VerifyMicrodepositsParams with the two amounts or the descriptor code
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let setup_intent = payup::stripe::SetupIntent::async_get(&client, format!("seti_")).await?;
+    /// let params = payup::stripe::VerifyMicrodepositsParams::descriptor_code("SM11AA");
+    /// let setup_intent = setup_intent.async_verify_microdeposits(&client, params).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_verify_microdeposits(&self, client: &StripeClient, params: VerifyMicrodepositsParams) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/setup_intents/{}/verify_microdeposits", id);
+        client.async_post(&path, &params).await
+    }
+
+    /// Cancels a SetupIntent that has not succeeded yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut setup_intent = payup::stripe::SetupIntent::get(&client, format!("seti_"))?;
+    /// setup_intent.cancellation_reason = Some(format!("abandoned"));
+    /// let setup_intent = setup_intent.cancel(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cancel(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/setup_intents/{}/cancel", id);
+        client.post(&path, &self.to_cancel_params())
+    }
+
+    /// Confirms that the customer intends to save the attached payment method.
+    ///
+    /// If the card needs 3D Secure the returned SetupIntent has the status `RequiresAction` and a `next_action` to hand to the customer.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Record that the customer accepted the SEPA mandate in our checkout
+    /// let mut acceptance = payup::stripe::CustomerAcceptance::new();
+    /// acceptance.type_field = format!("online");
+    /// acceptance.online = Some(payup::stripe::Online {
+    ///     ip_address: Some(format!("127.0.0.1")),
+    ///     user_agent: Some(format!("Mozilla/5.0")),
+    /// });
+    ///
+    /// let mut setup_intent = payup::stripe::SetupIntent::get(&client, format!("seti_"))?;
+    /// setup_intent.payment_method = Some(format!("pm_"));
+    /// setup_intent.mandate_data = Some(payup::stripe::MandateData { customer_acceptance: acceptance });
+    /// let setup_intent = setup_intent.confirm(&client)?;
+    ///
+    /// if setup_intent.status == Some(payup::stripe::SetupIntentStatus::Succeeded) {
+    ///     let mandate = setup_intent.get_mandate(&client)?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn confirm(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/setup_intents/{}/confirm", id);
+        client.post(&path, &self.to_confirm_params())
+    }
+
+    /// Retrieves the details of a SetupIntent that has previously been created.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the SetupIntent you want to retrieve.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let setup_intent = payup::stripe::SetupIntent::get(&client, format!("seti_"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/setup_intents/{}", id.clone());
+        client.get(&path)
+    }
+
+    /// Returns the Mandate of a succeeded SetupIntent, fetching it unless it was expanded.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let setup_intent = payup::stripe::SetupIntent::get(&client, format!("seti_"))?;
+    /// if let Some(mandate) = setup_intent.get_mandate(&client)? {
+    ///     println!("mandate {} is {}", mandate.id, mandate.status);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_mandate(&self, client: &StripeClient) -> Result<Option<Mandate>, StripeError> {
+        match &self.mandate {
+            Some(Expandable::Object(mandate)) => Ok(Some(*mandate.clone())),
+            Some(Expandable::Id(id)) => Mandate::get(client, id.clone()).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Lazily iterates over SetupIntents, fetching pages from the stripe api only as they are needed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SetupIntentListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Walk the SetupIntents of a customer
+    /// let mut params = payup::stripe::SetupIntentListParams::new();
+    /// params.customer = Some(format!("cus_"));
+    /// for setup_intent in payup::stripe::SetupIntent::iter(&client, params) {
+    ///     let setup_intent = setup_intent?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter(client: &StripeClient, params: SetupIntentListParams) -> ListIter<Self> {
+        list::iter(client, "/setup_intents", &params)
+    }
+
+    /// Returns all SetupIntents.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SetupIntentListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Fetch the SetupIntents that saved a given payment method
+    /// let mut params = payup::stripe::SetupIntentListParams::new();
+    /// params.payment_method = Some(format!("pm_"));
+    /// let setup_intents = payup::stripe::SetupIntent::list(&client, params)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(client: &StripeClient, params: SetupIntentListParams) -> Result<Vec<Self>, StripeError>{
+        let params = SetupIntentListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::iter(client, params).collect()
+    }
+
+    /// POSTs a new SetupIntent to the stripe api
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut setup_intent = payup::stripe::SetupIntent::new();
+    /// setup_intent.customer = Some(format!("cus_").into());
+    /// setup_intent.payment_method_types = Some(vec![format!("card")]);
+    /// setup_intent.usage = Some(format!("off_session"));
+    ///
+    /// // Hand the client_secret to Stripe.js to collect the card
+    /// setup_intent = setup_intent.post(&client)?;
+    /// println!("{:?}", setup_intent.client_secret);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.post("/setup_intents", &self.to_create_params())
+    }
+
+    /// POSTs an update to an existing SetupIntent
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut setup_intent = payup::stripe::SetupIntent::get(&client, format!("seti_"))?;
+    /// setup_intent.metadata.insert(format!("plan"), format!("monthly"));
+    /// setup_intent = setup_intent.update(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        client.post(&format!("/setup_intents/{}", id), &self.to_params())
+    }
+
+    /// Verifies the bank account of a SetupIntent with the microdeposits Stripe sent to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::VerifyMicrodepositsParams with the two amounts or the descriptor code
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let setup_intent = payup::stripe::SetupIntent::get(&client, format!("seti_"))?;
+    /// let params = payup::stripe::VerifyMicrodepositsParams::amounts(32, 45);
+    /// let setup_intent = setup_intent.verify_microdeposits(&client, params)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn verify_microdeposits(&self, client: &StripeClient, params: VerifyMicrodepositsParams) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/setup_intents/{}/verify_microdeposits", id);
+        client.post(&path, &params)
+    }
+    fn to_cancel_params(&self) -> SetupIntentCancelParams<'_> {
+        SetupIntentCancelParams {
+            cancellation_reason: &self.cancellation_reason,
+        }
+    }
+    fn to_confirm_params(&self) -> SetupIntentConfirmParams<'_> {
+        SetupIntentConfirmParams {
+            mandate_data: &self.mandate_data,
+            payment_method: &self.payment_method,
+            return_url: &self.return_url,
+        }
+    }
+    fn to_create_params(&self) -> SetupIntentCreateParams<'_> {
+        SetupIntentCreateParams {
+            params: self.to_params(),
+            confirm: &self.confirm,
+            mandate_data: &self.mandate_data,
+            return_url: &self.return_url,
+            usage: &self.usage,
+        }
+    }
+    fn to_params(&self) -> SetupIntentParams<'_> {
+        SetupIntentParams {
+            customer: self.customer.as_ref().and_then(Expandable::id),
+            description: &self.description,
+            payment_method: &self.payment_method,
+            payment_method_types: &self.payment_method_types,
+            metadata: &self.metadata,
+        }
+    }
+
+}
+
+impl Object for SetupIntent {
+    fn object_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+}
+
+/// Filters and pagination for listing SetupIntents with `SetupIntent::list` and `SetupIntent::iter`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SetupIntentListParams {
+    /// Only SetupIntents created in this range.
+    pub created: Option<RangeQuery>,
+    /// Only SetupIntents for this customer id.
+    pub customer: Option<String>,
+    /// Only SetupIntents that saved this payment method id.
+    pub payment_method: Option<String>,
+    /// Number of objects fetched per page, between 1 and 100.
+    pub limit: Option<u32>,
+    /// Starts the list after the object with this id.
+    pub starting_after: Option<String>,
+    /// Starts the list before the object with this id and walks towards newer objects.
+    pub ending_before: Option<String>,
+}
+impl SetupIntentListParams {
+    pub fn new() -> Self {
+        SetupIntentListParams::default()
+    }
+}
+
+/// Where a SetupIntent is in its lifecycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SetupIntentStatus {
+    /// A payment method has to be attached, or the last one failed.
+    RequiresPaymentMethod,
+    /// The payment method is attached and the SetupIntent waits for `confirm`.
+    RequiresConfirmation,
+    /// The customer has to act first, see `next_action`.
+    RequiresAction,
+    Processing,
+    Canceled,
+    Succeeded,
+    /// A status this version of the crate does not know yet, with its name.
+    Other(String),
+}
+
+impl SetupIntentStatus {
+    /// The name Stripe uses for the status, e.g. `requires_action`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            SetupIntentStatus::RequiresPaymentMethod => "requires_payment_method",
+            SetupIntentStatus::RequiresConfirmation => "requires_confirmation",
+            SetupIntentStatus::RequiresAction => "requires_action",
+            SetupIntentStatus::Processing => "processing",
+            SetupIntentStatus::Canceled => "canceled",
+            SetupIntentStatus::Succeeded => "succeeded",
+            SetupIntentStatus::Other(name) => name,
+        }
+    }
+}
+
+impl From<&str> for SetupIntentStatus {
+    fn from(name: &str) -> Self {
+        match name {
+            "requires_payment_method" => SetupIntentStatus::RequiresPaymentMethod,
+            "requires_confirmation" => SetupIntentStatus::RequiresConfirmation,
+            "requires_action" => SetupIntentStatus::RequiresAction,
+            "processing" => SetupIntentStatus::Processing,
+            "canceled" => SetupIntentStatus::Canceled,
+            "succeeded" => SetupIntentStatus::Succeeded,
+            _ => SetupIntentStatus::Other(name.to_string()),
+        }
+    }
+}
+
+impl Serialize for SetupIntentStatus {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for SetupIntentStatus {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(|name| SetupIntentStatus::from(name.as_str()))
+    }
+}
+
+/// How the customer accepted the mandate created by a SetupIntent.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct MandateData {
+    pub customer_acceptance: CustomerAcceptance,
+}
+
+/// The amounts or the descriptor code of the microdeposits sent to a bank account, see `SetupIntent::verify_microdeposits`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct VerifyMicrodepositsParams {
+    /// The two amounts in cents deposited to the account.
+    pub amounts: Option<Vec<i64>>,
+    /// The six character code starting with SM on the statement of the deposit.
+    pub descriptor_code: Option<String>,
+}
+impl VerifyMicrodepositsParams {
+    pub fn new() -> Self {
+        VerifyMicrodepositsParams::default()
+    }
+
+    /// Verifies with the two amounts in cents deposited to the account.
+    pub fn amounts(first: i64, second: i64) -> Self {
+        VerifyMicrodepositsParams {
+            amounts: Some(vec![first, second]),
+            ..VerifyMicrodepositsParams::default()
+        }
+    }
+
+    /// Verifies with the code on the statement of the deposit.
+    pub fn descriptor_code(code: impl Into<String>) -> Self {
+        VerifyMicrodepositsParams {
+            descriptor_code: Some(code.into()),
+            ..VerifyMicrodepositsParams::default()
+        }
+    }
+}
+
+
+/// Subscriptions allow you to charge a customer on a recurring basis.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Subscription {
+    pub id: Option<String>,
+    pub object: Option<String>,
+    pub billing_cycle_anchor: Option<i64>,
+    pub cancel_at: Option<Timestamp>,
+    /// Cancel the subscription at the end of the current period instead of renewing it.
+    pub cancel_at_period_end: Option<bool>,
+    pub canceled_at: Option<i64>,
+    pub collection_method: Option<String>,
+    /// Id of a coupon to apply to the subscription. Only sent when creating or updating.
+    pub coupon: Option<String>,
+    pub created: Option<i64>,
+    pub current_period_end: Option<i64>,
+    pub current_period_start: Option<i64>,
+    pub customer: Option<Expandable<Customer>>,
+    pub days_until_due: Option<i64>,
+    pub default_payment_method: Option<String>,
+    pub discount: Option<Discount>,
+    pub ended_at: Option<i64>,
+    /// The prices the customer is subscribed to, each with its quantity.
+    #[serde(default, deserialize_with = "list::data")]
+    pub items: Vec<SubscriptionItem>,
+    pub latest_invoice: Option<Expandable<Invoice>>,
+    pub livemode: Option<bool>,
+    /// Pauses collecting payments, an empty PauseCollection resumes collection on update.
+    pub pause_collection: Option<PauseCollection>,
+    /// `allow_incomplete`, `default_incomplete`, `error_if_incomplete` or `pending_if_incomplete`. Only sent when creating or updating.
+    pub payment_behavior: Option<String>,
+    /// Price ids to subscribe to with a quantity of one each, a shorthand for `items`. Only sent when creating or updating.
+    pub price_items: Option<Vec<String>>,
+    /// Id of a promotion code to apply to the subscription. Only sent when creating or updating.
+    pub promotion_code: Option<String>,
+    /// `create_prorations`, `none` or `always_invoice`, how changes are prorated. Only sent when creating, updating or resuming.
+    pub proration_behavior: Option<String>,
+    pub quantity: Option<i64>,
+    pub start_date: Option<i64>,
+    pub status: Option<String>,
+    /// End of the trial period, `Timestamp::Now` ends it right away.
+    pub trial_end: Option<Timestamp>,
+    /// Number of trial days before the first charge. Only sent when creating.
+    pub trial_period_days: Option<i64>,
+    pub trial_start: Option<i64>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    /// Set when Stripe answered with the stored result of an earlier request with the same idempotency key.
+    #[serde(skip_serializing)]
+    pub idempotent_replayed: Option<bool>,
+}
+impl Subscription {
+
+    /// Returns an empty Subscription object
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// let mut seats = payup::stripe::SubscriptionItem::new();
+    /// seats.price = Some(format!("price_seats").into());
+    /// seats.quantity = Some(5);
+    ///
+    /// let mut subscription = payup::stripe::Subscription::new();
+    /// subscription.customer = Some(format!("cus_").into());
+    /// subscription.items = vec![seats];
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new() -> Self {
+        Subscription::default()
+    }
+
+    /// Asynchronously cancels a subscription right away.
+    ///
+    /// Set `cancel_at_period_end` and update the subscription instead to cancel it once the paid period is over.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the subscription you want to cancel.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let subscription = payup::stripe::Subscription::async_cancel(&client, format!("sub_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_cancel(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/subscriptions/{}", id.clone());
+        client.async_delete(&path).await
+    }
+
+    /// Asynchronously removes the discount of a subscription, e.g. to end a coupon that lasts forever.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the subscription whose discount you want to remove.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let discount = payup::stripe::Subscription::async_delete_discount(&client, format!("sub_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_delete_discount(client: &StripeClient, id: String) -> Result<Discount, StripeError> {
+        let path = format!("/subscriptions/{}/discount", id.clone());
+        client.async_delete(&path).await
+    }
+
+    /// Asynchronously retrieves the details of an existing subscription.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the subscription you want to retrieve.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let subscription = payup::stripe::Subscription::async_get(&client, format!("sub_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/subscriptions/{}", id.clone());
+        client.async_get(&path).await
+    }
+
+    /// Asynchronously and lazily iterates over subscriptions, fetching pages from the stripe api only as they are needed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SubscriptionListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Walk the subscriptions that failed to pay
+    /// let mut params = payup::stripe::SubscriptionListParams::new();
+    /// params.status = Some(format!("past_due"));
+    /// let mut subscriptions = payup::stripe::Subscription::async_iter(&client, params);
+    /// while let Some(subscription) = subscriptions.next().await {
+    ///     let subscription = subscription?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn async_iter(client: &StripeClient, params: SubscriptionListParams) -> ListStream<Self> {
+        list::stream(client, "/subscriptions", &params)
+    }
+
+    /// Asynchronously returns all subscriptions.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SubscriptionListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut params = payup::stripe::SubscriptionListParams::new();
+    /// params.customer = Some(format!("cus_"));
+    /// let subscriptions = payup::stripe::Subscription::async_list(&client, params).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_list(client: &StripeClient, params: SubscriptionListParams) -> Result<Vec<Self>, StripeError>{
+        let params = SubscriptionListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::async_iter(client, params).try_collect().await
+    }
+
+    /// Asynchronously POSTs a new Subscription to the stripe api
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut subscription = payup::stripe::Subscription::new();
+    /// subscription.customer = Some(format!("cus_").into());
+    /// subscription.price_items = Some(vec![format!("price_")]);
+    /// subscription.trial_period_days = Some(14);
+    ///
+    /// subscription = subscription.async_post(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.async_post("/subscriptions", &self.to_create_params()).await
+    }
+
+    /// Asynchronously resumes a paused subscription, e.g. one whose trial ended without a payment method.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let subscription = payup::stripe::Subscription::async_get(&client, format!("sub_")).await?;
+    /// let subscription = subscription.async_resume(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_resume(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/subscriptions/{}/resume", id);
+        client.async_post(&path, &self.to_resume_params()).await
+    }
+
+    /// Asynchronously and lazily iterates over the subscriptions matching a search query, e.g. by metadata
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SearchParams holding the query
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Find the active subscriptions of a plan tier
+    /// let params = payup::stripe::SearchParams::new("status:'active' AND metadata['tier']:'gold'");
+    /// let mut subscriptions = payup::stripe::Subscription::async_search(&client, params);
+    /// while let Some(subscription) = subscriptions.next().await {
+    ///     let subscription = subscription?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn async_search(client: &StripeClient, params: SearchParams) -> ListStream<Self> {
+        list::search_stream(client, "/subscriptions/search", &params)
+    }
+
+    /// Asynchronously POSTs an update to an existing Subscription, only the fields set in `params` are changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the Subscription you want to update.
+    /// * `params` - payup::stripe::SubscriptionUpdateParams with the fields to change
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Cancel once the paid period is over
+    /// let mut params = payup::stripe::SubscriptionUpdateParams::new();
+    /// params.cancel_at_period_end = Some(true);
+    /// let subscription = payup::stripe::Subscription::async_update(&client, format!("sub_"), params).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_update(client: &StripeClient, id: String, params: SubscriptionUpdateParams) -> Result<Self, StripeError> {
+        client.async_post(&format!("/subscriptions/{}", id), &params.to_params()).await
+    }
+
+    /// Cancels a subscription right away.
+    ///
+    /// Set `cancel_at_period_end` and update the subscription instead to cancel it once the paid period is over.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the subscription you want to cancel.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let subscription = payup::stripe::Subscription::cancel(&client, format!("sub_"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cancel(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/subscriptions/{}", id.clone());
+        client.delete(&path)
+    }
+
+    /// Removes the discount of a subscription, e.g. to end a coupon that lasts forever.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the subscription whose discount you want to remove.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let discount = payup::stripe::Subscription::delete_discount(&client, format!("sub_"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_discount(client: &StripeClient, id: String) -> Result<Discount, StripeError> {
+        let path = format!("/subscriptions/{}/discount", id.clone());
+        client.delete(&path)
+    }
+
+    /// Returns a subscription
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - A string representing an existing stripe subscription_id
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let subscription = payup::stripe::Subscription::get(&client, format!("sub_"))?;
+    /// for item in &subscription.items {
+    ///     println!("{:?} x {:?}", item.price.as_ref().and_then(|price| price.id()), item.quantity);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/subscriptions/{}", id.clone());
+        client.get(&path)
+    }
+
+    /// Lazily iterates over subscriptions, fetching pages from the stripe api only as they are needed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SubscriptionListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Walk every subscription of a price, canceled ones included
+    /// let mut params = payup::stripe::SubscriptionListParams::new();
+    /// params.price = Some(format!("price_"));
+    /// params.status = Some(format!("all"));
+    /// for subscription in payup::stripe::Subscription::iter(&client, params) {
+    ///     let subscription = subscription?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter(client: &StripeClient, params: SubscriptionListParams) -> ListIter<Self> {
+        list::iter(client, "/subscriptions", &params)
+    }
+
+    /// Returns all subscriptions.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SubscriptionListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Fetch the subscriptions that renew in January 2022
+    /// let mut params = payup::stripe::SubscriptionListParams::new();
+    /// params.current_period_end = Some(payup::stripe::RangeQuery::between(1640995200, 1643673599));
+    /// let subscriptions = payup::stripe::Subscription::list(&client, params)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(client: &StripeClient, params: SubscriptionListParams) -> Result<Vec<Self>, StripeError>{
+        let params = SubscriptionListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::iter(client, params).collect()
+    }
+
+    /// POSTs a new Subscription to the stripe api
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // A base plan plus five seats
+    /// let mut base = payup::stripe::SubscriptionItem::new();
+    /// base.price = Some(format!("price_base").into());
+    /// let mut seats = payup::stripe::SubscriptionItem::new();
+    /// seats.price = Some(format!("price_seats").into());
+    /// seats.quantity = Some(5);
+    ///
+    /// let mut subscription = payup::stripe::Subscription::new();
+    /// subscription.customer = Some(format!("cus_").into());
+    /// subscription.items = vec![base, seats];
+    /// subscription.payment_behavior = Some(format!("default_incomplete"));
+    ///
+    /// subscription = subscription.post(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.post("/subscriptions", &self.to_create_params())
+    }
+
+    /// Resumes a paused subscription, e.g. one whose trial ended without a payment method.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut subscription = payup::stripe::Subscription::get(&client, format!("sub_"))?;
+    /// subscription.proration_behavior = Some(format!("none"));
+    /// let subscription = subscription.resume(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn resume(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let id = self.id.as_deref().ok_or_else(|| StripeError::missing("id"))?;
+        let path = format!("/subscriptions/{}/resume", id);
+        client.post(&path, &self.to_resume_params())
+    }
+
+    /// Lazily iterates over the subscriptions matching a search query, e.g. by metadata
+    /// 
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SearchParams holding the query
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Find the active subscriptions of a plan tier
+    /// let params = payup::stripe::SearchParams::new("status:'active' AND metadata['tier']:'gold'");
+    /// for subscription in payup::stripe::Subscription::search(&client, params) {
+    ///     let subscription = subscription?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search(client: &StripeClient, params: SearchParams) -> ListIter<Self> {
+        list::search(client, "/subscriptions/search", &params)
+    }
+
+    /// POSTs an update to an existing Subscription, only the fields set in `params` are changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the Subscription you want to update.
+    /// * `params` - payup::stripe::SubscriptionUpdateParams with the fields to change
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Add two seats without prorating the current period
+    /// let subscription = payup::stripe::Subscription::get(&client, format!("sub_"))?;
+    /// let mut items = subscription.items.clone();
+    /// for item in items.iter_mut() {
+    ///     item.quantity = item.quantity.map(|quantity| quantity + 2);
+    /// }
+    /// let mut params = payup::stripe::SubscriptionUpdateParams::new();
+    /// params.items = Some(items);
+    /// params.proration_behavior = Some(format!("none"));
+    /// let subscription = payup::stripe::Subscription::update(&client, format!("sub_"), params)?;
+    ///
+    /// // End the trial right away
+    /// let mut params = payup::stripe::SubscriptionUpdateParams::new();
+    /// params.trial_end = Some(payup::stripe::Timestamp::Now);
+    /// let subscription = payup::stripe::Subscription::update(&client, format!("sub_"), params)?;
+    ///
+    /// // Stop collecting payments for a while, then collect again
+    /// let mut pause = payup::stripe::PauseCollection::new();
+    /// pause.behavior = Some(format!("void"));
+    /// let mut params = payup::stripe::SubscriptionUpdateParams::new();
+    /// params.pause_collection = Some(pause);
+    /// let subscription = payup::stripe::Subscription::update(&client, format!("sub_"), params)?;
+    ///
+    /// let mut params = payup::stripe::SubscriptionUpdateParams::new();
+    /// params.pause_collection = Some(payup::stripe::PauseCollection::new());
+    /// let subscription = payup::stripe::Subscription::update(&client, format!("sub_"), params)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(client: &StripeClient, id: String, params: SubscriptionUpdateParams) -> Result<Self, StripeError> {
+        client.post(&format!("/subscriptions/{}", id), &params.to_params())
+    }
+    fn to_create_params(&self) -> SubscriptionCreateParams<'_> {
+        SubscriptionCreateParams {
+            params: self.to_params(),
+            customer: self.customer.as_ref().and_then(Expandable::id),
+            trial_period_days: &self.trial_period_days,
+        }
+    }
+    fn to_params(&self) -> SubscriptionParams<'_> {
+        let mut items: Vec<SubscriptionItemParams<'_>> = self.items.iter().map(SubscriptionItemParams::from).collect();
+        if let Some(price_items) = &self.price_items {
+            items.extend(price_items.iter().map(|price| SubscriptionItemParams {
+                price: Some(price),
+                ..SubscriptionItemParams::default()
+            }));
+        }
+
+        SubscriptionParams {
+            cancel_at: &self.cancel_at,
+            cancel_at_period_end: &self.cancel_at_period_end,
+            collection_method: &self.collection_method,
+            coupon: &self.coupon,
+            days_until_due: &self.days_until_due,
+            default_payment_method: &self.default_payment_method,
+            items: if items.is_empty() { None } else { Some(items) },
+            pause_collection: self.pause_collection.as_ref().map(PauseCollectionParams::from),
+            payment_behavior: &self.payment_behavior,
+            promotion_code: &self.promotion_code,
+            proration_behavior: &self.proration_behavior,
+            trial_end: &self.trial_end,
+            metadata: &self.metadata,
+        }
+    }
+    fn to_resume_params(&self) -> SubscriptionResumeParams<'_> {
+        SubscriptionResumeParams {
+            proration_behavior: &self.proration_behavior,
+        }
+    }
+
+}
+
+impl Object for Subscription {
+    fn object_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+}
+
+/// Filters and pagination for listing subscriptions with `Subscription::list` and `Subscription::iter`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SubscriptionListParams {
+    /// Only subscriptions collected this way, `charge_automatically` or `send_invoice`.
+    pub collection_method: Option<String>,
+    /// Only subscriptions created in this range.
+    pub created: Option<RangeQuery>,
+    /// Only subscriptions whose current period ends in this range.
+    pub current_period_end: Option<RangeQuery>,
+    /// Only subscriptions whose current period started in this range.
+    pub current_period_start: Option<RangeQuery>,
+    /// Only subscriptions of this customer id.
+    pub customer: Option<String>,
+    /// Only subscriptions with an item of this price id.
+    pub price: Option<String>,
+    /// Only subscriptions with this status. Canceled subscriptions are left out unless this is `canceled` or `all`.
+    pub status: Option<String>,
+    /// Number of objects fetched per page, between 1 and 100.
+    pub limit: Option<u32>,
+    /// Starts the list after the object with this id.
+    pub starting_after: Option<String>,
+    /// Starts the list before the object with this id and walks towards newer objects.
+    pub ending_before: Option<String>,
+}
+impl SubscriptionListParams {
+    pub fn new() -> Self {
+        SubscriptionListParams::default()
+    }
+}
+
+/// Pauses collecting the payments of a subscription.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct PauseCollection {
+    /// `keep_as_draft`, `mark_uncollectible` or `void`, what happens to the invoices while paused.
+    pub behavior: Option<String>,
+    /// When collection resumes on its own, as a unix timestamp.
+    pub resumes_at: Option<i64>,
+}
+impl PauseCollection {
+    pub fn new() -> Self {
+        PauseCollection::default()
+    }
+}
+
+/// The fields to change with `Subscription::update`, the ones left `None` are not sent and stay as they are.
+#[derive(Debug, Clone, Default)]
+pub struct SubscriptionUpdateParams {
+    /// When to cancel the subscription.
+    pub cancel_at: Option<Timestamp>,
+    /// Cancel the subscription at the end of the current period instead of renewing it.
+    pub cancel_at_period_end: Option<bool>,
+    /// `charge_automatically` or `send_invoice`.
+    pub collection_method: Option<String>,
+    /// Id of a coupon to apply to the subscription.
+    pub coupon: Option<String>,
+    /// Days the customer has to pay an invoice, for `send_invoice`.
+    pub days_until_due: Option<i64>,
+    /// Id of the payment method to charge.
+    pub default_payment_method: Option<String>,
+    /// The items to add or change, an item with an id changes that item.
+    pub items: Option<Vec<SubscriptionItem>>,
+    /// Pauses collecting payments, an empty PauseCollection resumes collection.
+    pub pause_collection: Option<PauseCollection>,
+    /// `allow_incomplete`, `default_incomplete`, `error_if_incomplete` or `pending_if_incomplete`.
+    pub payment_behavior: Option<String>,
+    /// Id of a promotion code to apply to the subscription.
+    pub promotion_code: Option<String>,
+    /// `create_prorations`, `none` or `always_invoice`, how the changes are prorated.
+    pub proration_behavior: Option<String>,
+    /// End of the trial period, `Timestamp::Now` ends it right away.
+    pub trial_end: Option<Timestamp>,
+    /// Keys to set, an empty value removes a key.
+    pub metadata: HashMap<String, String>,
+}
+impl SubscriptionUpdateParams {
+    pub fn new() -> Self {
+        SubscriptionUpdateParams::default()
+    }
+
+    fn to_params(&self) -> SubscriptionParams<'_> {
+        SubscriptionParams {
+            cancel_at: &self.cancel_at,
+            cancel_at_period_end: &self.cancel_at_period_end,
+            collection_method: &self.collection_method,
+            coupon: &self.coupon,
+            days_until_due: &self.days_until_due,
+            default_payment_method: &self.default_payment_method,
+            items: self.items.as_ref().map(|items| items.iter().map(SubscriptionItemParams::from).collect()),
+            pause_collection: self.pause_collection.as_ref().map(PauseCollectionParams::from),
+            payment_behavior: &self.payment_behavior,
+            promotion_code: &self.promotion_code,
+            proration_behavior: &self.proration_behavior,
+            trial_end: &self.trial_end,
+            metadata: &self.metadata,
+        }
+    }
+}
+
+/// A point in time a param is set to, either a unix timestamp or the moment Stripe handles the request.
+///
+/// Stripe rejects `Now` for the params that do not take it and timestamps that have passed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Timestamp {
+    /// Sent as `now`, e.g. a `trial_end` of `Now` ends the trial right away.
+    Now,
+    /// A unix timestamp.
+    At(i64),
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Timestamp::Now => serializer.serialize_str("now"),
+            Timestamp::At(timestamp) => serializer.serialize_i64(*timestamp),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            At(i64),
+            Name(String),
+        }
+
+        match Raw::deserialize(deserializer)? {
+            Raw::At(timestamp) => Ok(Timestamp::At(timestamp)),
+            Raw::Name(name) if name == "now" => Ok(Timestamp::Now),
+            Raw::Name(name) => Err(serde::de::Error::invalid_value(serde::de::Unexpected::Str(&name), &"a unix timestamp or now")),
+        }
+    }
+}
+
+/// A price a subscription is subscribed to, together with its quantity.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SubscriptionItem {
+    pub id: Option<String>,
+    pub object: Option<String>,
+    pub created: Option<i64>,
+    /// Set once the item was deleted. Removes the item when sent as part of a subscription update.
+    pub deleted: Option<bool>,
+    /// `allow_incomplete`, `default_incomplete`, `error_if_incomplete` or `pending_if_incomplete`. Only sent when creating or updating.
+    pub payment_behavior: Option<String>,
+    pub price: Option<Expandable<Price>>,
+    /// `create_prorations`, `none` or `always_invoice`, how the change is prorated. Only sent when creating or updating.
+    pub proration_behavior: Option<String>,
+    /// Prorate as if the change happened at this unix timestamp. Only sent when creating or updating.
+    pub proration_date: Option<i64>,
+    pub quantity: Option<i64>,
+    pub subscription: Option<String>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    /// Set when Stripe answered with the stored result of an earlier request with the same idempotency key.
+    #[serde(skip_serializing)]
+    pub idempotent_replayed: Option<bool>,
+}
+impl SubscriptionItem {
+
+    /// Returns an empty SubscriptionItem object
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// let mut item = payup::stripe::SubscriptionItem::new();
+    /// item.subscription = Some(format!("sub_"));
+    /// item.price = Some(format!("price_").into());
+    /// item.quantity = Some(2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new() -> Self {
+        SubscriptionItem::default()
+    }
+
+    /// Asynchronously deletes an item from its subscription.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the subscription item you want to delete.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let item = payup::stripe::SubscriptionItem::async_delete(&client, format!("si_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_delete(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/subscription_items/{}", id.clone());
+        client.async_delete(&path).await
+    }
+
+    /// Asynchronously retrieves a subscription item.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the subscription item you want to retrieve.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let item = payup::stripe::SubscriptionItem::async_get(&client, format!("si_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/subscription_items/{}", id.clone());
+        client.async_get(&path).await
+    }
+
+    /// Asynchronously and lazily iterates over the items of a subscription, fetching pages from the stripe api only as they are needed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SubscriptionItemListParams with the subscription, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let params = payup::stripe::SubscriptionItemListParams::new(format!("sub_"));
+    /// let mut items = payup::stripe::SubscriptionItem::async_iter(&client, params);
+    /// while let Some(item) = items.next().await {
+    ///     let item = item?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn async_iter(client: &StripeClient, params: SubscriptionItemListParams) -> ListStream<Self> {
+        list::stream(client, "/subscription_items", &params)
+    }
+
+    /// Asynchronously returns all items of a subscription.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SubscriptionItemListParams with the subscription to list the items of
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let params = payup::stripe::SubscriptionItemListParams::new(format!("sub_"));
+    /// let items = payup::stripe::SubscriptionItem::async_list(&client, params).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_list(client: &StripeClient, params: SubscriptionItemListParams) -> Result<Vec<Self>, StripeError>{
+        let params = SubscriptionItemListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::async_iter(client, params).try_collect().await
+    }
+
+    /// Asynchronously adds a new item to an existing subscription
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut item = payup::stripe::SubscriptionItem::new();
+    /// item.subscription = Some(format!("sub_"));
+    /// item.price = Some(format!("price_").into());
+    ///
+    /// item = item.async_post(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.async_post("/subscription_items", &self.to_create_params()).await
+    }
+
+    /// Asynchronously POSTs an update to an existing SubscriptionItem
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut item = payup::stripe::SubscriptionItem::async_get(&client, format!("si_")).await?;
+    /// item.quantity = Some(10);
+    /// item = item.async_update(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_update(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.async_post(&format!("/subscription_items/{}", self.clone().id.unwrap()), &self.to_params()).await
+    }
+
+    /// Deletes an item from its subscription.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the subscription item you want to delete.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let item = payup::stripe::SubscriptionItem::delete(&client, format!("si_"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/subscription_items/{}", id.clone());
+        client.delete(&path)
+    }
+
+    /// Returns a subscription item
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the subscription item you want to retrieve.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let item = payup::stripe::SubscriptionItem::get(&client, format!("si_"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/subscription_items/{}", id.clone());
+        client.get(&path)
+    }
+
+    /// Lazily iterates over the items of a subscription, fetching pages from the stripe api only as they are needed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SubscriptionItemListParams with the subscription, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let params = payup::stripe::SubscriptionItemListParams::new(format!("sub_"));
+    /// for item in payup::stripe::SubscriptionItem::iter(&client, params) {
+    ///     let item = item?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter(client: &StripeClient, params: SubscriptionItemListParams) -> ListIter<Self> {
+        list::iter(client, "/subscription_items", &params)
+    }
+
+    /// Returns all items of a subscription.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SubscriptionItemListParams with the subscription to list the items of
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let params = payup::stripe::SubscriptionItemListParams::new(format!("sub_"));
+    /// let items = payup::stripe::SubscriptionItem::list(&client, params)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(client: &StripeClient, params: SubscriptionItemListParams) -> Result<Vec<Self>, StripeError>{
+        let params = SubscriptionItemListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::iter(client, params).collect()
+    }
+
+    /// Adds a new item to an existing subscription
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Add a metered price, billed by the usage reported for the item
+    /// let mut item = payup::stripe::SubscriptionItem::new();
+    /// item.subscription = Some(format!("sub_"));
+    /// item.price = Some(format!("price_metered").into());
+    /// item.proration_behavior = Some(format!("none"));
+    ///
+    /// item = item.post(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.post("/subscription_items", &self.to_create_params())
+    }
+
+    /// POSTs an update to an existing SubscriptionItem
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Switch the item to another price, keeping its quantity
+    /// let mut item = payup::stripe::SubscriptionItem::get(&client, format!("si_"))?;
+    /// item.price = Some(format!("price_gold").into());
+    /// item.proration_behavior = Some(format!("always_invoice"));
+    /// item = item.update(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.post(&format!("/subscription_items/{}", self.clone().id.unwrap()), &self.to_params())
+    }
+    fn to_create_params(&self) -> SubscriptionItemCreateParams<'_> {
+        SubscriptionItemCreateParams {
+            params: self.to_params(),
+            subscription: &self.subscription,
+        }
+    }
+    fn to_params(&self) -> SubscriptionItemUpdateParams<'_> {
+        SubscriptionItemUpdateParams {
+            payment_behavior: &self.payment_behavior,
+            price: self.price.as_ref().and_then(Expandable::id),
+            proration_behavior: &self.proration_behavior,
+            proration_date: &self.proration_date,
+            quantity: &self.quantity,
+            metadata: &self.metadata,
+        }
+    }
+
+}
+
+impl Object for SubscriptionItem {
+    fn object_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+}
+
+/// The subscription and pagination for listing subscription items with `SubscriptionItem::list` and `SubscriptionItem::iter`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SubscriptionItemListParams {
+    /// The id of the subscription whose items are listed, required by Stripe.
+    pub subscription: Option<String>,
+    /// Number of objects fetched per page, between 1 and 100.
+    pub limit: Option<u32>,
+    /// Starts the list after the object with this id.
+    pub starting_after: Option<String>,
+    /// Starts the list before the object with this id and walks towards newer objects.
+    pub ending_before: Option<String>,
+}
+impl SubscriptionItemListParams {
+    pub fn new(subscription: impl Into<String>) -> Self {
+        SubscriptionItemListParams {
+            subscription: Some(subscription.into()),
+            ..SubscriptionItemListParams::default()
+        }
+    }
+}
+
+/// Schedules changes to a subscription, e.g. an upgrade at the end of the current period, as a list of phases.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SubscriptionSchedule {
+    pub id: Option<String>,
+    pub object: Option<String>,
+    pub canceled_at: Option<i64>,
+    pub completed_at: Option<i64>,
+    pub created: Option<i64>,
+    pub current_phase: Option<CurrentPhase>,
+    pub customer: Option<Expandable<Customer>>,
+    /// `release` keeps the subscription running once the last phase ended, `cancel` cancels it.
+    pub end_behavior: Option<String>,
+    pub livemode: Option<bool>,
+    #[serde(default)]
+    pub phases: Vec<SubscriptionSchedulePhase>,
+    /// `create_prorations`, `none` or `always_invoice`, how changing the current phase is prorated. Only sent when updating.
+    pub proration_behavior: Option<String>,
+    pub released_at: Option<i64>,
+    pub released_subscription: Option<String>,
+    /// When the first phase starts as a unix timestamp. Only sent when creating.
+    pub start_date: Option<i64>,
+    pub status: Option<String>,
+    pub subscription: Option<Expandable<Subscription>>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    /// Set when Stripe answered with the stored result of an earlier request with the same idempotency key.
+    #[serde(skip_serializing)]
+    pub idempotent_replayed: Option<bool>,
+}
+impl SubscriptionSchedule {
+
+    /// Returns an empty SubscriptionSchedule object
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// let mut schedule = payup::stripe::SubscriptionSchedule::new();
+    /// schedule.customer = Some(format!("cus_").into());
+    /// schedule.end_behavior = Some(format!("release"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new() -> Self {
+        SubscriptionSchedule::default()
+    }
+
+    /// Asynchronously cancels a schedule together with its subscription.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the subscription schedule you want to cancel.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let schedule = payup::stripe::SubscriptionSchedule::async_cancel(&client, format!("sub_sched_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_cancel(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/subscription_schedules/{}/cancel", id.clone());
+        client.async_post(&path, &()).await
+    }
+
+    /// Asynchronously creates a schedule for an existing subscription, with its current period as the first phase.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `subscription` - The id of the subscription to schedule changes for.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let schedule = payup::stripe::SubscriptionSchedule::async_from_subscription(&client, format!("sub_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_from_subscription(client: &StripeClient, subscription: String) -> Result<Self, StripeError> {
+        let params = SubscriptionScheduleFromParams { from_subscription: &subscription };
+        client.async_post("/subscription_schedules", &params).await
+    }
+
+    /// Asynchronously retrieves a subscription schedule.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the subscription schedule you want to retrieve.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let schedule = payup::stripe::SubscriptionSchedule::async_get(&client, format!("sub_sched_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/subscription_schedules/{}", id.clone());
+        client.async_get(&path).await
+    }
+
+    /// Asynchronously and lazily iterates over subscription schedules, fetching pages from the stripe api only as they are needed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SubscriptionScheduleListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut params = payup::stripe::SubscriptionScheduleListParams::new();
+    /// params.customer = Some(format!("cus_"));
+    /// let mut schedules = payup::stripe::SubscriptionSchedule::async_iter(&client, params);
+    /// while let Some(schedule) = schedules.next().await {
+    ///     let schedule = schedule?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn async_iter(client: &StripeClient, params: SubscriptionScheduleListParams) -> ListStream<Self> {
+        list::stream(client, "/subscription_schedules", &params)
+    }
+
+    /// Asynchronously returns all subscription schedules.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SubscriptionScheduleListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let schedules = payup::stripe::SubscriptionSchedule::async_list(&client, payup::stripe::SubscriptionScheduleListParams::new()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_list(client: &StripeClient, params: SubscriptionScheduleListParams) -> Result<Vec<Self>, StripeError>{
+        let params = SubscriptionScheduleListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::async_iter(client, params).try_collect().await
+    }
+
+    /// Asynchronously POSTs a new SubscriptionSchedule to the stripe api
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut item = payup::stripe::SubscriptionItem::new();
+    /// item.price = Some(format!("price_").into());
+    ///
+    /// let mut phase = payup::stripe::SubscriptionSchedulePhase::new();
+    /// phase.items = vec![item];
+    /// phase.iterations = Some(12);
+    ///
+    /// let mut schedule = payup::stripe::SubscriptionSchedule::new();
+    /// schedule.customer = Some(format!("cus_").into());
+    /// schedule.start_date = Some(1640995200);
+    /// schedule.phases = vec![phase];
+    ///
+    /// schedule = schedule.async_post(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.async_post("/subscription_schedules", &self.to_create_params()).await
+    }
+
+    /// Asynchronously releases a schedule, the subscription keeps running as it is without further phase changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the subscription schedule you want to release.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let schedule = payup::stripe::SubscriptionSchedule::async_release(&client, format!("sub_sched_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_release(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/subscription_schedules/{}/release", id.clone());
+        client.async_post(&path, &()).await
+    }
+
+    /// Asynchronously POSTs an update to an existing SubscriptionSchedule
+    ///
+    /// The current and upcoming phases are sent, phases that already ended are left out.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut schedule = payup::stripe::SubscriptionSchedule::async_get(&client, format!("sub_sched_")).await?;
+    /// schedule.end_behavior = Some(format!("cancel"));
+    /// schedule = schedule.async_update(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_update(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.async_post(&format!("/subscription_schedules/{}", self.clone().id.unwrap()), &self.to_params()).await
+    }
+
+    /// Cancels a schedule together with its subscription.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the subscription schedule you want to cancel.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let schedule = payup::stripe::SubscriptionSchedule::cancel(&client, format!("sub_sched_"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cancel(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/subscription_schedules/{}/cancel", id.clone());
+        client.post(&path, &())
+    }
+
+    /// Creates a schedule for an existing subscription, with its current period as the first phase.
+    ///
+    /// Update the returned schedule to add the phases that follow.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `subscription` - The id of the subscription to schedule changes for.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Downgrade to the basic plan once the current period is over
+    /// let mut schedule = payup::stripe::SubscriptionSchedule::from_subscription(&client, format!("sub_"))?;
+    ///
+    /// let mut item = payup::stripe::SubscriptionItem::new();
+    /// item.price = Some(format!("price_basic").into());
+    /// let mut downgrade = payup::stripe::SubscriptionSchedulePhase::new();
+    /// downgrade.items = vec![item];
+    /// downgrade.iterations = Some(1);
+    /// schedule.phases.push(downgrade);
+    ///
+    /// schedule = schedule.update(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_subscription(client: &StripeClient, subscription: String) -> Result<Self, StripeError> {
+        let params = SubscriptionScheduleFromParams { from_subscription: &subscription };
+        client.post("/subscription_schedules", &params)
+    }
+
+    /// Returns a subscription schedule
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the subscription schedule you want to retrieve.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let schedule = payup::stripe::SubscriptionSchedule::get(&client, format!("sub_sched_"))?;
+    /// for phase in &schedule.phases {
+    ///     println!("{:?} - {:?}", phase.start_date, phase.end_date);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/subscription_schedules/{}", id.clone());
+        client.get(&path)
+    }
+
+    /// Lazily iterates over subscription schedules, fetching pages from the stripe api only as they are needed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SubscriptionScheduleListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Walk the schedules that did not start yet
+    /// let mut params = payup::stripe::SubscriptionScheduleListParams::new();
+    /// params.scheduled = Some(true);
+    /// for schedule in payup::stripe::SubscriptionSchedule::iter(&client, params) {
+    ///     let schedule = schedule?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter(client: &StripeClient, params: SubscriptionScheduleListParams) -> ListIter<Self> {
+        list::iter(client, "/subscription_schedules", &params)
+    }
+
+    /// Returns all subscription schedules.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::SubscriptionScheduleListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut params = payup::stripe::SubscriptionScheduleListParams::new();
+    /// params.customer = Some(format!("cus_"));
+    /// let schedules = payup::stripe::SubscriptionSchedule::list(&client, params)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(client: &StripeClient, params: SubscriptionScheduleListParams) -> Result<Vec<Self>, StripeError>{
+        let params = SubscriptionScheduleListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::iter(client, params).collect()
+    }
+
+    /// POSTs a new SubscriptionSchedule to the stripe api
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Three discounted months on the starter plan, then the regular plan
+    /// let mut starter = payup::stripe::SubscriptionItem::new();
+    /// starter.price = Some(format!("price_starter").into());
+    /// let mut intro = payup::stripe::SubscriptionSchedulePhase::new();
+    /// intro.items = vec![starter];
+    /// intro.coupon = Some(format!("INTRO"));
+    /// intro.iterations = Some(3);
+    ///
+    /// let mut regular = payup::stripe::SubscriptionItem::new();
+    /// regular.price = Some(format!("price_regular").into());
+    /// let mut after = payup::stripe::SubscriptionSchedulePhase::new();
+    /// after.items = vec![regular];
+    ///
+    /// let mut schedule = payup::stripe::SubscriptionSchedule::new();
+    /// schedule.customer = Some(format!("cus_").into());
+    /// schedule.start_date = Some(1640995200);
+    /// schedule.end_behavior = Some(format!("release"));
+    /// schedule.phases = vec![intro, after];
+    ///
+    /// schedule = schedule.post(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.post("/subscription_schedules", &self.to_create_params())
+    }
+
+    /// Releases a schedule, the subscription keeps running as it is without further phase changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the subscription schedule you want to release.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let schedule = payup::stripe::SubscriptionSchedule::release(&client, format!("sub_sched_"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn release(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/subscription_schedules/{}/release", id.clone());
+        client.post(&path, &())
+    }
+
+    /// POSTs an update to an existing SubscriptionSchedule
+    ///
+    /// The current and upcoming phases are sent, phases that already ended are left out.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Add five seats to the next phase
+    /// let mut schedule = payup::stripe::SubscriptionSchedule::get(&client, format!("sub_sched_"))?;
+    /// if let Some(next) = schedule.phases.last_mut() {
+    ///     for item in next.items.iter_mut() {
+    ///         item.quantity = Some(item.quantity.unwrap_or(1) + 5);
+    ///     }
+    /// }
+    /// schedule = schedule.update(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.post(&format!("/subscription_schedules/{}", self.clone().id.unwrap()), &self.to_params())
+    }
+    fn to_create_params(&self) -> SubscriptionScheduleCreateParams<'_> {
+        SubscriptionScheduleCreateParams {
+            customer: self.customer.as_ref().and_then(Expandable::id),
+            end_behavior: &self.end_behavior,
+            phases: if self.phases.is_empty() {
+                None
+            } else {
+                Some(self.phases.iter().map(SubscriptionSchedulePhaseParams::from).collect())
+            },
+            start_date: &self.start_date,
+            metadata: &self.metadata,
+        }
+    }
+    fn to_params(&self) -> SubscriptionScheduleParams<'_> {
+        // Stripe only takes the phases that did not end yet.
+        let phases: Vec<SubscriptionSchedulePhaseParams<'_>> = self.phases.iter()
+            .filter(|phase| phase.end_date.is_none() || upcoming(phase.end_date).is_some())
+            .map(SubscriptionSchedulePhaseParams::from)
+            .collect();
+
+        SubscriptionScheduleParams {
+            end_behavior: &self.end_behavior,
+            phases: if phases.is_empty() { None } else { Some(phases) },
+            proration_behavior: &self.proration_behavior,
+            metadata: &self.metadata,
+        }
+    }
+
+}
+
+impl Object for SubscriptionSchedule {
+    fn object_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+}
+
+/// Filters and pagination for listing subscription schedules with `SubscriptionSchedule::list` and `SubscriptionSchedule::iter`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SubscriptionScheduleListParams {
+    /// Only schedules canceled in this range.
+    pub canceled_at: Option<RangeQuery>,
+    /// Only schedules completed in this range.
+    pub completed_at: Option<RangeQuery>,
+    /// Only schedules created in this range.
+    pub created: Option<RangeQuery>,
+    /// Only schedules of this customer id.
+    pub customer: Option<String>,
+    /// Only schedules released in this range.
+    pub released_at: Option<RangeQuery>,
+    /// Only schedules that did not start yet.
+    pub scheduled: Option<bool>,
+    /// Number of objects fetched per page, between 1 and 100.
+    pub limit: Option<u32>,
+    /// Starts the list after the object with this id.
+    pub starting_after: Option<String>,
+    /// Starts the list before the object with this id and walks towards newer objects.
+    pub ending_before: Option<String>,
+}
+impl SubscriptionScheduleListParams {
+    pub fn new() -> Self {
+        SubscriptionScheduleListParams::default()
+    }
+}
+
+/// A period of a subscription schedule with the prices the customer is subscribed to during it.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SubscriptionSchedulePhase {
+    /// `automatic` or `phase_start`, whether a new billing period starts with the phase.
+    pub billing_cycle_anchor: Option<String>,
+    pub collection_method: Option<String>,
+    pub coupon: Option<String>,
+    pub default_payment_method: Option<String>,
+    /// When the phase ends as a unix timestamp. Leave empty and set `iterations` to end it after a number of periods.
+    pub end_date: Option<i64>,
+    /// The prices and quantities the customer is subscribed to during the phase.
+    #[serde(default)]
+    pub items: Vec<SubscriptionItem>,
+    /// Number of billing periods the phase lasts. Only sent when creating or updating.
+    pub iterations: Option<i64>,
+    pub proration_behavior: Option<String>,
+    /// When the phase starts as a unix timestamp, Stripe starts it when the previous phase ends when empty.
+    pub start_date: Option<i64>,
+    /// Treat the whole phase as a trial. Only sent when creating or updating.
+    pub trial: Option<bool>,
+    pub trial_end: Option<i64>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+}
+impl SubscriptionSchedulePhase {
+    pub fn new() -> Self {
+        SubscriptionSchedulePhase::default()
+    }
+}
+
+/// The start and end of the phase a subscription schedule is in, as unix timestamps.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct CurrentPhase {
+    pub start_date: Option<i64>,
+    pub end_date: Option<i64>,
+}
+
+/// Reports the usage of a metered subscription item, which is billed at the end of the period.
+///
+/// Use a `UsageAggregator` to report frequent small increments in batches.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct UsageRecord {
+    pub id: Option<String>,
+    pub object: Option<String>,
+    /// `increment` adds the quantity to the usage at the timestamp, `set` overrides it. Stripe defaults to `increment`. Only sent when creating.
+    pub action: Option<String>,
+    pub livemode: Option<bool>,
+    pub quantity: Option<i64>,
+    pub subscription_item: Option<String>,
+    /// When the usage happened as a unix timestamp, Stripe uses the current time when empty.
+    pub timestamp: Option<i64>,
+    /// Set when Stripe answered with the stored result of an earlier request with the same idempotency key.
+    #[serde(skip_serializing)]
+    pub idempotent_replayed: Option<bool>,
+}
+impl UsageRecord {
+
+    /// Returns an empty UsageRecord object
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// let mut usage = payup::stripe::UsageRecord::new();
+    /// usage.subscription_item = Some(format!("si_"));
+    /// usage.quantity = Some(100);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new() -> Self {
+        UsageRecord::default()
+    }
+
+    /// Asynchronously POSTs a new UsageRecord for its `subscription_item` to the stripe api
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut usage = payup::stripe::UsageRecord::new();
+    /// usage.subscription_item = Some(format!("si_"));
+    /// usage.quantity = Some(100);
+    ///
+    /// usage = usage.async_post(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let subscription_item = self.subscription_item.as_deref().ok_or_else(|| StripeError::missing("subscription_item"))?;
+        let path = format!("/subscription_items/{}/usage_records", subscription_item);
+        client.async_post(&path, &self.to_params()).await
+    }
+
+    /// POSTs a new UsageRecord for its `subscription_item` to the stripe api
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Replace the usage reported for the start of January 2022
+    /// let mut usage = payup::stripe::UsageRecord::new();
+    /// usage.subscription_item = Some(format!("si_"));
+    /// usage.action = Some(format!("set"));
+    /// usage.quantity = Some(1200);
+    /// usage.timestamp = Some(1640995200);
+    ///
+    /// usage = usage.post(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        let subscription_item = self.subscription_item.as_deref().ok_or_else(|| StripeError::missing("subscription_item"))?;
+        let path = format!("/subscription_items/{}/usage_records", subscription_item);
+        client.post(&path, &self.to_params())
+    }
+    fn to_params(&self) -> UsageRecordParams<'_> {
+        UsageRecordParams {
+            action: &self.action,
+            quantity: &self.quantity,
+            timestamp: &self.timestamp,
+        }
+    }
+
+}
+
+/// The usage of a metered subscription item, summed up per billing period.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct UsageRecordSummary {
+    pub id: Option<String>,
+    pub object: Option<String>,
+    /// The invoice the usage was billed on, empty for the current period.
+    pub invoice: Option<String>,
+    pub livemode: Option<bool>,
+    pub period: Option<UsagePeriod>,
+    pub subscription_item: Option<String>,
+    pub total_usage: Option<i64>,
+}
+impl UsageRecordSummary {
+
+    /// Asynchronously and lazily iterates over the usage summaries of a subscription item, newest period first
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `subscription_item` - The id of the metered subscription item.
+    /// * `params` - payup::stripe::UsageRecordSummaryListParams with the page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let params = payup::stripe::UsageRecordSummaryListParams::new();
+    /// let mut summaries = payup::stripe::UsageRecordSummary::async_iter(&client, format!("si_"), params);
+    /// while let Some(summary) = summaries.next().await {
+    ///     let summary = summary?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn async_iter(client: &StripeClient, subscription_item: String, params: UsageRecordSummaryListParams) -> ListStream<Self> {
+        let path = format!("/subscription_items/{}/usage_record_summaries", subscription_item);
+        list::stream(client, &path, &params)
+    }
+
+    /// Asynchronously returns the usage summaries of a subscription item, newest period first.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `subscription_item` - The id of the metered subscription item.
+    /// * `params` - payup::stripe::UsageRecordSummaryListParams
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let params = payup::stripe::UsageRecordSummaryListParams::new();
+    /// let summaries = payup::stripe::UsageRecordSummary::async_list(&client, format!("si_"), params).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_list(client: &StripeClient, subscription_item: String, params: UsageRecordSummaryListParams) -> Result<Vec<Self>, StripeError>{
+        let params = UsageRecordSummaryListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::async_iter(client, subscription_item, params).try_collect().await
+    }
+
+    /// Lazily iterates over the usage summaries of a subscription item, newest period first
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `subscription_item` - The id of the metered subscription item.
+    /// * `params` - payup::stripe::UsageRecordSummaryListParams with the page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // The usage of the current period comes first
+    /// let params = payup::stripe::UsageRecordSummaryListParams::new();
+    /// if let Some(current) = payup::stripe::UsageRecordSummary::iter(&client, format!("si_"), params).next() {
+    ///     println!("used so far: {:?}", current?.total_usage);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter(client: &StripeClient, subscription_item: String, params: UsageRecordSummaryListParams) -> ListIter<Self> {
+        let path = format!("/subscription_items/{}/usage_record_summaries", subscription_item);
+        list::iter(client, &path, &params)
+    }
+
+    /// Returns the usage summaries of a subscription item, newest period first.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `subscription_item` - The id of the metered subscription item.
+    /// * `params` - payup::stripe::UsageRecordSummaryListParams
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let params = payup::stripe::UsageRecordSummaryListParams::new();
+    /// let summaries = payup::stripe::UsageRecordSummary::list(&client, format!("si_"), params)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(client: &StripeClient, subscription_item: String, params: UsageRecordSummaryListParams) -> Result<Vec<Self>, StripeError>{
+        let params = UsageRecordSummaryListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::iter(client, subscription_item, params).collect()
+    }
+
+}
+
+impl Object for UsageRecordSummary {
+    fn object_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+}
+
+/// Pagination for listing usage summaries with `UsageRecordSummary::list` and `UsageRecordSummary::iter`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct UsageRecordSummaryListParams {
+    /// Number of objects fetched per page, between 1 and 100.
+    pub limit: Option<u32>,
+    /// Starts the list after the object with this id.
+    pub starting_after: Option<String>,
+    /// Starts the list before the object with this id and walks towards newer objects.
+    pub ending_before: Option<String>,
+}
+impl UsageRecordSummaryListParams {
+    pub fn new() -> Self {
+        UsageRecordSummaryListParams::default()
+    }
+}
+
+/// The billing period a usage summary covers, as unix timestamps.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct UsagePeriod {
+    pub start: Option<i64>,
+    pub end: Option<i64>,
+}
+
+
+/// An endpoint Stripe sends the events of your account to, see `Webhook` to verify the requests.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct WebhookEndpoint {
+    pub id: Option<String>,
+    pub object: Option<String>,
+    /// The api version events are rendered in, the version of the account when empty. Only sent when creating.
+    pub api_version: Option<String>,
+    /// The id of the Connect application the endpoint belongs to.
+    pub application: Option<String>,
+    /// Whether the endpoint receives the events of connected accounts instead of your own. Only sent when creating.
+    pub connect: Option<bool>,
+    pub created: Option<i64>,
+    /// Set once the webhook endpoint was deleted.
+    pub deleted: Option<bool>,
+    pub description: Option<String>,
+    /// The event types sent to the endpoint, e.g. `invoice.paid`. `*` enables all of them.
+    pub enabled_events: Option<Vec<String>>,
+    pub livemode: Option<bool>,
+    /// The signing secret of the endpoint, only returned when it is created.
+    pub secret: Option<String>,
+    /// `enabled` or `disabled`, set to `disabled` to stop sending events and back to `enabled` to resume.
+    pub status: Option<String>,
+    /// The URL events are sent to.
+    pub url: Option<String>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    /// Set when Stripe answered with the stored result of an earlier request with the same idempotency key.
+    #[serde(skip_serializing)]
+    pub idempotent_replayed: Option<bool>,
+}
+impl WebhookEndpoint {
+
+    /// Returns an empty WebhookEndpoint object
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// let mut endpoint = payup::stripe::WebhookEndpoint::new();
+    /// endpoint.url = Some(format!("https://example.com/stripe/webhook"));
+    /// endpoint.enabled_events = Some(vec![format!("*")]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new() -> Self {
+        WebhookEndpoint::default()
+    }
+
+    /// Asynchronously deletes a webhook endpoint, Stripe stops sending events to it right away.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the webhook endpoint you want to delete.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let endpoint = payup::stripe::WebhookEndpoint::async_delete(&client, format!("we_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_delete(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/webhook_endpoints/{}", id.clone());
+        client.async_delete(&path).await
+    }
+
+    /// Asynchronously retrieves a webhook endpoint, without its secret.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the webhook endpoint you want to retrieve.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let endpoint = payup::stripe::WebhookEndpoint::async_get(&client, format!("we_")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/webhook_endpoints/{}", id.clone());
+        client.async_get(&path).await
+    }
+
+    /// Asynchronously and lazily iterates over webhook endpoints, fetching pages from the stripe api only as they are needed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::WebhookEndpointListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// use futures_util::StreamExt;
+    ///
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let params = payup::stripe::WebhookEndpointListParams::new();
+    /// let mut endpoints = payup::stripe::WebhookEndpoint::async_iter(&client, params);
+    /// while let Some(endpoint) = endpoints.next().await {
+    ///     let endpoint = endpoint?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn async_iter(client: &StripeClient, params: WebhookEndpointListParams) -> ListStream<Self> {
+        list::stream(client, "/webhook_endpoints", &params)
+    }
+
+    /// Asynchronously returns all webhook endpoints.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::WebhookEndpointListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let endpoints = payup::stripe::WebhookEndpoint::async_list(&client, payup::stripe::WebhookEndpointListParams::new()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_list(client: &StripeClient, params: WebhookEndpointListParams) -> Result<Vec<Self>, StripeError>{
+        let params = WebhookEndpointListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
+        };
+        Self::async_iter(client, params).try_collect().await
+    }
+
+    /// Asynchronously POSTs a new WebhookEndpoint to the stripe api, the returned endpoint carries the signing secret.
+    ///
+    /// The secret is only returned here, it cannot be retrieved later.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut endpoint = payup::stripe::WebhookEndpoint::new();
+    /// endpoint.url = Some(format!("https://example.com/stripe/webhook"));
+    /// endpoint.enabled_events = Some(vec![format!("invoice.paid"), format!("customer.subscription.deleted")]);
+    /// endpoint.api_version = Some(format!("2020-08-27"));
+    /// endpoint = endpoint.async_post(&client).await?;
+    ///
+    /// // Only returned here, store it to verify the requests sent to the endpoint
+    /// let webhook = payup::stripe::Webhook::new(endpoint.secret.unwrap());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.async_post("/webhook_endpoints", &self.to_create_params()).await
+    }
+
+    /// Asynchronously POSTs an update to an existing WebhookEndpoint
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut endpoint = payup::stripe::WebhookEndpoint::async_get(&client, format!("we_")).await?;
+    /// endpoint.status = Some(format!("disabled"));
+    /// endpoint = endpoint.async_update(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn async_update(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.async_post(&format!("/webhook_endpoints/{}", self.clone().id.unwrap()), &self.to_params()).await
+    }
+
+    /// Deletes a webhook endpoint, Stripe stops sending events to it right away.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the webhook endpoint you want to delete.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let endpoint = payup::stripe::WebhookEndpoint::delete(&client, format!("we_"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/webhook_endpoints/{}", id.clone());
+        client.delete(&path)
+    }
+
+    /// Retrieves a webhook endpoint, without its secret.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `id` - The id of the webhook endpoint you want to retrieve.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let endpoint = payup::stripe::WebhookEndpoint::get(&client, format!("we_"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get(client: &StripeClient, id: String) -> Result<Self, StripeError> {
+        let path = format!("/webhook_endpoints/{}", id.clone());
+        client.get(&path)
+    }
+
+    /// Lazily iterates over webhook endpoints, fetching pages from the stripe api only as they are needed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::WebhookEndpointListParams with the filters, page size and the object to start from
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// for endpoint in payup::stripe::WebhookEndpoint::iter(&client, payup::stripe::WebhookEndpointListParams::new()) {
+    ///     let endpoint = endpoint?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter(client: &StripeClient, params: WebhookEndpointListParams) -> ListIter<Self> {
+        list::iter(client, "/webhook_endpoints", &params)
+    }
+
+    /// Returns all webhook endpoints.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    /// * `params` - payup::stripe::WebhookEndpointListParams with the filters to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let endpoints = payup::stripe::WebhookEndpoint::list(&client, payup::stripe::WebhookEndpointListParams::new())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(client: &StripeClient, params: WebhookEndpointListParams) -> Result<Vec<Self>, StripeError>{
+        let params = WebhookEndpointListParams {
+            limit: params.limit.or(Some(100)),
+            ..params
         };
+        Self::iter(client, params).collect()
+    }
+
+    /// POSTs a new WebhookEndpoint to the stripe api, the returned endpoint carries the signing secret.
+    ///
+    /// The secret is only returned here, it cannot be retrieved later.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut endpoint = payup::stripe::WebhookEndpoint::new();
+    /// endpoint.url = Some(format!("https://example.com/stripe/webhook"));
+    /// endpoint.enabled_events = Some(vec![format!("invoice.paid"), format!("customer.subscription.deleted")]);
+    /// endpoint.api_version = Some(format!("2020-08-27"));
+    /// endpoint = endpoint.post(&client)?;
+    ///
+    /// // Only returned here, store it to verify the requests sent to the endpoint
+    /// let webhook = payup::stripe::Webhook::new(endpoint.secret.unwrap());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn post(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.post("/webhook_endpoints", &self.to_create_params())
     }
-    pub fn attach(&self, customer: Customer, creds: Auth) ->  Result<bool, reqwest::Error>{
-
-        match &self.id{
-            Some(id) => {
-
-                match &customer.id{
-                    Some(cust_id) => {
-  
-                        let url = format!("https://api.stripe.com/v1/payment_methods/{}/attach", id.clone());
-
-                
-                        let params = [
-                            ("customer", cust_id.as_str())
-                        ];
-                        let request = reqwest::blocking::Client::new().post(url)
-                        .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-                        .form(&params)
-                        .send()?;
-                        return Ok(true);
-                    },
-                    None => return Ok(false)
-                }
-            
-            },
-            None => return Ok(false)
-        }
 
-        return Ok(false);
-    }
-    pub fn get(creds: Auth, id: String) -> Result<crate::stripe::response::PaymentMethod, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/payment_methods/{}", id.clone());
-        
-        let request = reqwest::blocking::Client::new().get(url)
-        .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-        .send();
-        match request{
-            Ok(req) => {
-                let json = req.json::<crate::stripe::response::PaymentMethod>().unwrap();
-                return Ok(json);
-            },
-            Err(err) => Err(err)
-        }
-    }
-    pub fn post(&self, creds: Auth) ->  Result<PaymentMethod, reqwest::Error> {
-        let request = reqwest::blocking::Client::new().post("https://api.stripe.com/v1/payment_methods")
-        .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-        .form(&self.to_params())
-        .send();
-
-        match request{
-            Ok(req) => {
-                let mut plan = self.clone();
-                let json = req.json::<crate::stripe::response::PaymentMethod>()?;
-                plan.id = Some(json.id);
-                Ok(plan)
-            },
-            Err(err) => Err(err)
+    /// POSTs an update to an existing WebhookEndpoint
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - payup::stripe::StripeClient::new(auth)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut endpoint = payup::stripe::WebhookEndpoint::get(&client, format!("we_"))?;
+    /// endpoint.status = Some(format!("disabled"));
+    /// endpoint = endpoint.update(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self, client: &StripeClient) -> Result<Self, StripeError> {
+        client.post(&format!("/webhook_endpoints/{}", self.clone().id.unwrap()), &self.to_params())
+    }
+    fn to_create_params(&self) -> WebhookEndpointCreateParams<'_> {
+        WebhookEndpointCreateParams {
+            params: self.to_params(),
+            api_version: &self.api_version,
+            connect: &self.connect,
         }
     }
-    fn to_params(&self) -> Vec<(&str, &str)> {
-        // return Customer{client, secret};
-        let mut params = vec![];
-        match &self.method_type{
-            Some(method_type) => params.push(("type", method_type.as_str())),
-            None => {}
-        }
-        match &self.card{
-            Some(card) => {
-                match &card.number{
-                    Some(number) => params.push(("card[number]", number.as_str())),
-                    None => {}
-                }
-                match &card.exp_month{
-                    Some(exp_month) => params.push(("card[exp_month]", exp_month.as_str())),
-                    None => {}
-                }
-                match &card.exp_year{
-                    Some(exp_year) => params.push(("card[exp_year]", exp_year.as_str())),
-                    None => {}
-                }
-                match &card.cvc{
-                    Some(cvc) => params.push(("card[cvc]", cvc.as_str())),
-                    None => {}
-                }
-            },
-            None => {}
+    fn to_params(&self) -> WebhookEndpointParams<'_> {
+        WebhookEndpointParams {
+            description: &self.description,
+            // Stripe takes the status as a flag, and only when updating.
+            disabled: self.status.as_deref().filter(|_| self.id.is_some()).map(|status| status == "disabled"),
+            enabled_events: &self.enabled_events,
+            url: &self.url,
+            metadata: &self.metadata,
         }
-        return params;
     }
-}
 
+}
 
-// TODO - Finish Implementation
-/// Plans define the base price, currency, and billing cycle for recurring purchases of products. 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct Plan {
-    pub id: Option<String>,
-    pub active: Option<String>,
-    pub amount: Option<String>,
-    pub amount_decimal: Option<String>,
-    pub billing_scheme: Option<String>,
-    pub created: Option<i64>,
-    pub currency: Option<String>,
-    pub interval: Option<String>,
-    pub interval_count: Option<String>,
-    pub product: Option<String>,
+impl Object for WebhookEndpoint {
+    fn object_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
 }
-impl Plan {
 
+/// Pagination for listing webhook endpoints with `WebhookEndpoint::list` and `WebhookEndpoint::iter`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct WebhookEndpointListParams {
+    /// Number of objects fetched per page, between 1 and 100.
+    pub limit: Option<u32>,
+    /// Starts the list after the object with this id.
+    pub starting_after: Option<String>,
+    /// Starts the list before the object with this id and walks towards newer objects.
+    pub ending_before: Option<String>,
+}
+impl WebhookEndpointListParams {
     pub fn new() -> Self {
-        return Plan {
-            id: None, 
-            active: None, 
-            amount: None, 
-            amount_decimal: None, 
-            billing_scheme: None, 
-            created: None, 
-            currency: None, 
-            interval: None, 
-            interval_count: None,
-            product: None
-        };
+        WebhookEndpointListParams::default()
     }
+}
 
-    pub async fn async_delete(creds: Auth, id: String) -> Result<crate::stripe::response::Plan, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/plans/{}", id.clone());
-        
-        let request = reqwest::Client::new().delete(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send().await?;
-
-        let json = request.json::<crate::stripe::response::Plan>().await?;
-        return Ok(json);
-    }
 
-    pub async fn async_get(auth: Auth, id: String) -> Result<crate::stripe::response::Plan, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/plans/{}", id.clone());
-        let request = reqwest::Client::new().get(url).basic_auth(auth.client.as_str(), Some(auth.secret.as_str())).send().await?;
-        let json = request.json::<crate::stripe::response::Plan>().await?;
-        return Ok(json);
-    }
 
-    pub async fn async_list(creds: Auth) -> Result<Vec<crate::stripe::response::Plan>, reqwest::Error>{
-        let mut objects: Vec<crate::stripe::response::Plan> = Vec::new();
+// =====================================================================================
+// All structs below this point are just used to support the implimented structs above
+// =====================================================================================
 
-        let mut has_more = true;
-        let mut starting_after: Option<String> = None;
-        while has_more{
-            let json = Self::list_chunk_async(creds.clone(), starting_after).await?;
-            for json_object in json.data{
-                objects.push(json_object);
-            }
-            has_more = json.has_more;
-            starting_after = Some(objects[objects.len() - 1].id.clone());
-        }
+// Request bodies, form encoded by the client. Fields that are None are not sent.
 
-        return Ok(objects);
-    }
+#[derive(Serialize)]
+struct ChargeCaptureParams<'a> {
+    amount: &'a Option<i64>,
+    receipt_email: &'a Option<String>,
+    statement_descriptor: &'a Option<String>,
+    statement_descriptor_suffix: &'a Option<String>,
+}
 
-    pub async fn async_post(&self, creds: Auth) ->  Result<crate::stripe::response::Plan, reqwest::Error> {
-        let request = reqwest::Client::new().post("https://api.stripe.com/v1/plans")
-        .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-        .form(&self.to_params())
-        .send().await?;
+#[derive(Serialize)]
+struct ChargeParams<'a> {
+    amount: &'a Option<i64>,
+    currency: &'a Option<String>,
+    customer: Option<&'a str>,
+    description: &'a Option<String>,
+    receipt_email: &'a Option<String>,
+    source: &'a Option<String>,
+    statement_descriptor: &'a Option<String>,
+    statement_descriptor_suffix: &'a Option<String>,
+    metadata: &'a HashMap<String, String>,
+}
 
-        let json = request.json::<crate::stripe::response::Plan>().await?;
-        return Ok(json);
-    }
+// Creating takes everything an update does, plus the fields that only make sense up front.
+#[derive(Serialize)]
+struct CouponCreateParams<'a> {
+    #[serde(flatten)]
+    params: CouponParams<'a>,
+    id: &'a Option<String>,
+    amount_off: &'a Option<i64>,
+    applies_to: &'a Option<AppliesTo>,
+    currency: &'a Option<String>,
+    duration: &'a Option<String>,
+    duration_in_months: &'a Option<i64>,
+    max_redemptions: &'a Option<i64>,
+    percent_off: &'a Option<f64>,
+    redeem_by: &'a Option<i64>,
+}
 
-    pub fn delete(creds: Auth, id: String) -> Result<crate::stripe::response::Plan, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/plans/{}", id.clone());
-        
-        let request = reqwest::blocking::Client::new().delete(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send()?;
+#[derive(Serialize)]
+struct CouponParams<'a> {
+    name: &'a Option<String>,
+    metadata: &'a HashMap<String, String>,
+}
 
-        let json = request.json::<crate::stripe::response::Plan>()?;
-        return Ok(json);
-    }
+#[derive(Serialize)]
+struct CustomerParams<'a> {
+    coupon: &'a Option<String>,
+    description: &'a Option<String>,
+    email: &'a Option<String>,
+    name: &'a Option<String>,
+    payment_method: &'a Option<String>,
+    phone: &'a Option<String>,
+    promotion_code: &'a Option<String>,
+    metadata: &'a HashMap<String, String>,
+}
 
+#[derive(Serialize)]
+struct DisputeParams<'a> {
+    evidence: &'a Option<Evidence>,
+    submit: &'a Option<bool>,
+    metadata: &'a HashMap<String, String>,
+}
 
-    pub fn get(auth: Auth, id: String) -> Result<crate::stripe::response::Plan, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/plans/{}", id.clone());
-        let request = reqwest::blocking::Client::new().get(url).basic_auth(auth.client.as_str(), Some(auth.secret.as_str())).send()?;
-        let json = request.json::<crate::stripe::response::Plan>()?;
-        return Ok(json);
-    }
+#[derive(Serialize)]
+struct FileLinkParams<'a> {
+    expires_at: &'a Option<String>,
+    file: &'a Option<String>,
+    metadata: &'a HashMap<String, String>,
+}
 
-    pub fn list(creds: Auth) -> Result<Vec<crate::stripe::response::Plan>, reqwest::Error>{
-        let mut objects: Vec<crate::stripe::response::Plan> = Vec::new();
+#[derive(Serialize)]
+struct InvoiceFinalizeParams<'a> {
+    auto_advance: &'a Option<bool>,
+}
 
-        let mut has_more = true;
-        let mut starting_after: Option<String> = None;
-        while has_more{
-            let json = Self::list_chunk(creds.clone(), starting_after)?;
-            for json_object in json.data{
-                objects.push(json_object);
-            }
-            has_more = json.has_more;
-            starting_after = Some(objects[objects.len() - 1].id.clone());
-        }
+#[derive(Serialize)]
+struct InvoiceItemCreateParams<'a> {
+    #[serde(flatten)]
+    params: InvoiceItemParams<'a>,
+    currency: &'a Option<String>,
+    customer: Option<&'a str>,
+    invoice: Option<&'a str>,
+    subscription: &'a Option<String>,
+}
 
-        return Ok(objects);
-    }
+#[derive(Serialize)]
+struct InvoiceItemParams<'a> {
+    amount: &'a Option<i64>,
+    description: &'a Option<String>,
+    discountable: &'a Option<bool>,
+    period: &'a Option<Period>,
+    price: Option<&'a str>,
+    quantity: &'a Option<i64>,
+    tax_rates: Option<Vec<&'a str>>,
+    unit_amount: &'a Option<i64>,
+    unit_amount_decimal: &'a Option<String>,
+    metadata: &'a HashMap<String, String>,
+}
 
-    pub fn post(&self, creds: Auth) ->  Result<crate::stripe::response::Plan, reqwest::Error> {
-        let request = reqwest::blocking::Client::new().post("https://api.stripe.com/v1/plans")
-            .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-            .form(&self.to_params())
-            .send()?;
+#[derive(Serialize)]
+struct InvoiceCreateParams<'a> {
+    #[serde(flatten)]
+    params: InvoiceParams<'a>,
+    customer: Option<&'a str>,
+    subscription: Option<&'a str>,
+}
 
-        let json = request.json::<crate::stripe::response::Plan>()?;
-        return Ok(json);
-    }
+#[derive(Serialize)]
+struct InvoiceParams<'a> {
+    auto_advance: &'a Option<bool>,
+    collection_method: &'a Option<String>,
+    description: &'a Option<String>,
+    discounts: Option<Vec<InvoiceDiscountParams<'a>>>,
+    metadata: &'a HashMap<String, String>,
+}
 
-    fn list_chunk(creds: Auth, starting_after: Option<String>) -> Result<crate::stripe::response::Plans, reqwest::Error> {
-        let mut url = "https://api.stripe.com/v1/plans".to_string();
+// Stripe takes a list of discounts on invoices, each entry either a coupon or a promotion code.
+#[derive(Serialize)]
+struct InvoiceDiscountParams<'a> {
+    coupon: Option<&'a str>,
+    promotion_code: Option<&'a str>,
+}
 
-        if starting_after.is_some() {
-            url = format!("https://api.stripe.com/v1/plans?starting_after={}", starting_after.unwrap());
-        }
+#[derive(Serialize)]
+struct PaymentIntentCancelParams<'a> {
+    cancellation_reason: &'a Option<String>,
+}
 
-        let request = reqwest::blocking::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send()?;
+#[derive(Serialize)]
+struct PaymentIntentCaptureParams<'a> {
+    amount_to_capture: &'a Option<i64>,
+    statement_descriptor: &'a Option<String>,
+    statement_descriptor_suffix: &'a Option<String>,
+}
 
-        let json = request.json::<crate::stripe::response::Plans>()?;
-        return Ok(json);
-    }
+#[derive(Serialize)]
+struct PaymentIntentConfirmParams<'a> {
+    off_session: &'a Option<bool>,
+    payment_method: &'a Option<String>,
+    receipt_email: &'a Option<String>,
+    return_url: &'a Option<String>,
+    setup_future_usage: &'a Option<String>,
+}
 
-    async fn list_chunk_async(creds: Auth, starting_after: Option<String>) -> Result<crate::stripe::response::Plans, reqwest::Error> {
-        let mut url = "https://api.stripe.com/v1/plans".to_string();
+#[derive(Serialize)]
+struct PaymentIntentCreateParams<'a> {
+    #[serde(flatten)]
+    params: PaymentIntentParams<'a>,
+    confirm: &'a Option<bool>,
+    off_session: &'a Option<bool>,
+    return_url: &'a Option<String>,
+}
 
-        if starting_after.is_some() {
-            url = format!("https://api.stripe.com/v1/plans?starting_after={}", starting_after.unwrap());
-        }
+#[derive(Serialize)]
+struct PaymentIntentParams<'a> {
+    amount: &'a Option<i64>,
+    capture_method: &'a Option<String>,
+    currency: &'a Option<String>,
+    customer: Option<&'a str>,
+    description: &'a Option<String>,
+    payment_method: &'a Option<String>,
+    payment_method_types: &'a Option<Vec<String>>,
+    receipt_email: &'a Option<String>,
+    setup_future_usage: &'a Option<String>,
+    statement_descriptor: &'a Option<String>,
+    statement_descriptor_suffix: &'a Option<String>,
+    metadata: &'a HashMap<String, String>,
+}
 
-        let request = reqwest::Client::new().get(url).basic_auth(creds.client.as_str(), Some(creds.secret.as_str())).send().await?;
+#[derive(Serialize)]
+struct PaymentMethodParams<'a> {
+    #[serde(rename = "type")]
+    method_type: &'a Option<String>,
+    card: Option<CardParams<'a>>,
+    metadata: &'a HashMap<String, String>,
+}
 
-        let json = request.json::<crate::stripe::response::Plans>().await?;
-        return Ok(json);
-    }
+#[derive(Serialize)]
+struct CardParams<'a> {
+    number: &'a Option<String>,
+    exp_month: &'a Option<i64>,
+    exp_year: &'a Option<i64>,
+    cvc: &'a Option<String>,
+}
 
-    fn to_params(&self) -> Vec<(&str, &str)> {
-        // return Customer{client, secret};
-        let mut params = vec![];
-        match &self.amount{
-            Some(amount) => params.push(("amount", amount.as_str())),
-            None => {}
-        }
-        match &self.currency{
-            Some(currency) => params.push(("currency", currency.as_str())),
-            None => {}
-        }
-        match &self.interval{
-            Some(interval) => params.push(("interval", interval.as_str())),
-            None => {}
+impl<'a> From<&'a Card> for CardParams<'a> {
+    fn from(card: &'a Card) -> Self {
+        CardParams {
+            number: &card.number,
+            exp_month: &card.exp_month,
+            exp_year: &card.exp_year,
+            cvc: &card.cvc,
         }
-        match &self.product{
-            Some(product) => params.push(("product", product.as_str())),
-            None => {}
-        }
-        match &self.active{
-            Some(active) => params.push(("active", active.as_str())),
-            None => {}
-        }
-        return params;
     }
 }
 
+#[derive(Serialize)]
+struct PlanParams<'a> {
+    active: &'a Option<bool>,
+    amount: &'a Option<i64>,
+    currency: &'a Option<String>,
+    interval: &'a Option<String>,
+    product: &'a Option<String>,
+    metadata: &'a HashMap<String, String>,
+}
 
-// TODO - Finish Implementation
-/// Prices define the unit cost, currency, and (optional) billing cycle. 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct Price {
-    pub id: Option<String>,
-    pub active: Option<bool>,
-    pub billing_scheme: Option<String>,
-    pub created: Option<i64>,
-    pub currency: Option<String>,
-    pub livemode: Option<bool>,
-    pub product: Option<String>,
-    pub tax_behavior: Option<String>,
-    pub type_field: Option<String>,
-    pub unit_amount: Option<String>,
-    pub unit_amount_decimal: Option<String>,
+#[derive(Serialize)]
+struct PriceParams<'a> {
+    active: &'a Option<bool>,
+    billing_scheme: &'a Option<String>,
+    currency: &'a Option<String>,
+    lookup_key: &'a Option<String>,
+    nickname: &'a Option<String>,
+    product: Option<&'a str>,
+    product_data: &'a Option<ProductData>,
+    recurring: &'a Option<Recurring>,
+    tax_behavior: &'a Option<String>,
+    tiers: Option<Vec<PriceTierParams<'a>>>,
+    tiers_mode: &'a Option<String>,
+    transform_quantity: &'a Option<TransformQuantity>,
+    unit_amount: &'a Option<i64>,
+    unit_amount_decimal: &'a Option<String>,
+    metadata: &'a HashMap<String, String>,
 }
-impl Price {
-    pub fn new() -> Self {
-        return Price{
-            id: None,
-            active: None,
-            billing_scheme: None,
-            created: None,
-            currency: None,
-            livemode: None,
-            product: None,
-            tax_behavior: None,
-            type_field: None,
-            unit_amount: None,
-            unit_amount_decimal: None
-        };
-    }
-    pub fn post(&self, creds: Auth) ->  Result<Price, reqwest::Error> {
-        let request = reqwest::blocking::Client::new().post("https://api.stripe.com/v1/prices")
-        .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-        .form(&self.to_params())
-        .send();
-
-        match request{
-            Ok(req) => {
-                let mut plan = self.clone();
-                let json = req.json::<crate::stripe::response::Price>()?;
-                plan.id = Some(json.id);
-                Ok(plan)
-            },
-            Err(err) => Err(err)
+
+// Stripe leaves `up_to` empty for the last tier but expects `inf` when creating it.
+#[derive(Serialize)]
+struct PriceTierParams<'a> {
+    flat_amount: &'a Option<i64>,
+    flat_amount_decimal: &'a Option<String>,
+    unit_amount: &'a Option<i64>,
+    unit_amount_decimal: &'a Option<String>,
+    up_to: String,
+}
+
+impl<'a> From<&'a PriceTier> for PriceTierParams<'a> {
+    fn from(tier: &'a PriceTier) -> Self {
+        PriceTierParams {
+            flat_amount: &tier.flat_amount,
+            flat_amount_decimal: &tier.flat_amount_decimal,
+            unit_amount: &tier.unit_amount,
+            unit_amount_decimal: &tier.unit_amount_decimal,
+            up_to: tier.up_to.map_or_else(|| "inf".to_string(), |up_to| up_to.to_string()),
         }
     }
-    fn to_params(&self) -> Vec<(&str, &str)> {
-        // return Customer{client, secret};
-        let mut params = vec![];
-        match &self.currency{
-            Some(currency) => params.push(("currency", currency.as_str())),
-            None => {}
-        }
-        match &self.unit_amount{
-            Some(unit_amount) => params.push(("unit_amount", unit_amount.as_str())),
-            None => {}
-        }
+}
 
-        // TODO Impliment product
+#[derive(Serialize)]
+struct PriceUpdateParams<'a> {
+    active: &'a Option<bool>,
+    lookup_key: &'a Option<String>,
+    nickname: &'a Option<String>,
+    tax_behavior: &'a Option<String>,
+    metadata: &'a HashMap<String, String>,
+}
 
-        return params;
-    }
+#[derive(Serialize)]
+struct ProductParams<'a> {
+    active: &'a Option<bool>,
+    default_price: Option<&'a str>,
+    description: &'a Option<String>,
+    images: &'a Option<Vec<String>>,
+    name: &'a Option<String>,
+    shippable: &'a Option<bool>,
+    statement_descriptor: &'a Option<String>,
+    tax_code: &'a Option<String>,
+    unit_label: &'a Option<String>,
+    url: &'a Option<String>,
+    metadata: &'a HashMap<String, String>,
+}
 
- 
+#[derive(Serialize)]
+struct PromotionCodeCreateParams<'a> {
+    #[serde(flatten)]
+    params: PromotionCodeParams<'a>,
+    code: &'a Option<String>,
+    coupon: Option<&'a str>,
+    customer: Option<&'a str>,
+    expires_at: &'a Option<i64>,
+    max_redemptions: &'a Option<i64>,
+    restrictions: &'a Option<PromotionCodeRestrictions>,
 }
 
-// TODO - Finish Implementation
-/// Subscriptions allow you to charge a customer on a recurring basis.
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct Subscription {
-    pub id: Option<String>,
-    pub billing_cycle_anchor: Option<i64>,
-    pub cancel_at: Option<i64>,
-    pub cancel_at_period_end: Option<bool>,
-    pub canceled_at: Option<i64>,
-    pub collection_method: Option<String>,
-    pub created: Option<i64>,
-    pub current_period_end: Option<i64>,
-    pub current_period_start: Option<i64>,
-    pub customer: Option<String>,
-    pub days_until_due: Option<i64>,
-    pub default_payment_method: Option<String>,
-    pub ended_at: Option<i64>,
-    pub latest_invoice: Option<String>,
-    pub livemode: Option<bool>,
-    pub quantity: Option<i64>,
-    pub start_date: Option<i64>,
-    pub status: Option<String>,
-    pub price_items: Option<Vec<String>>
+#[derive(Serialize)]
+struct PromotionCodeParams<'a> {
+    active: &'a Option<bool>,
+    metadata: &'a HashMap<String, String>,
 }
-impl Subscription {
-    pub fn new() -> Self {
-      
-        return Subscription{
-            id: None, 
-            billing_cycle_anchor: None, 
-            cancel_at: None, 
-            cancel_at_period_end: None, 
-            canceled_at: None, 
-            collection_method: None,
-            created: None,
-            current_period_end: None, 
-            current_period_start: None,
-            customer: None,
-            price_items: None,
-            days_until_due: None,
-            default_payment_method: None,
-            ended_at: None,
-            latest_invoice: None,
-            livemode: None,
-            quantity: None,
-            start_date: None,
-            status: None
-        };
-    }
-    pub fn cancel(creds: Auth, id: String) -> Result<crate::stripe::response::Subscription, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/subscriptions/{}", id.clone());
-        
-        let request = reqwest::blocking::Client::new().delete(url)
-        .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-        .send();
-        match request{
-            Ok(req) => {
-                let json = req.json::<crate::stripe::response::Subscription>().unwrap();
-                return Ok(json);
-            },
-            Err(err) => Err(err)
-        }
-    }
 
-    /// Returns a subscription
-    ///
-    /// # Arguments
-    ///
-    /// * `creds` - A struct containing the client + secret for authentication over the stripe API.
-    /// * `id` - A string representing an existing stripe subscription_id
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// // Client and Secret for Stripe account
-    /// // In a production environment...load values from environment variables.
-    /// let client = format!("sk_test_51Jo2sKGrEH09RU9uu8d8ARKasYUKHXAHk4vUNup1JLgP5wFnQQf6t7UpKfh7woVMhI9oeuziolW2dK1uwmgAheVI00bN8ews6g");
-    /// let secret = format!("");
-    /// // Create the Authentication refererence
-    /// let auth = payup::stripe::Auth::new(client, secret);
-    /// 
-    /// let get_subscription = payup::stripe::Subscription::get(auth, "subscription_id");
-    /// ```
-    pub fn get(creds: Auth, id: String) -> Result<crate::stripe::response::Subscription, reqwest::Error> {
-        let mut url = format!("https://api.stripe.com/v1/subscriptions/{}", id.clone());
-        
-        let request = reqwest::blocking::Client::new().get(url)
-        .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-        .send();
-        match request{
-            Ok(req) => {
-                let json = req.json::<crate::stripe::response::Subscription>().unwrap();
-                return Ok(json);
-            },
-            Err(err) => Err(err)
-        }
-    }
-    pub fn update(&self, creds: Auth) ->  Result<crate::stripe::response::Subscription, reqwest::Error> {
-        let request = reqwest::blocking::Client::new().post(format!("https://api.stripe.com/v1/subscriptions/{}", self.clone().id.unwrap()))
-        .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-        .form(&self.to_params())
-        .send();
+#[derive(Serialize)]
+struct RefundParams<'a> {
+    amount: &'a Option<i64>,
+    charge: Option<&'a str>,
+    payment_intent: Option<&'a str>,
+    reason: &'a Option<String>,
+    refund_application_fee: &'a Option<bool>,
+    reverse_transfer: &'a Option<bool>,
+    metadata: &'a HashMap<String, String>,
+}
 
-        match request{
-            Ok(req) => {
-                let json = req.json::<crate::stripe::response::Subscription>()?;
-                Ok(json)
-            },
-            Err(err) => Err(err)
+#[derive(Serialize)]
+struct RefundUpdateParams<'a> {
+    metadata: &'a HashMap<String, String>,
+}
+
+#[derive(Serialize)]
+struct SetupIntentCancelParams<'a> {
+    cancellation_reason: &'a Option<String>,
+}
+
+#[derive(Serialize)]
+struct SetupIntentConfirmParams<'a> {
+    mandate_data: &'a Option<MandateData>,
+    payment_method: &'a Option<String>,
+    return_url: &'a Option<String>,
+}
+
+#[derive(Serialize)]
+struct SetupIntentCreateParams<'a> {
+    #[serde(flatten)]
+    params: SetupIntentParams<'a>,
+    confirm: &'a Option<bool>,
+    mandate_data: &'a Option<MandateData>,
+    return_url: &'a Option<String>,
+    usage: &'a Option<String>,
+}
+
+#[derive(Serialize)]
+struct SetupIntentParams<'a> {
+    customer: Option<&'a str>,
+    description: &'a Option<String>,
+    payment_method: &'a Option<String>,
+    payment_method_types: &'a Option<Vec<String>>,
+    metadata: &'a HashMap<String, String>,
+}
+
+#[derive(Serialize)]
+struct SubscriptionCreateParams<'a> {
+    #[serde(flatten)]
+    params: SubscriptionParams<'a>,
+    customer: Option<&'a str>,
+    trial_period_days: &'a Option<i64>,
+}
+
+#[derive(Serialize)]
+struct SubscriptionItemCreateParams<'a> {
+    #[serde(flatten)]
+    params: SubscriptionItemUpdateParams<'a>,
+    subscription: &'a Option<String>,
+}
+
+#[derive(Serialize, Default)]
+struct SubscriptionItemParams<'a> {
+    id: Option<&'a str>,
+    deleted: Option<bool>,
+    price: Option<&'a str>,
+    quantity: Option<i64>,
+    metadata: Option<&'a HashMap<String, String>>,
+}
+impl<'a> From<&'a SubscriptionItem> for SubscriptionItemParams<'a> {
+    fn from(item: &'a SubscriptionItem) -> Self {
+        SubscriptionItemParams {
+            id: item.id.as_deref(),
+            deleted: item.deleted,
+            price: item.price.as_ref().and_then(Expandable::id),
+            quantity: item.quantity,
+            metadata: Some(&item.metadata),
         }
     }
-    pub fn post(&self, creds: Auth) -> Result<Subscription, reqwest::Error>{
-        let request = reqwest::blocking::Client::new().post("https://api.stripe.com/v1/subscriptions")
-            .basic_auth(creds.client.as_str(), Some(creds.secret.as_str()))
-            .form(&self.to_params())
-            .send()?;
-    
-        let json = request.json::<Subscription>()?;
-        Ok(json)
-    }
+}
 
-    fn to_params(&self) -> Vec<(&str, &str)> {
-        // return Customer{client, secret};
-        let mut params = vec![];
-        match &self.customer{
-            Some(customer) => params.push(("customer", customer.as_str())),
-            None => {}
-        }
+#[derive(Serialize)]
+struct SubscriptionItemUpdateParams<'a> {
+    payment_behavior: &'a Option<String>,
+    price: Option<&'a str>,
+    proration_behavior: &'a Option<String>,
+    proration_date: &'a Option<i64>,
+    quantity: &'a Option<i64>,
+    metadata: &'a HashMap<String, String>,
+}
 
-        match &self.default_payment_method{
-            Some(default_payment_method) => params.push(("default_payment_method", default_payment_method.as_str())),
-            None => {}
-        }
+#[derive(Serialize)]
+struct SubscriptionParams<'a> {
+    cancel_at: &'a Option<Timestamp>,
+    cancel_at_period_end: &'a Option<bool>,
+    collection_method: &'a Option<String>,
+    coupon: &'a Option<String>,
+    days_until_due: &'a Option<i64>,
+    default_payment_method: &'a Option<String>,
+    items: Option<Vec<SubscriptionItemParams<'a>>>,
+    pause_collection: Option<PauseCollectionParams<'a>>,
+    payment_behavior: &'a Option<String>,
+    promotion_code: &'a Option<String>,
+    proration_behavior: &'a Option<String>,
+    trial_end: &'a Option<Timestamp>,
+    metadata: &'a HashMap<String, String>,
+}
 
-        match &self.price_items{
+// Stripe takes an empty `pause_collection` to resume collecting payments.
+#[derive(Serialize)]
+#[serde(untagged)]
+enum PauseCollectionParams<'a> {
+    Pause(&'a PauseCollection),
+    Resume(&'static str),
+}
+impl<'a> From<&'a PauseCollection> for PauseCollectionParams<'a> {
+    fn from(pause: &'a PauseCollection) -> Self {
+        if pause.behavior.is_none() && pause.resumes_at.is_none() {
+            PauseCollectionParams::Resume("")
+        } else {
+            PauseCollectionParams::Pause(pause)
+        }
+    }
+}
 
-            Some(price_items) => {
+#[derive(Serialize)]
+struct SubscriptionResumeParams<'a> {
+    proration_behavior: &'a Option<String>,
+}
 
+// Creating takes the phases and the customer instead of the proration of an update.
+#[derive(Serialize)]
+struct SubscriptionScheduleCreateParams<'a> {
+    customer: Option<&'a str>,
+    end_behavior: &'a Option<String>,
+    phases: Option<Vec<SubscriptionSchedulePhaseParams<'a>>>,
+    start_date: &'a Option<i64>,
+    metadata: &'a HashMap<String, String>,
+}
 
-                let mut ii = 0;
-                for (item) in price_items{
-                    if ii < 20{
-                        if ii == 0{
-                            params.push(("items[0][price]", item.as_str()));
-                        }
-                        ii+=1;
-                    }
-                }
+#[derive(Serialize)]
+struct SubscriptionScheduleFromParams<'a> {
+    from_subscription: &'a str,
+}
 
-            },
-            None => {}
+#[derive(Serialize)]
+struct SubscriptionScheduleParams<'a> {
+    end_behavior: &'a Option<String>,
+    phases: Option<Vec<SubscriptionSchedulePhaseParams<'a>>>,
+    proration_behavior: &'a Option<String>,
+    metadata: &'a HashMap<String, String>,
+}
 
+#[derive(Serialize)]
+struct SubscriptionSchedulePhaseParams<'a> {
+    billing_cycle_anchor: &'a Option<String>,
+    collection_method: &'a Option<String>,
+    coupon: &'a Option<String>,
+    default_payment_method: &'a Option<String>,
+    end_date: &'a Option<i64>,
+    items: Vec<SubscriptionItemParams<'a>>,
+    iterations: &'a Option<i64>,
+    proration_behavior: &'a Option<String>,
+    start_date: &'a Option<i64>,
+    trial: &'a Option<bool>,
+    trial_end: &'a Option<i64>,
+    metadata: &'a HashMap<String, String>,
+}
+impl<'a> From<&'a SubscriptionSchedulePhase> for SubscriptionSchedulePhaseParams<'a> {
+    fn from(phase: &'a SubscriptionSchedulePhase) -> Self {
+        SubscriptionSchedulePhaseParams {
+            billing_cycle_anchor: &phase.billing_cycle_anchor,
+            collection_method: &phase.collection_method,
+            coupon: &phase.coupon,
+            default_payment_method: &phase.default_payment_method,
+            end_date: &phase.end_date,
+            items: phase.items.iter().map(SubscriptionItemParams::from).collect(),
+            iterations: &phase.iterations,
+            proration_behavior: &phase.proration_behavior,
+            start_date: &phase.start_date,
+            trial: &phase.trial,
+            trial_end: &phase.trial_end,
+            metadata: &phase.metadata,
         }
+    }
+}
 
+#[derive(Serialize)]
+struct UpcomingInvoiceQuery<'a> {
+    coupon: &'a Option<String>,
+    customer: &'a Option<String>,
+    subscription: &'a Option<String>,
+    subscription_cancel_at_period_end: &'a Option<bool>,
+    subscription_items: Option<Vec<SubscriptionItemParams<'a>>>,
+    subscription_proration_behavior: &'a Option<String>,
+    subscription_proration_date: &'a Option<i64>,
+    subscription_trial_end: &'a Option<i64>,
+}
 
-   
-     
-        return params;
-    }
+// The lines of an upcoming invoice are paged like any other list.
+#[derive(Serialize)]
+struct UpcomingLinesQuery<'a> {
+    #[serde(flatten)]
+    query: UpcomingInvoiceQuery<'a>,
+    limit: &'a Option<u32>,
+    starting_after: &'a Option<String>,
+    ending_before: &'a Option<String>,
+}
 
- 
+#[derive(Serialize)]
+struct UsageRecordParams<'a> {
+    action: &'a Option<String>,
+    quantity: &'a Option<i64>,
+    timestamp: &'a Option<i64>,
 }
 
+#[derive(Serialize)]
+struct WebhookEndpointCreateParams<'a> {
+    #[serde(flatten)]
+    params: WebhookEndpointParams<'a>,
+    api_version: &'a Option<String>,
+    connect: &'a Option<bool>,
+}
 
+#[derive(Serialize)]
+struct WebhookEndpointParams<'a> {
+    description: &'a Option<String>,
+    disabled: Option<bool>,
+    enabled_events: &'a Option<Vec<String>>,
+    url: &'a Option<String>,
+    metadata: &'a HashMap<String, String>,
+}
 
-// =====================================================================================
-// All structs below this point are just used to support the implimented structs above
-// =====================================================================================
+// An object fetched earlier carries timestamps that have passed by now, Stripe rejects those when they are sent back.
+fn upcoming(timestamp: Option<i64>) -> Option<i64> {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() as i64)
+        .unwrap_or(0);
+    timestamp.filter(|timestamp| *timestamp > now)
+}
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -3769,7 +12270,10 @@ pub struct BalanceAvailable {
 #[serde(rename_all = "camelCase")]
 #[doc(hidden)]
 pub struct BalanceSourceTypes {
-    pub card: i64,
+    #[serde(rename = "bank_account")]
+    pub bank_account: Option<i64>,
+    pub card: Option<i64>,
+    pub fpx: Option<i64>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3863,7 +12367,7 @@ pub struct Address {
 #[doc(hidden)]
 pub struct Refunds {
     pub object: String,
-    // pub data: Vec<Value>,
+    pub data: Vec<Refund>,
     #[serde(rename = "has_more")]
     pub has_more: bool,
     pub url: String,
@@ -3891,7 +12395,7 @@ pub struct Disputes {
     pub data: Vec<Dispute>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 #[doc(hidden)]
 pub struct Evidence {
@@ -3951,35 +12455,7 @@ pub struct Evidence {
 }
 impl Evidence {
     pub fn new() -> Self {
-        return Evidence{
-            access_activity_log: None,
-            billing_address: None,
-            cancellation_policy: None,
-            cancellation_policy_disclosure: None,
-            cancellation_rebuttal: None,
-            customer_communication: None,
-            customer_email_address: None,
-            customer_name: None,
-            customer_purchase_ip: None,
-            customer_signature: None,
-            duplicate_charge_documentation: None,
-            duplicate_charge_explanation: None,
-            duplicate_charge_id: None,
-            product_description: None,
-            receipt: None,
-            refund_policy: None,
-            refund_policy_disclosure: None,
-            refund_refusal_explanation: None,
-            service_date: None,
-            service_documentation: None,
-            shipping_address: None,
-            shipping_carrier: None,
-            shipping_date: None,
-            shipping_documentation: None,
-            shipping_tracking_number: None,
-            uncategorized_file: None,
-            uncategorized_text: None
-        };
+        Evidence::default()
     }
 }
 
@@ -3988,7 +12464,7 @@ impl Evidence {
 #[doc(hidden)]
 pub struct EvidenceDetails {
     #[serde(rename = "due_by")]
-    pub due_by: i64,
+    pub due_by: Option<i64>,
     #[serde(rename = "has_evidence")]
     pub has_evidence: bool,
     #[serde(rename = "past_due")]
@@ -4012,9 +12488,9 @@ pub struct Events {
 #[serde(rename_all = "camelCase")]
 #[doc(hidden)]
 pub struct Request {
-    pub id: String,
+    pub id: Option<String>,
     #[serde(rename = "idempotency_key")]
-    pub idempotency_key: String,
+    pub idempotency_key: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4062,25 +12538,31 @@ pub struct Invoices {
 }
 
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+/// How a customer accepted a mandate: `online` in your checkout, or `offline` e.g. on a signed paper form.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
-#[doc(hidden)]
 pub struct CustomerAcceptance {
     #[serde(rename = "accepted_at")]
-    pub accepted_at: i64,
-    pub online: Online,
+    pub accepted_at: Option<i64>,
+    /// Where the customer accepted, required when the type is `online`.
+    pub online: Option<Online>,
     #[serde(rename = "type")]
     pub type_field: String,
 }
+impl CustomerAcceptance {
+    pub fn new() -> Self {
+        CustomerAcceptance::default()
+    }
+}
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+/// The browser a customer accepted a mandate in.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
-#[doc(hidden)]
 pub struct Online {
     #[serde(rename = "ip_address")]
-    pub ip_address: String,
+    pub ip_address: Option<String>,
     #[serde(rename = "user_agent")]
-    pub user_agent: String,
+    pub user_agent: Option<String>,
 }
 
 // #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -4097,6 +12579,44 @@ pub struct SepaDebit {
     pub url: String,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc(hidden)]
+pub struct TaxRate {
+    pub id: Option<String>,
+    pub object: Option<String>,
+    pub active: Option<bool>,
+    pub country: Option<String>,
+    pub description: Option<String>,
+    pub display_name: Option<String>,
+    pub inclusive: Option<bool>,
+    pub jurisdiction: Option<String>,
+    pub percentage: Option<f64>,
+    pub state: Option<String>,
+    pub tax_type: Option<String>,
+}
+
+impl Object for TaxRate {
+    fn object_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc(hidden)]
+pub struct CustomerShipping {
+    pub address: Option<Address>,
+    pub name: Option<String>,
+    pub phone: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc(hidden)]
+pub struct CustomerTaxId {
+    #[serde(rename = "type")]
+    pub type_field: Option<String>,
+    pub value: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 #[doc(hidden)]
@@ -4124,9 +12644,10 @@ pub struct InvoiceLine {
     #[serde(rename = "invoice_item")]
     pub invoice_item: Option<String>,
     pub livemode: Option<bool>,
-    // pub metadata: Metadata,
-    // pub period: Option<Period>,
-    // pub price: Price,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    pub period: Option<Period>,
+    pub price: Option<Price>,
     pub proration: Option<bool>,
     pub quantity: Option<i64>,
     // pub subscription: Value,
@@ -4138,14 +12659,6 @@ pub struct InvoiceLine {
     pub type_field: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[doc(hidden)]
-pub struct Period {
-    pub end: Option<i64>,
-    pub start: Option<i64>,
-}
-
 // #[derive(Serialize, Deserialize, Debug, Clone)]
 // #[serde(rename_all = "camelCase")]
 // #[doc(hidden)]
@@ -0,0 +1,468 @@
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use crate::stripe::retry::Outcome;
+use crate::stripe::{error, form, Auth, RetryPolicy, StripeError};
+
+/// The default Stripe API root every request path is appended to.
+pub const DEFAULT_BASE_URL: &str = "https://api.stripe.com/v1";
+
+pub(crate) const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
+const IDEMPOTENT_REPLAYED: &str = "Idempotent-Replayed";
+
+/// The user agent sent with every request unless one is configured.
+pub const DEFAULT_USER_AGENT: &str = concat!("payup/", env!("CARGO_PKG_VERSION"));
+
+/// A reusable connection to the Stripe API.
+///
+/// Holds the credentials, base URL and HTTP settings shared by every request, plus one pooled
+/// blocking and one pooled async HTTP client. Cloning a StripeClient is cheap and the clones share
+/// the same connection pools.
+#[derive(Debug, Clone)]
+pub struct StripeClient {
+    auth: Auth,
+    base_url: String,
+    settings: Arc<Settings>,
+    client: reqwest::Client,
+    blocking: Arc<OnceLock<reqwest::blocking::Client>>,
+    idempotency_key: Option<String>,
+    expand: Vec<String>,
+}
+
+#[derive(Debug)]
+struct Settings {
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    user_agent: String,
+    default_headers: reqwest::header::HeaderMap,
+    retry: RetryPolicy,
+}
+
+impl StripeClient {
+    /// Returns a StripeClient that talks to the live Stripe API with the default settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` - payup::stripe::Auth::new(client, secret)
+    ///
+    /// # Panics
+    ///
+    /// Panics if the TLS backend cannot be initialized. Use `StripeClient::builder` to handle that case.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # let (client, secret) = (String::new(), String::new());
+    /// // Create the Authentication refererence
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    ///
+    /// // Build a client that can be reused across requests
+    /// let client = payup::stripe::StripeClient::new(auth);
+    /// ```
+    pub fn new(auth: Auth) -> Self {
+        StripeClient::builder(auth).build().expect("failed to build the stripe http client")
+    }
+
+    /// Returns a StripeClientBuilder to customize the base URL, timeouts, user agent and default headers.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    ///
+    /// // Point the client at a local mock server
+    /// let client = payup::stripe::StripeClient::builder(auth)
+    ///     .base_url("http://localhost:12111/v1")
+    ///     .timeout(std::time::Duration::from_secs(10))
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder(auth: Auth) -> StripeClientBuilder {
+        StripeClientBuilder::new(auth)
+    }
+
+    /// The credentials used to authenticate every request.
+    pub fn auth(&self) -> &Auth {
+        &self.auth
+    }
+
+    /// The API root every request path is appended to.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Returns a copy of this client that sends `key` as the `Idempotency-Key` of its POST requests.
+    ///
+    /// Without a key every POST is sent with a freshly generated one. Supply your own key to make a
+    /// call safe to repeat across process restarts, e.g. one derived from your own order id.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// let mut charge = payup::stripe::Charge::new();
+    /// charge.amount = Some(100);
+    /// charge.currency = Some(format!("usd"));
+    ///
+    /// // Sending the same charge twice with the same key only charges the customer once
+    /// let charge = charge.post(&client.with_idempotency_key("order_1234"))?;
+    /// if charge.idempotent_replayed == Some(true) {
+    ///     println!("charge {:?} was already created", charge.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_idempotency_key(&self, key: impl Into<String>) -> StripeClient {
+        let mut client = self.clone();
+        client.idempotency_key = Some(key.into());
+        client
+    }
+
+    /// The idempotency key set with `with_idempotency_key`, if any.
+    pub fn idempotency_key(&self) -> Option<&str> {
+        self.idempotency_key.as_deref()
+    }
+
+    /// Returns a copy of this client that asks Stripe to expand `fields` in every response.
+    ///
+    /// An expanded field carries the whole referenced object instead of its id, see `Expandable`.
+    /// Nested fields are separated by dots, e.g. `invoice.subscription`, and fields of the objects
+    /// returned by a list need the `data.` prefix, e.g. `data.customer`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), payup::stripe::StripeError> {
+    /// # let (client, secret) = (String::new(), String::new());
+    /// let auth = payup::stripe::Auth::new(client, secret);
+    /// let client = payup::stripe::StripeClient::new(auth);
+    ///
+    /// // Fetch an invoice together with its customer and subscription
+    /// let invoice = payup::stripe::Invoice::get(&client.with_expand(["customer", "subscription"]), format!("in_"))?;
+    ///
+    /// // Expand the customer of every listed charge
+    /// let charges = payup::stripe::Charge::list(&client.with_expand(["data.customer"]), payup::stripe::ChargeListParams::new())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_expand<I, S>(&self, fields: I) -> StripeClient
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut client = self.clone();
+        client.expand = fields.into_iter().map(Into::into).collect();
+        client
+    }
+
+    /// The fields set with `with_expand`.
+    pub fn expand(&self) -> &[String] {
+        &self.expand
+    }
+
+    pub(crate) fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, StripeError> {
+        let request = self.blocking()?.get(self.url(path)).query(&self.expand_pairs());
+        self.send(request)
+    }
+
+    pub(crate) fn get_query<T: DeserializeOwned, Q: Serialize + ?Sized>(&self, path: &str, query: &Q) -> Result<T, StripeError> {
+        let request = self.blocking()?.get(self.url(path)).query(query).query(&self.expand_pairs());
+        self.send(request)
+    }
+
+    pub(crate) fn post<T: DeserializeOwned, P: Serialize + ?Sized>(&self, path: &str, params: &P) -> Result<T, StripeError> {
+        let request = self.blocking()?.post(self.url(path))
+            .header(IDEMPOTENCY_KEY, self.next_idempotency_key())
+            .form(&self.form(params)?);
+        self.send(request)
+    }
+
+    pub(crate) fn post_multipart<T: DeserializeOwned>(&self, path: &str, mut form: reqwest::blocking::multipart::Form) -> Result<T, StripeError> {
+        for field in &self.expand {
+            form = form.text("expand[]", field.clone());
+        }
+        let request = self.blocking()?.post(self.url(path))
+            .header(IDEMPOTENCY_KEY, self.next_idempotency_key())
+            .multipart(form);
+        self.send(request)
+    }
+
+    pub(crate) fn delete<T: DeserializeOwned>(&self, path: &str) -> Result<T, StripeError> {
+        let request = self.blocking()?.delete(self.url(path)).query(&self.expand_pairs());
+        self.send(request)
+    }
+
+    pub(crate) async fn async_get<T: DeserializeOwned>(&self, path: &str) -> Result<T, StripeError> {
+        let request = self.client.get(self.url(path)).query(&self.expand_pairs());
+        self.async_send(request).await
+    }
+
+    pub(crate) async fn async_get_query<T: DeserializeOwned, Q: Serialize + ?Sized>(&self, path: &str, query: &Q) -> Result<T, StripeError> {
+        let request = self.client.get(self.url(path)).query(query).query(&self.expand_pairs());
+        self.async_send(request).await
+    }
+
+    pub(crate) async fn async_post<T: DeserializeOwned, P: Serialize + ?Sized>(&self, path: &str, params: &P) -> Result<T, StripeError> {
+        let request = self.client.post(self.url(path))
+            .header(IDEMPOTENCY_KEY, self.next_idempotency_key())
+            .form(&self.form(params)?);
+        self.async_send(request).await
+    }
+
+    pub(crate) async fn async_post_multipart<T: DeserializeOwned>(&self, path: &str, mut form: reqwest::multipart::Form) -> Result<T, StripeError> {
+        for field in &self.expand {
+            form = form.text("expand[]", field.clone());
+        }
+        let request = self.client.post(self.url(path))
+            .header(IDEMPOTENCY_KEY, self.next_idempotency_key())
+            .multipart(form);
+        self.async_send(request).await
+    }
+
+    pub(crate) async fn async_delete<T: DeserializeOwned>(&self, path: &str) -> Result<T, StripeError> {
+        let request = self.client.delete(self.url(path)).query(&self.expand_pairs());
+        self.async_send(request).await
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    fn expand_pairs(&self) -> Vec<(&str, &str)> {
+        self.expand.iter().map(|field| ("expand[]", field.as_str())).collect()
+    }
+
+    fn form<P: Serialize + ?Sized>(&self, params: &P) -> Result<Vec<(String, String)>, StripeError> {
+        let mut pairs = form::to_pairs(params)?;
+        pairs.extend(self.expand.iter().map(|field| ("expand[]".to_string(), field.clone())));
+        Ok(pairs)
+    }
+
+    fn next_idempotency_key(&self) -> String {
+        match &self.idempotency_key {
+            Some(key) => key.clone(),
+            None => uuid::Uuid::new_v4().to_string(),
+        }
+    }
+
+    // The blocking client spawns its own runtime, which panics when done from inside an async
+    // runtime, so it is only built once a blocking call is actually made.
+    fn blocking(&self) -> Result<&reqwest::blocking::Client, StripeError> {
+        if let Some(client) = self.blocking.get() {
+            return Ok(client);
+        }
+
+        let mut builder = reqwest::blocking::Client::builder()
+            .user_agent(self.settings.user_agent.clone())
+            .default_headers(self.settings.default_headers.clone())
+            .timeout(self.settings.timeout);
+        if let Some(connect_timeout) = self.settings.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        let client = builder.build()?;
+        Ok(self.blocking.get_or_init(|| client))
+    }
+
+    fn send<T: DeserializeOwned>(&self, request: reqwest::blocking::RequestBuilder) -> Result<T, StripeError> {
+        let client = self.blocking()?;
+        let mut request = request
+            .basic_auth(self.auth.client.as_str(), Some(self.auth.secret.as_str()))
+            .build()?;
+
+        let mut attempt = 1;
+        let response = loop {
+            // Multipart bodies are streamed and cannot be copied, those are only sent once.
+            let retry = if self.settings.retry.allows(attempt, request.method(), request.headers()) {
+                request.try_clone()
+            } else {
+                None
+            };
+            let outcome = client.execute(request);
+            let (retry, delay) = match retry {
+                Some(retry) => (retry, self.settings.retry.delay(attempt, blocking_outcome(&outcome))),
+                None => break outcome?,
+            };
+            match delay {
+                Some(delay) => std::thread::sleep(delay),
+                None => break outcome?,
+            }
+            request = retry;
+            attempt += 1;
+        };
+
+        let status = response.status().as_u16();
+        let replayed = is_replayed(response.headers());
+        let body = response.text()?;
+        decode(status, replayed, &body)
+    }
+
+    async fn async_send<T: DeserializeOwned>(&self, request: reqwest::RequestBuilder) -> Result<T, StripeError> {
+        let mut request = request
+            .basic_auth(self.auth.client.as_str(), Some(self.auth.secret.as_str()))
+            .build()?;
+
+        let mut attempt = 1;
+        let response = loop {
+            // Multipart bodies are streamed and cannot be copied, those are only sent once.
+            let retry = if self.settings.retry.allows(attempt, request.method(), request.headers()) {
+                request.try_clone()
+            } else {
+                None
+            };
+            let outcome = self.client.execute(request).await;
+            let (retry, delay) = match retry {
+                Some(retry) => (retry, self.settings.retry.delay(attempt, async_outcome(&outcome))),
+                None => break outcome?,
+            };
+            match delay {
+                Some(delay) => tokio::time::sleep(delay).await,
+                None => break outcome?,
+            }
+            request = retry;
+            attempt += 1;
+        };
+
+        let status = response.status().as_u16();
+        let replayed = is_replayed(response.headers());
+        let body = response.text().await?;
+        decode(status, replayed, &body)
+    }
+}
+
+fn blocking_outcome(outcome: &reqwest::Result<reqwest::blocking::Response>) -> Outcome<'_> {
+    match outcome {
+        Ok(response) => Outcome::Response(response.status().as_u16(), response.headers()),
+        Err(err) => Outcome::Failed(err),
+    }
+}
+
+fn async_outcome(outcome: &reqwest::Result<reqwest::Response>) -> Outcome<'_> {
+    match outcome {
+        Ok(response) => Outcome::Response(response.status().as_u16(), response.headers()),
+        Err(err) => Outcome::Failed(err),
+    }
+}
+
+fn is_replayed(headers: &reqwest::header::HeaderMap) -> bool {
+    headers.get(IDEMPOTENT_REPLAYED)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+}
+
+// Stripe marks responses replayed from an earlier request with the same idempotency key in a header
+// only, so the flag is copied into the body where the resource structs pick it up.
+fn decode<T: DeserializeOwned>(status: u16, replayed: bool, body: &str) -> Result<T, StripeError> {
+    if !replayed {
+        return error::parse(status, body);
+    }
+
+    let mut value: serde_json::Value = error::parse(status, body)?;
+    if let Some(object) = value.as_object_mut() {
+        object.insert("idempotent_replayed".to_string(), serde_json::Value::Bool(true));
+    }
+    Ok(serde_json::from_value(value)?)
+}
+
+/// Configures and builds a StripeClient.
+#[derive(Debug)]
+pub struct StripeClientBuilder {
+    auth: Auth,
+    base_url: String,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    user_agent: String,
+    default_headers: reqwest::header::HeaderMap,
+    retry: RetryPolicy,
+}
+
+impl StripeClientBuilder {
+    /// Returns a builder with the default settings for the given credentials.
+    pub fn new(auth: Auth) -> Self {
+        StripeClientBuilder {
+            auth,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            timeout: Some(Duration::from_secs(80)),
+            connect_timeout: None,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            default_headers: reqwest::header::HeaderMap::new(),
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    /// Sets the API root every request path is appended to. Defaults to `https://api.stripe.com/v1`.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sets the total time allowed for a single request. Defaults to 80 seconds.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the time allowed to establish a connection.
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Sets the User-Agent header sent with every request.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Adds headers that are sent with every request, e.g. `Stripe-Version` or `Stripe-Account`.
+    pub fn default_headers(mut self, headers: reqwest::header::HeaderMap) -> Self {
+        self.default_headers.extend(headers);
+        self
+    }
+
+    /// Sets how requests that failed for a transient reason are retried. Defaults to `RetryPolicy::default()`,
+    /// use `RetryPolicy::none()` to send every request exactly once.
+    pub fn retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Builds the StripeClient, failing if the TLS backend cannot be initialized.
+    pub fn build(self) -> Result<StripeClient, StripeError> {
+        let mut builder = reqwest::Client::builder()
+            .user_agent(self.user_agent.clone())
+            .default_headers(self.default_headers.clone());
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+
+        Ok(StripeClient {
+            auth: self.auth,
+            base_url: self.base_url,
+            client: builder.build()?,
+            blocking: Arc::new(OnceLock::new()),
+            idempotency_key: None,
+            expand: Vec::new(),
+            settings: Arc::new(Settings {
+                timeout: self.timeout,
+                connect_timeout: self.connect_timeout,
+                user_agent: self.user_agent,
+                default_headers: self.default_headers,
+                retry: self.retry,
+            }),
+        })
+    }
+}
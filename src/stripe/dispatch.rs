@@ -0,0 +1,723 @@
+use futures_util::future::BoxFuture;
+use futures_util::TryStreamExt;
+use serde::Deserialize;
+
+use std::fmt;
+use std::future::Future;
+
+use crate::stripe::list::{self, Object};
+use crate::stripe::{
+    Balance, Charge, Coupon, Customer, Discount, Dispute, Event, EventListParams, EventObject, EventStore, EventType, File,
+    Invoice, InvoiceItem, Mandate, PaymentIntent, PaymentMethod, Plan, Price, Product, PromotionCode, RangeQuery, Refund,
+    SetupIntent, StripeClient, StripeError, Subscription, SubscriptionSchedule,
+};
+
+/// What an event handler returns, any error can be returned with `?`.
+pub type HandlerResult = Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+type Handler = Box<dyn Fn(&Event) -> Result<(), DispatchError> + Send + Sync>;
+type AsyncHandler = Box<dyn Fn(&Event) -> Result<BoxFuture<'static, HandlerResult>, DispatchError> + Send + Sync>;
+
+/// Routes events to the handlers registered for their type.
+///
+/// A handler takes the object of the event as the struct it expects, e.g. an `Invoice` for
+/// `invoice.payment_failed`, or the whole `Event` or the raw `serde_json::Value` of the object. Several
+/// handlers can be registered for the same type, they run in the order they were added. See
+/// `AsyncEventDispatcher` for handlers that return a future.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let (payload, header) = (String::new(), String::new());
+/// use payup::stripe::{Event, EventDispatcher, EventType, Invoice, Subscription};
+///
+/// let dispatcher = EventDispatcher::new()
+///     .on(EventType::InvoicePaymentFailed, |invoice: Invoice| {
+///         println!("payment of {:?} failed", invoice.id);
+///         Ok(())
+///     })
+///     .on(EventType::CustomerSubscriptionDeleted, |subscription: Subscription| {
+///         println!("{:?} ended", subscription.id);
+///         Ok(())
+///     })
+///     .fallback(|event: Event| {
+///         println!("ignoring {:?}", event.type_field);
+///         Ok(())
+///     });
+///
+/// let event = payup::stripe::Webhook::new("whsec_").construct_event(&payload, &header)?;
+/// dispatcher.dispatch(&event)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct EventDispatcher {
+    handlers: Vec<(EventType, Handler)>,
+    fallback: Option<Handler>,
+}
+
+impl EventDispatcher {
+    pub fn new() -> Self {
+        EventDispatcher::default()
+    }
+
+    /// Runs the handlers registered for the type of the event, or the fallback when there are none.
+    ///
+    /// Returns whether a handler ran. Stops at the first handler that fails.
+    pub fn dispatch(&self, event: &Event) -> Result<bool, DispatchError> {
+        let mut handled = false;
+        for (_, handler) in self.handlers.iter().filter(|(event_type, _)| event.type_field.as_ref() == Some(event_type)) {
+            handler(event)?;
+            handled = true;
+        }
+        if let (false, Some(fallback)) = (handled, &self.fallback) {
+            fallback(event)?;
+            handled = true;
+        }
+        Ok(handled)
+    }
+
+    /// Dispatches an event unless the store recorded it before, returns `false` for a duplicate.
+    ///
+    /// The event is recorded before the handlers run, so a second delivery that arrives meanwhile is
+    /// skipped, and removed again when a handler fails, so the retry of Stripe is processed.
+    pub fn dispatch_once(&self, store: &dyn EventStore, event: &Event) -> Result<bool, DispatchError> {
+        Ok(self.dispatch_new(store, event)?.unwrap_or(false))
+    }
+
+    /// Registers a handler for the events no other handler is registered for.
+    pub fn fallback<T: FromEvent, F>(mut self, handler: F) -> Self
+    where
+        F: Fn(T) -> HandlerResult + Send + Sync + 'static,
+    {
+        self.fallback = Some(handler_for(handler));
+        self
+    }
+
+    /// Registers a handler for the events of a type.
+    pub fn on<T: FromEvent, F>(mut self, event_type: EventType, handler: F) -> Self
+    where
+        F: Fn(T) -> HandlerResult + Send + Sync + 'static,
+    {
+        self.handlers.push((event_type, handler_for(handler)));
+        self
+    }
+
+    /// Dispatches the events that were missed, e.g. while the endpoint was down, oldest first.
+    ///
+    /// Walks the events Stripe listed after the checkpoint of the store page by page, oldest first, and
+    /// dispatches every event the store has not recorded yet. The checkpoint is set by the first event the
+    /// store records and only moves forward once every event before it was processed, so the events missed
+    /// while live ones kept arriving are replayed too. It stays behind a handler that fails, so the next
+    /// replay starts there again. An event that does not decode, e.g. one created with an old api version,
+    /// is skipped. Nothing is replayed before the first event was recorded, and Stripe only lists the
+    /// events of the last 30 days. Returns the number of events that were dispatched.
+    pub fn replay(&self, client: &StripeClient, store: &dyn EventStore) -> Result<usize, DispatchError> {
+        let checkpoint = match store.checkpoint().map_err(DispatchError::Store)? {
+            Some(checkpoint) => checkpoint,
+            None => return Ok(0),
+        };
+        let mut replay = Replay::default();
+        let result = self.replay_from(client, store, checkpoint, &mut replay);
+        replay.finish(store, result)
+    }
+
+    fn replay_from(&self, client: &StripeClient, store: &dyn EventStore, checkpoint: i64, replay: &mut Replay) -> Result<(), DispatchError> {
+        let anchor = match list::iter::<RawEvent, _>(client, "/events", &anchor_params(checkpoint)).next() {
+            Some(anchor) => anchor.map_err(DispatchError::Stripe)?,
+            None => {
+                // Stripe no longer lists anything before the checkpoint, the walk starts at the oldest event.
+                let oldest = match list::iter::<RawEvent, _>(client, "/events", &since_params(checkpoint)).last() {
+                    Some(oldest) => oldest.map_err(DispatchError::Stripe)?,
+                    None => return Ok(()),
+                };
+                let result = oldest.decode().map_or(Ok(None), |event| self.dispatch_new(store, &event));
+                replay.record(&oldest, result)?;
+                oldest
+            }
+        };
+        let anchor = match anchor.object_id() {
+            Some(anchor) => anchor,
+            None => return Ok(()),
+        };
+        for event in list::iter::<RawEvent, _>(client, "/events", &after_params(anchor)) {
+            let event = event.map_err(DispatchError::Stripe)?;
+            let result = event.decode().map_or(Ok(None), |decoded| self.dispatch_new(store, &decoded));
+            replay.record(&event, result)?;
+        }
+        Ok(())
+    }
+
+    // Returns whether a handler ran, or None when the event was a duplicate.
+    fn dispatch_new(&self, store: &dyn EventStore, event: &Event) -> Result<Option<bool>, DispatchError> {
+        let id = match &event.id {
+            Some(id) => id,
+            None => return self.dispatch(event).map(Some),
+        };
+        if !store.insert(id, event.created.unwrap_or(0)).map_err(DispatchError::Store)? {
+            return Ok(None);
+        }
+        match self.dispatch(event) {
+            Ok(handled) => start(store, event).map(|_| Some(handled)),
+            Err(err) => {
+                store.remove(id).map_err(DispatchError::Store)?;
+                Err(err)
+            }
+        }
+    }
+}
+
+fn handler_for<T: FromEvent, F>(handler: F) -> Handler
+where
+    F: Fn(T) -> HandlerResult + Send + Sync + 'static,
+{
+    Box::new(move |event: &Event| {
+        let object = T::from_event(event).map_err(|err| decode_error(event, err))?.ok_or_else(|| unexpected_object(event))?;
+        handler(object).map_err(DispatchError::Handler)
+    })
+}
+
+/// Routes events to async handlers, see `EventDispatcher`.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let (payload, header) = (String::new(), String::new());
+/// use payup::stripe::{AsyncEventDispatcher, EventType, Invoice};
+///
+/// let dispatcher = AsyncEventDispatcher::new()
+///     .on(EventType::InvoicePaid, |invoice: Invoice| async move {
+///         println!("{:?} was paid", invoice.id);
+///         Ok(())
+///     });
+///
+/// let event = payup::stripe::Webhook::new("whsec_").construct_event(&payload, &header)?;
+/// dispatcher.dispatch(&event).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct AsyncEventDispatcher {
+    handlers: Vec<(EventType, AsyncHandler)>,
+    fallback: Option<AsyncHandler>,
+}
+
+impl AsyncEventDispatcher {
+    pub fn new() -> Self {
+        AsyncEventDispatcher::default()
+    }
+
+    /// Runs the handlers registered for the type of the event one after another, or the fallback when
+    /// there are none.
+    ///
+    /// Returns whether a handler ran. Stops at the first handler that fails.
+    pub async fn dispatch(&self, event: &Event) -> Result<bool, DispatchError> {
+        let mut handled = false;
+        for (_, handler) in self.handlers.iter().filter(|(event_type, _)| event.type_field.as_ref() == Some(event_type)) {
+            handler(event)?.await.map_err(DispatchError::Handler)?;
+            handled = true;
+        }
+        if let (false, Some(fallback)) = (handled, &self.fallback) {
+            fallback(event)?.await.map_err(DispatchError::Handler)?;
+            handled = true;
+        }
+        Ok(handled)
+    }
+
+    /// Dispatches an event unless the store recorded it before, see `EventDispatcher::dispatch_once`.
+    pub async fn dispatch_once(&self, store: &dyn EventStore, event: &Event) -> Result<bool, DispatchError> {
+        Ok(self.dispatch_new(store, event).await?.unwrap_or(false))
+    }
+
+    /// Registers a handler for the events no other handler is registered for.
+    pub fn fallback<T: FromEvent, F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HandlerResult> + Send + 'static,
+    {
+        self.fallback = Some(async_handler_for(handler));
+        self
+    }
+
+    /// Registers a handler for the events of a type.
+    pub fn on<T: FromEvent, F, Fut>(mut self, event_type: EventType, handler: F) -> Self
+    where
+        F: Fn(T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HandlerResult> + Send + 'static,
+    {
+        self.handlers.push((event_type, async_handler_for(handler)));
+        self
+    }
+
+    /// Dispatches the events that were missed, oldest first, see `EventDispatcher::replay`.
+    pub async fn replay(&self, client: &StripeClient, store: &dyn EventStore) -> Result<usize, DispatchError> {
+        let checkpoint = match store.checkpoint().map_err(DispatchError::Store)? {
+            Some(checkpoint) => checkpoint,
+            None => return Ok(0),
+        };
+        let mut replay = Replay::default();
+        let result = self.replay_from(client, store, checkpoint, &mut replay).await;
+        replay.finish(store, result)
+    }
+
+    async fn replay_from(&self, client: &StripeClient, store: &dyn EventStore, checkpoint: i64, replay: &mut Replay) -> Result<(), DispatchError> {
+        let anchor = match list::stream::<RawEvent, _>(client, "/events", &anchor_params(checkpoint)).try_next().await {
+            Ok(Some(anchor)) => anchor,
+            Ok(None) => {
+                // Stripe no longer lists anything before the checkpoint, the walk starts at the oldest event.
+                let mut events = list::stream::<RawEvent, _>(client, "/events", &since_params(checkpoint));
+                let mut oldest = None;
+                while let Some(event) = events.try_next().await.map_err(DispatchError::Stripe)? {
+                    oldest = Some(event);
+                }
+                let oldest = match oldest {
+                    Some(oldest) => oldest,
+                    None => return Ok(()),
+                };
+                let result = match oldest.decode() {
+                    Some(event) => self.dispatch_new(store, &event).await,
+                    None => Ok(None),
+                };
+                replay.record(&oldest, result)?;
+                oldest
+            }
+            Err(err) => return Err(DispatchError::Stripe(err)),
+        };
+        let anchor = match anchor.object_id() {
+            Some(anchor) => anchor,
+            None => return Ok(()),
+        };
+        let mut events = list::stream::<RawEvent, _>(client, "/events", &after_params(anchor));
+        while let Some(event) = events.try_next().await.map_err(DispatchError::Stripe)? {
+            let result = match event.decode() {
+                Some(decoded) => self.dispatch_new(store, &decoded).await,
+                None => Ok(None),
+            };
+            replay.record(&event, result)?;
+        }
+        Ok(())
+    }
+
+    // Returns whether a handler ran, or None when the event was a duplicate.
+    async fn dispatch_new(&self, store: &dyn EventStore, event: &Event) -> Result<Option<bool>, DispatchError> {
+        let id = match &event.id {
+            Some(id) => id,
+            None => return self.dispatch(event).await.map(Some),
+        };
+        if !store.insert(id, event.created.unwrap_or(0)).map_err(DispatchError::Store)? {
+            return Ok(None);
+        }
+        match self.dispatch(event).await {
+            Ok(handled) => start(store, event).map(|_| Some(handled)),
+            Err(err) => {
+                store.remove(id).map_err(DispatchError::Store)?;
+                Err(err)
+            }
+        }
+    }
+}
+
+fn async_handler_for<T: FromEvent, F, Fut>(handler: F) -> AsyncHandler
+where
+    F: Fn(T) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = HandlerResult> + Send + 'static,
+{
+    Box::new(move |event: &Event| {
+        let object = T::from_event(event).map_err(|err| decode_error(event, err))?.ok_or_else(|| unexpected_object(event))?;
+        Ok(Box::pin(handler(object)) as BoxFuture<'static, HandlerResult>)
+    })
+}
+
+// How far a replay got, the checkpoint is moved to the last event that was processed along with all before it.
+#[derive(Default)]
+struct Replay {
+    replayed: usize,
+    processed: Option<i64>,
+}
+
+impl Replay {
+    // A skipped event counts as processed, only a failed one stops the replay.
+    fn record(&mut self, event: &RawEvent, dispatched: Result<Option<bool>, DispatchError>) -> Result<(), DispatchError> {
+        self.replayed += usize::from(dispatched?.is_some());
+        self.processed = event.0.get("created").and_then(serde_json::Value::as_i64).or(self.processed);
+        Ok(())
+    }
+
+    fn finish(self, store: &dyn EventStore, result: Result<(), DispatchError>) -> Result<usize, DispatchError> {
+        let checkpoint = store.checkpoint().map_err(DispatchError::Store)?;
+        if let Some(created) = self.processed.filter(|created| checkpoint.is_none_or(|checkpoint| *created > checkpoint)) {
+            store.set_checkpoint(created).map_err(DispatchError::Store)?;
+        }
+        result.map(|_| self.replayed)
+    }
+}
+
+// An event as Stripe listed it, decoded only when it is dispatched so one that does not decode can be skipped.
+#[derive(Deserialize)]
+struct RawEvent(serde_json::Value);
+
+impl RawEvent {
+    fn decode(&self) -> Option<Event> {
+        Event::deserialize(&self.0).ok()
+    }
+}
+
+impl list::Object for RawEvent {
+    fn object_id(&self) -> Option<&str> {
+        self.0.get("id").and_then(serde_json::Value::as_str)
+    }
+}
+
+// The first event a store records is where replays start, nothing before it was meant to be processed.
+fn start(store: &dyn EventStore, event: &Event) -> Result<(), DispatchError> {
+    match (store.checkpoint().map_err(DispatchError::Store)?, event.created) {
+        (None, Some(created)) => store.set_checkpoint(created).map_err(DispatchError::Store),
+        _ => Ok(()),
+    }
+}
+
+// The newest event before the checkpoint, the replay walks everything Stripe listed after it.
+fn anchor_params(checkpoint: i64) -> EventListParams {
+    EventListParams {
+        created: Some(RangeQuery {
+            lt: Some(checkpoint),
+            ..RangeQuery::default()
+        }),
+        limit: Some(1),
+        ..EventListParams::default()
+    }
+}
+
+// `ending_before` walks the list backwards, i.e. from the anchor to the newest event.
+fn after_params(anchor: &str) -> EventListParams {
+    EventListParams {
+        ending_before: Some(anchor.to_string()),
+        limit: Some(100),
+        ..EventListParams::default()
+    }
+}
+
+fn since_params(checkpoint: i64) -> EventListParams {
+    EventListParams {
+        created: Some(RangeQuery {
+            gte: Some(checkpoint),
+            ..RangeQuery::default()
+        }),
+        limit: Some(100),
+        ..EventListParams::default()
+    }
+}
+
+fn decode_error(event: &Event, err: serde_json::Error) -> DispatchError {
+    DispatchError::Decode(event_type(event), err)
+}
+
+// The handler was registered for a type whose object did not match, e.g. an `Invoice` handler for `charge.succeeded`.
+fn unexpected_object(event: &Event) -> DispatchError {
+    DispatchError::UnexpectedObject(event_type(event))
+}
+
+fn event_type(event: &Event) -> EventType {
+    event.type_field.clone().unwrap_or_else(|| EventType::Other(String::new()))
+}
+
+/// What a handler can take from an event, implemented for every object an Event can carry.
+pub trait FromEvent: Sized {
+    /// Returns the value for the handler, `None` when the event does not carry it and an error when it
+    /// carries it but the value could not be decoded.
+    fn from_event(event: &Event) -> Result<Option<Self>, serde_json::Error>;
+}
+
+impl FromEvent for Event {
+    fn from_event(event: &Event) -> Result<Option<Self>, serde_json::Error> {
+        Ok(Some(event.clone()))
+    }
+}
+
+impl FromEvent for serde_json::Value {
+    fn from_event(event: &Event) -> Result<Option<Self>, serde_json::Error> {
+        event.data.as_ref().map(|data| serde_json::to_value(&data.object)).transpose()
+    }
+}
+
+// An object that did not match its struct is kept as `EventObject::Unknown`, decoding it again from the raw
+// JSON returns the error for the handler.
+macro_rules! from_event {
+    ($($object:ident => $name:literal),*) => {$(
+        impl FromEvent for $object {
+            fn from_event(event: &Event) -> Result<Option<Self>, serde_json::Error> {
+                match event.data.as_ref().map(|data| &data.object) {
+                    Some(EventObject::$object(object)) => Ok(Some(object.as_ref().clone())),
+                    Some(EventObject::Unknown(object)) if object.get("object").and_then(serde_json::Value::as_str) == Some($name) => {
+                        $object::deserialize(object).map(Some)
+                    }
+                    _ => Ok(None),
+                }
+            }
+        }
+    )*};
+}
+
+from_event!(
+    Balance => "balance",
+    Charge => "charge",
+    Coupon => "coupon",
+    Customer => "customer",
+    Discount => "discount",
+    Dispute => "dispute",
+    File => "file",
+    Invoice => "invoice",
+    InvoiceItem => "invoiceitem",
+    Mandate => "mandate",
+    PaymentIntent => "payment_intent",
+    PaymentMethod => "payment_method",
+    Plan => "plan",
+    Price => "price",
+    Product => "product",
+    PromotionCode => "promotion_code",
+    Refund => "refund",
+    SetupIntent => "setup_intent",
+    Subscription => "subscription",
+    SubscriptionSchedule => "subscription_schedule"
+);
+
+/// Errors returned when an event could not be handled.
+#[derive(Debug)]
+pub enum DispatchError {
+    /// A handler expected another object than the event carried, e.g. because it was registered for
+    /// the wrong type. Carries the type of the event.
+    UnexpectedObject(EventType),
+    /// The event carried the object a handler expected, but it could not be decoded into what the
+    /// handler takes. Carries the type of the event and the error.
+    Decode(EventType, serde_json::Error),
+    /// A handler returned an error.
+    Handler(Box<dyn std::error::Error + Send + Sync>),
+    /// The event store could not be read or written.
+    Store(std::io::Error),
+    /// The missed events could not be listed.
+    Stripe(StripeError),
+}
+
+impl fmt::Display for DispatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DispatchError::UnexpectedObject(event_type) => write!(f, "event {} carries an unexpected object", event_type),
+            DispatchError::Decode(event_type, err) => write!(f, "could not decode the object of event {}: {}", event_type, err),
+            DispatchError::Handler(err) => write!(f, "event handler failed: {}", err),
+            DispatchError::Store(err) => write!(f, "event store failed: {}", err),
+            DispatchError::Stripe(err) => write!(f, "could not list missed events: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for DispatchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DispatchError::Decode(_, err) => Some(err),
+            DispatchError::Handler(err) => Some(err.as_ref()),
+            DispatchError::Store(err) => Some(err),
+            DispatchError::Stripe(err) => Some(err),
+            DispatchError::UnexpectedObject(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{self, Receiver};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    use crate::stripe::{Auth, MemoryEventStore, RetryPolicy};
+
+    fn event(amount: serde_json::Value) -> Event {
+        serde_json::from_value(serde_json::json!({
+            "id": "evt_1",
+            "object": "event",
+            "created": 1680064028,
+            "data": {"object": {"id": "ch_1", "object": "charge", "amount": amount}},
+            "type": "charge.succeeded"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn hands_the_typed_object_to_the_handler() {
+        let dispatcher = EventDispatcher::new().on(EventType::ChargeSucceeded, |charge: Charge| {
+            assert_eq!(charge.amount, Some(1099));
+            Ok(())
+        });
+        assert!(dispatcher.dispatch(&event(1099.into())).unwrap());
+    }
+
+    #[test]
+    fn reports_an_object_that_does_not_decode() {
+        let dispatcher = EventDispatcher::new().on(EventType::ChargeSucceeded, |_: Charge| Ok(()));
+        match dispatcher.dispatch(&event("1099".into())) {
+            Err(DispatchError::Decode(EventType::ChargeSucceeded, err)) => assert!(err.to_string().contains("invalid type"), "{}", err),
+            result => panic!("expected a decode error, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn hands_an_object_that_does_not_decode_to_raw_handlers() {
+        let dispatcher = EventDispatcher::new().on(EventType::ChargeSucceeded, |object: serde_json::Value| {
+            assert_eq!(object["amount"], "1099");
+            Ok(())
+        });
+        assert!(dispatcher.dispatch(&event("1099".into())).unwrap());
+    }
+
+    #[test]
+    fn reports_a_handler_for_another_object() {
+        let dispatcher = EventDispatcher::new().on(EventType::ChargeSucceeded, |_: Invoice| Ok(()));
+        for amount in [serde_json::Value::from(1099), "1099".into()] {
+            assert!(matches!(dispatcher.dispatch(&event(amount)), Err(DispatchError::UnexpectedObject(EventType::ChargeSucceeded))));
+        }
+    }
+
+    #[tokio::test]
+    async fn reports_an_object_that_does_not_decode_to_async_handlers() {
+        let dispatcher = AsyncEventDispatcher::new().on(EventType::ChargeSucceeded, |_: Charge| async { Ok(()) });
+        assert!(matches!(dispatcher.dispatch(&event("1099".into())).await, Err(DispatchError::Decode(EventType::ChargeSucceeded, _))));
+    }
+
+    fn listed(id: &str, created: i64) -> String {
+        format!(r#"{{"id": "{}", "object": "event", "created": {}, "type": "customer.created"}}"#, id, created)
+    }
+
+    fn page(events: &[String], has_more: bool) -> String {
+        format!(r#"{{"object": "list", "data": [{}], "has_more": {}}}"#, events.join(","), has_more)
+    }
+
+    fn client(base_url: &str) -> StripeClient {
+        StripeClient::builder(Auth::new(String::new(), String::new()))
+            .base_url(base_url)
+            .retry_policy(RetryPolicy::none())
+            .build()
+            .unwrap()
+    }
+
+    // Answers one connection per canned body and sends back the request line of each.
+    fn serve(bodies: Vec<String>) -> (String, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/v1", listener.local_addr().unwrap());
+        let (requests, received) = mpsc::channel();
+        thread::spawn(move || {
+            for body in bodies {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 2 {
+                    line.clear();
+                }
+                requests.send(request_line.trim().to_string()).unwrap();
+                write!(stream, "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", body.len(), body).unwrap();
+            }
+        });
+        (base_url, received)
+    }
+
+    // Records the ids of the dispatched events and fails on `fail_on`.
+    fn recording(fail_on: &'static str) -> (EventDispatcher, Arc<Mutex<Vec<String>>>) {
+        let dispatched = Arc::new(Mutex::new(Vec::new()));
+        let recorded = dispatched.clone();
+        let dispatcher = EventDispatcher::new().fallback(move |event: Event| {
+            let id = event.id.unwrap();
+            if id == fail_on {
+                return Err("handler failed".into());
+            }
+            recorded.lock().unwrap().push(id);
+            Ok(())
+        });
+        (dispatcher, dispatched)
+    }
+
+    #[test]
+    fn replays_oldest_first_from_the_event_before_the_checkpoint() {
+        let undecodable = r#"{"id": "evt_2", "object": "event", "created": 101, "request": "req_1", "type": "customer.created"}"#.to_string();
+        let (base_url, requests) = serve(vec![
+            page(&[listed("evt_0", 99)], true),
+            page(&[listed("evt_3", 102), undecodable, listed("evt_1", 100)], false),
+        ]);
+        let store = MemoryEventStore::new();
+        store.set_checkpoint(100).unwrap();
+        let (dispatcher, dispatched) = recording("");
+
+        assert_eq!(dispatcher.replay(&client(&base_url), &store).unwrap(), 2);
+        assert_eq!(*dispatched.lock().unwrap(), ["evt_1", "evt_3"]);
+        assert_eq!(store.checkpoint().unwrap(), Some(102));
+
+        let anchor = requests.recv().unwrap();
+        assert!(anchor.contains("created%5Blt%5D=100") && anchor.contains("limit=1"), "{}", anchor);
+        let walk = requests.recv().unwrap();
+        assert!(walk.contains("ending_before=evt_0"), "{}", walk);
+    }
+
+    #[test]
+    fn replays_from_the_oldest_listed_event_without_one_before_the_checkpoint() {
+        let (base_url, requests) = serve(vec![
+            page(&[], false),
+            page(&[listed("evt_2", 101), listed("evt_1", 100)], false),
+            page(&[listed("evt_2", 101)], false),
+        ]);
+        let store = MemoryEventStore::new();
+        store.set_checkpoint(100).unwrap();
+        let (dispatcher, dispatched) = recording("");
+
+        assert_eq!(dispatcher.replay(&client(&base_url), &store).unwrap(), 2);
+        assert_eq!(*dispatched.lock().unwrap(), ["evt_1", "evt_2"]);
+        assert_eq!(store.checkpoint().unwrap(), Some(101));
+
+        requests.recv().unwrap();
+        let since = requests.recv().unwrap();
+        assert!(since.contains("created%5Bgte%5D=100"), "{}", since);
+        let walk = requests.recv().unwrap();
+        assert!(walk.contains("ending_before=evt_1"), "{}", walk);
+    }
+
+    #[test]
+    fn keeps_the_checkpoint_behind_a_failed_handler() {
+        let (base_url, _requests) = serve(vec![
+            page(&[listed("evt_0", 99)], true),
+            page(&[listed("evt_3", 102), listed("evt_2", 101), listed("evt_1", 100)], false),
+        ]);
+        let store = MemoryEventStore::new();
+        store.set_checkpoint(99).unwrap();
+        let (dispatcher, dispatched) = recording("evt_2");
+
+        assert!(matches!(dispatcher.replay(&client(&base_url), &store), Err(DispatchError::Handler(_))));
+        assert_eq!(*dispatched.lock().unwrap(), ["evt_1"]);
+        assert_eq!(store.checkpoint().unwrap(), Some(100));
+        assert!(store.insert("evt_2", 101).unwrap());
+    }
+
+    #[tokio::test]
+    async fn replays_asynchronously_oldest_first() {
+        let (base_url, _requests) = serve(vec![
+            page(&[listed("evt_0", 99)], true),
+            page(&[listed("evt_1", 100)], true),
+            page(&[listed("evt_2", 101)], false),
+        ]);
+        let store = MemoryEventStore::new();
+        store.set_checkpoint(100).unwrap();
+        let dispatched = Arc::new(Mutex::new(Vec::new()));
+        let recorded = dispatched.clone();
+        let dispatcher = AsyncEventDispatcher::new().fallback(move |event: Event| {
+            recorded.lock().unwrap().push(event.id.unwrap());
+            async { Ok(()) }
+        });
+
+        assert_eq!(dispatcher.replay(&client(&base_url), &store).await.unwrap(), 2);
+        assert_eq!(*dispatched.lock().unwrap(), ["evt_1", "evt_2"]);
+        assert_eq!(store.checkpoint().unwrap(), Some(101));
+    }
+}
@@ -0,0 +1,155 @@
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+
+use std::fmt;
+
+/// The details Stripe returns in the `error` object of a failed request.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ApiError {
+    /// The type of error returned. One of api_error, card_error, idempotency_error, or invalid_request_error
+    #[serde(rename = "type")]
+    pub type_field: Option<String>,
+    /// For some errors that could be handled programmatically, a short string indicating the error code reported.
+    pub code: Option<String>,
+    /// For card errors resulting from a card issuer decline, a short string indicating the card issuer's reason for the decline.
+    pub decline_code: Option<String>,
+    /// If the error is parameter-specific, the parameter related to the error.
+    pub param: Option<String>,
+    /// A human-readable message providing more details about the error.
+    pub message: Option<String>,
+    /// A URL to more information about the error code reported.
+    pub doc_url: Option<String>,
+    /// The HTTP status code of the response that carried the error, 0 when the request was not sent.
+    #[serde(skip)]
+    pub status: u16,
+}
+
+/// Errors returned by every call made to the Stripe API.
+#[derive(Debug)]
+pub enum StripeError {
+    /// The card could not be charged (declined, expired, incorrect cvc, ...).
+    CardError(Box<ApiError>),
+    /// The request had invalid parameters or referenced a missing object.
+    InvalidRequest(Box<ApiError>),
+    /// The API key was missing, invalid or lacked the required permissions.
+    Authentication(Box<ApiError>),
+    /// Too many requests hit the API too quickly.
+    RateLimit(Box<ApiError>),
+    /// An idempotency key was re-used with different parameters.
+    Idempotency(Box<ApiError>),
+    /// Any other error reported by Stripe, including problems on Stripe's end.
+    Api(Box<ApiError>),
+    /// The request could not be sent or the response could not be read.
+    Transport(reqwest::Error),
+    /// The response body could not be deserialized into the expected type.
+    Decode(serde_json::Error),
+    /// The request parameters could not be form encoded.
+    Encode(serde_json::Error),
+}
+
+impl StripeError {
+    /// Returns the Stripe error details when the error was reported by the API.
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            StripeError::CardError(err)
+            | StripeError::InvalidRequest(err)
+            | StripeError::Authentication(err)
+            | StripeError::RateLimit(err)
+            | StripeError::Idempotency(err)
+            | StripeError::Api(err) => Some(err.as_ref()),
+            StripeError::Transport(_) | StripeError::Decode(_) | StripeError::Encode(_) => None,
+        }
+    }
+
+    // A field the request needs, e.g. the id of the object, is not set, so it was not sent to Stripe.
+    pub(crate) fn missing(param: &str) -> Self {
+        StripeError::InvalidRequest(Box::new(ApiError {
+            type_field: Some("invalid_request_error".to_string()),
+            param: Some(param.to_string()),
+            message: Some(format!("{} is not set", param)),
+            ..ApiError::default()
+        }))
+    }
+
+    fn from_api_error(err: ApiError) -> Self {
+        let variant: fn(Box<ApiError>) -> Self = match (err.status, err.type_field.as_deref()) {
+            (401, _) | (403, _) => StripeError::Authentication,
+            (429, _) => StripeError::RateLimit,
+            (_, Some("card_error")) => StripeError::CardError,
+            (_, Some("idempotency_error")) => StripeError::Idempotency,
+            (_, Some("invalid_request_error")) => StripeError::InvalidRequest,
+            _ => StripeError::Api,
+        };
+        variant(Box::new(err))
+    }
+}
+
+impl fmt::Display for StripeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            StripeError::CardError(_) => "card error",
+            StripeError::InvalidRequest(_) => "invalid request",
+            StripeError::Authentication(_) => "authentication error",
+            StripeError::RateLimit(_) => "rate limit exceeded",
+            StripeError::Idempotency(_) => "idempotency error",
+            StripeError::Api(_) => "api error",
+            StripeError::Transport(err) => return write!(f, "stripe transport error: {}", err),
+            StripeError::Decode(err) => return write!(f, "could not decode stripe response: {}", err),
+            StripeError::Encode(err) => return write!(f, "could not encode stripe parameters: {}", err),
+        };
+        let err = self.api_error().cloned().unwrap_or_default();
+        write!(f, "stripe {} (status {})", kind, err.status)?;
+        if let Some(code) = &err.code {
+            write!(f, " [{}]", code)?;
+        }
+        if let Some(message) = &err.message {
+            write!(f, ": {}", message)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for StripeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StripeError::Transport(err) => Some(err),
+            StripeError::Decode(err) => Some(err),
+            StripeError::Encode(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for StripeError {
+    fn from(err: reqwest::Error) -> Self {
+        StripeError::Transport(err)
+    }
+}
+
+impl From<serde_json::Error> for StripeError {
+    fn from(err: serde_json::Error) -> Self {
+        StripeError::Decode(err)
+    }
+}
+
+#[derive(Deserialize)]
+struct ErrorEnvelope {
+    error: ApiError,
+}
+
+/// Turns the status and body of a Stripe response into either the expected object or a StripeError.
+pub(crate) fn parse<T: DeserializeOwned>(status: u16, body: &str) -> Result<T, StripeError> {
+    if (200..300).contains(&status) {
+        return Ok(serde_json::from_str::<T>(body)?);
+    }
+
+    let mut err = match serde_json::from_str::<ErrorEnvelope>(body) {
+        Ok(envelope) => envelope.error,
+        Err(_) => ApiError {
+            message: Some(body.to_string()),
+            ..ApiError::default()
+        },
+    };
+    err.status = status;
+    Err(StripeError::from_api_error(err))
+}
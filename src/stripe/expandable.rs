@@ -0,0 +1,78 @@
+use serde::{Serialize, Deserialize};
+
+use crate::stripe::list::Object;
+
+/// A reference to another Stripe object: its id, or the whole object when the field was expanded.
+///
+/// Fields are only expanded when asked for with `StripeClient::with_expand`, otherwise Stripe sends
+/// the id alone.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> Result<(), payup::stripe::StripeError> {
+/// # let (client, secret) = (String::new(), String::new());
+/// let auth = payup::stripe::Auth::new(client, secret);
+/// let client = payup::stripe::StripeClient::new(auth);
+///
+/// // Fetch the charge together with its customer in one round trip
+/// let charge = payup::stripe::Charge::get(&client.with_expand(["customer"]), format!("ch_"))?;
+/// if let Some(customer) = charge.customer.as_ref().and_then(|customer| customer.as_object()) {
+///     println!("charged {:?}", customer.email);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum Expandable<T> {
+    /// The id of the referenced object.
+    Id(String),
+    /// The referenced object, sent when the field was expanded.
+    Object(Box<T>),
+}
+
+impl<T: Object> Expandable<T> {
+    /// The id of the referenced object, whether or not it was expanded.
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            Expandable::Id(id) => Some(id),
+            Expandable::Object(object) => object.object_id(),
+        }
+    }
+}
+
+impl<T> Expandable<T> {
+    /// Whether the whole object was sent.
+    pub fn is_object(&self) -> bool {
+        matches!(self, Expandable::Object(_))
+    }
+
+    /// The referenced object if the field was expanded.
+    pub fn as_object(&self) -> Option<&T> {
+        match self {
+            Expandable::Id(_) => None,
+            Expandable::Object(object) => Some(object),
+        }
+    }
+
+    /// Takes the referenced object if the field was expanded.
+    pub fn into_object(self) -> Option<T> {
+        match self {
+            Expandable::Id(_) => None,
+            Expandable::Object(object) => Some(*object),
+        }
+    }
+}
+
+impl<T> From<String> for Expandable<T> {
+    fn from(id: String) -> Self {
+        Expandable::Id(id)
+    }
+}
+
+impl<T> From<&str> for Expandable<T> {
+    fn from(id: &str) -> Self {
+        Expandable::Id(id.to_string())
+    }
+}
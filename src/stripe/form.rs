@@ -0,0 +1,97 @@
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+
+use crate::stripe::StripeError;
+
+/// Encodes `params` into the bracketed form pairs the Stripe API expects.
+///
+/// Nested objects become `parent[child]`, arrays become `items[0][price]`, maps become
+/// `metadata[key]` and booleans `true`/`false`. Fields that are `None` and empty arrays are left out, so
+/// a struct of `Option`s only sends what was set and an object that was fetched can be sent back without
+/// clearing its lists. Use `Clear` to clear a list on purpose.
+///
+/// # Examples
+///
+/// ```
+/// #[derive(serde::Serialize)]
+/// struct Item {
+///     price: String,
+///     quantity: Option<u64>,
+/// }
+///
+/// #[derive(serde::Serialize)]
+/// struct Params {
+///     customer: String,
+///     items: Vec<Item>,
+///     tax_rates: Vec<String>,
+///     default_tax_rates: Option<payup::stripe::form::Clear>,
+///     cancel_at_period_end: Option<bool>,
+/// }
+///
+/// let params = Params {
+///     customer: format!("cus_"),
+///     items: vec![
+///         Item { price: format!("price_a"), quantity: None },
+///         Item { price: format!("price_b"), quantity: Some(2) },
+///     ],
+///     tax_rates: vec![],
+///     default_tax_rates: Some(payup::stripe::form::Clear),
+///     cancel_at_period_end: Some(true),
+/// };
+///
+/// let pairs = payup::stripe::form::to_pairs(&params)?;
+/// assert_eq!(pairs, vec![
+///     (format!("cancel_at_period_end"), format!("true")),
+///     (format!("customer"), format!("cus_")),
+///     (format!("default_tax_rates"), format!("")),
+///     (format!("items[0][price]"), format!("price_a")),
+///     (format!("items[1][price]"), format!("price_b")),
+///     (format!("items[1][quantity]"), format!("2")),
+/// ]);
+/// # Ok::<(), payup::stripe::StripeError>(())
+/// ```
+pub fn to_pairs<T: Serialize + ?Sized>(params: &T) -> Result<Vec<(String, String)>, StripeError> {
+    let mut pairs = Vec::new();
+    match serde_json::to_value(params).map_err(StripeError::Encode)? {
+        Value::Object(map) => {
+            for (key, value) in map {
+                flatten(&mut pairs, key, value);
+            }
+        }
+        Value::Null => {}
+        _ => {
+            let err = <serde_json::Error as serde::ser::Error>::custom("stripe parameters must be a struct or a map");
+            return Err(StripeError::Encode(err));
+        }
+    }
+    Ok(pairs)
+}
+
+fn flatten(pairs: &mut Vec<(String, String)>, key: String, value: Value) {
+    match value {
+        Value::Null => {}
+        Value::Bool(value) => pairs.push((key, value.to_string())),
+        Value::Number(value) => pairs.push((key, value.to_string())),
+        Value::String(value) => pairs.push((key, value)),
+        Value::Array(items) => {
+            for (index, item) in items.into_iter().enumerate() {
+                flatten(pairs, format!("{}[{}]", key, index), item);
+            }
+        }
+        Value::Object(map) => {
+            for (child, value) in map {
+                flatten(pairs, format!("{}[{}]", key, child), value);
+            }
+        }
+    }
+}
+
+/// A param that is sent as `key=`, which is how Stripe clears a list or unsets a field, see `to_pairs`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Clear;
+
+impl Serialize for Clear {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str("")
+    }
+}
@@ -0,0 +1,418 @@
+use futures_util::stream::{self, Stream};
+use serde::de::{DeserializeOwned, Deserializer};
+use serde::{Serialize, Deserialize};
+
+use std::collections::VecDeque;
+use std::pin::Pin;
+
+use crate::stripe::{form, StripeClient, StripeError};
+
+/// A Stripe object whose id can be used as a pagination cursor.
+pub trait Object {
+    /// The id of the object, e.g. `ch_3KtXbBGrEH09RU9u0rpxUqVH`.
+    fn object_id(&self) -> Option<&str>;
+}
+
+/// A filter on a timestamp or amount, e.g. the `created` date of the listed objects.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct RangeQuery {
+    /// Only objects where the value is greater than this.
+    pub gt: Option<i64>,
+    /// Only objects where the value is greater than or equal to this.
+    pub gte: Option<i64>,
+    /// Only objects where the value is less than this.
+    pub lt: Option<i64>,
+    /// Only objects where the value is less than or equal to this.
+    pub lte: Option<i64>,
+}
+
+impl RangeQuery {
+    pub fn new() -> Self {
+        RangeQuery::default()
+    }
+
+    /// Matches values between `start` and `end`, both included.
+    pub fn between(start: i64, end: i64) -> Self {
+        RangeQuery {
+            gte: Some(start),
+            lte: Some(end),
+            ..RangeQuery::default()
+        }
+    }
+}
+
+/// A query for the search endpoints, e.g. all charges with `metadata['order_id']:'6735'`.
+///
+/// See <https://stripe.com/docs/search#search-query-language> for the query language. Search results
+/// can lag behind writes by up to a minute.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SearchParams {
+    /// The search query, e.g. `status:'succeeded' AND metadata['order_id']:'6735'`.
+    pub query: String,
+    /// Number of objects fetched per page, between 1 and 100.
+    pub limit: Option<u32>,
+    /// The `next_page` cursor of an earlier search to continue from.
+    pub page: Option<String>,
+}
+
+impl SearchParams {
+    pub fn new(query: impl Into<String>) -> Self {
+        SearchParams {
+            query: query.into(),
+            ..SearchParams::default()
+        }
+    }
+
+    /// Searches for the objects whose metadata `key` is set to `value`.
+    pub fn metadata(key: &str, value: &str) -> Self {
+        SearchParams::new(format!("metadata['{}']:'{}'", escape(key), escape(value)))
+    }
+}
+
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('\'', "\\'")
+}
+
+/// Returns a blocking iterator over the list at `path`.
+///
+/// `starting_after` and `ending_before` in the params are taken as the first cursor, every other
+/// param is sent unchanged with each page request.
+pub(crate) fn iter<T: DeserializeOwned + Object, P: Serialize + ?Sized>(client: &StripeClient, path: &str, params: &P) -> ListIter<T> {
+    ListIter {
+        pager: Pager::new(client, path, form::to_pairs(params), Cursor::After(None)),
+        buffer: VecDeque::new(),
+    }
+}
+
+/// Returns a blocking iterator over the results of the search endpoint at `path`.
+pub(crate) fn search<T: DeserializeOwned + Object>(client: &StripeClient, path: &str, params: &SearchParams) -> ListIter<T> {
+    ListIter {
+        pager: Pager::new(client, path, form::to_pairs(params), Cursor::Page(None)),
+        buffer: VecDeque::new(),
+    }
+}
+
+/// Returns a Stream over the list at `path`, see `iter`.
+pub(crate) fn stream<T: DeserializeOwned + Object + Send + 'static, P: Serialize + ?Sized>(client: &StripeClient, path: &str, params: &P) -> ListStream<T> {
+    stream_pages(Pager::new(client, path, form::to_pairs(params), Cursor::After(None)))
+}
+
+/// Returns a Stream over the results of the search endpoint at `path`.
+pub(crate) fn search_stream<T: DeserializeOwned + Object + Send + 'static>(client: &StripeClient, path: &str, params: &SearchParams) -> ListStream<T> {
+    stream_pages(Pager::new(client, path, form::to_pairs(params), Cursor::Page(None)))
+}
+
+/// Deserializes a list embedded in another object, e.g. the `items` of a subscription, into its `data`.
+///
+/// A plain array is taken as well, so structs serialized by this crate read back the same way.
+pub(crate) fn data<'de, D: Deserializer<'de>, T: Deserialize<'de>>(deserializer: D) -> Result<Vec<T>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Embedded<T> {
+        List { data: Vec<T> },
+        Array(Vec<T>),
+    }
+
+    Ok(match Option::<Embedded<T>>::deserialize(deserializer)? {
+        Some(Embedded::List { data }) | Some(Embedded::Array(data)) => data,
+        None => Vec::new(),
+    })
+}
+
+fn stream_pages<T: DeserializeOwned + Object + Send + 'static>(pager: Pager) -> ListStream<T> {
+    Box::pin(stream::unfold((pager, VecDeque::new()), |(mut pager, mut buffer)| async move {
+        if let Some(err) = pager.error.take() {
+            return Some((Err(err), (pager, buffer)));
+        }
+        while buffer.is_empty() && !pager.done {
+            match pager.client.async_get_query::<Page<T>, _>(&pager.path, &pager.query()).await {
+                Ok(page) => buffer.extend(pager.advance(page)),
+                Err(err) => {
+                    pager.done = true;
+                    return Some((Err(err), (pager, buffer)));
+                }
+            }
+        }
+        let object = buffer.pop_front()?;
+        Some((Ok(object), (pager, buffer)))
+    }))
+}
+
+/// Lazily walks a Stripe list, fetching the next page only once the current one is used up.
+///
+/// Stops after the first error.
+pub struct ListIter<T> {
+    pager: Pager,
+    buffer: VecDeque<T>,
+}
+
+/// Lazily walks a Stripe list from async code, fetching the next page only once the current one is used up.
+///
+/// Stops after the first error.
+pub type ListStream<T> = Pin<Box<dyn Stream<Item = Result<T, StripeError>> + Send>>;
+
+impl<T: DeserializeOwned + Object> Iterator for ListIter<T> {
+    type Item = Result<T, StripeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(err) = self.pager.error.take() {
+            return Some(Err(err));
+        }
+        while self.buffer.is_empty() && !self.pager.done {
+            match self.pager.client.get_query::<Page<T>, _>(&self.pager.path, &self.pager.query()) {
+                Ok(page) => self.buffer.extend(self.pager.advance(page)),
+                Err(err) => {
+                    self.pager.done = true;
+                    return Some(Err(err));
+                }
+            }
+        }
+        self.buffer.pop_front().map(Ok)
+    }
+}
+
+#[derive(Deserialize)]
+struct Page<T> {
+    data: Vec<T>,
+    has_more: bool,
+    #[serde(default)]
+    next_page: Option<String>,
+}
+
+// Walking forwards uses the last id of a page as the next `starting_after`, walking backwards uses
+// the first id as the next `ending_before`. Search results carry their own `next_page` token.
+enum Cursor {
+    After(Option<String>),
+    Before(Option<String>),
+    Page(Option<String>),
+}
+
+struct Pager {
+    client: StripeClient,
+    path: String,
+    query: Vec<(String, String)>,
+    cursor: Cursor,
+    done: bool,
+    // Set when the params could not be encoded, returned instead of the first page.
+    error: Option<StripeError>,
+}
+
+impl Pager {
+    fn new(client: &StripeClient, path: &str, query: Result<Vec<(String, String)>, StripeError>, mut cursor: Cursor) -> Self {
+        let (mut query, error) = match query {
+            Ok(query) => (query, None),
+            Err(err) => (Vec::new(), Some(err)),
+        };
+        query.retain(|(key, value)| match key.as_str() {
+            "page" => {
+                cursor = Cursor::Page(Some(value.clone()));
+                false
+            }
+            "starting_after" => {
+                if let Cursor::After(None) = cursor {
+                    cursor = Cursor::After(Some(value.clone()));
+                }
+                false
+            }
+            "ending_before" => {
+                cursor = Cursor::Before(Some(value.clone()));
+                false
+            }
+            _ => true,
+        });
+
+        Pager {
+            client: client.clone(),
+            path: path.to_string(),
+            query,
+            cursor,
+            done: error.is_some(),
+            error,
+        }
+    }
+
+    fn query(&self) -> Vec<(String, String)> {
+        let mut query = self.query.clone();
+        match &self.cursor {
+            Cursor::After(Some(id)) => query.push(("starting_after".to_string(), id.clone())),
+            Cursor::Before(Some(id)) => query.push(("ending_before".to_string(), id.clone())),
+            Cursor::Page(Some(page)) => query.push(("page".to_string(), page.clone())),
+            Cursor::After(None) | Cursor::Before(None) | Cursor::Page(None) => {}
+        }
+        query
+    }
+
+    // Moves the cursor past the page and returns its objects in the order they are walked.
+    fn advance<T: Object>(&mut self, page: Page<T>) -> Vec<T> {
+        let mut data = page.data;
+        let next = match self.cursor {
+            Cursor::After(_) => data.last().and_then(|object| object.object_id()).map(|id| id.to_string()),
+            Cursor::Before(_) => data.first().and_then(|object| object.object_id()).map(|id| id.to_string()),
+            Cursor::Page(_) => page.next_page,
+        };
+
+        self.done = !page.has_more || next.is_none();
+        match &mut self.cursor {
+            Cursor::After(id) | Cursor::Page(id) => *id = next,
+            Cursor::Before(id) => {
+                *id = next;
+                data.reverse();
+            }
+        }
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{self, Receiver};
+    use std::thread;
+
+    use crate::stripe::Auth;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Item {
+        id: Option<String>,
+    }
+
+    impl Object for Item {
+        fn object_id(&self) -> Option<&str> {
+            self.id.as_deref()
+        }
+    }
+
+    fn page(ids: &[&str], has_more: bool) -> Page<Item> {
+        Page {
+            data: ids.iter().map(|id| Item { id: Some(id.to_string()) }).collect(),
+            has_more,
+            next_page: None,
+        }
+    }
+
+    fn ids(items: &[Item]) -> Vec<&str> {
+        items.iter().filter_map(|item| item.object_id()).collect()
+    }
+
+    fn client(base_url: &str) -> StripeClient {
+        StripeClient::builder(Auth::new(String::new(), String::new()))
+            .base_url(base_url)
+            .retry_policy(crate::stripe::RetryPolicy::none())
+            .build()
+            .unwrap()
+    }
+
+    fn pager(params: &[(&str, &str)]) -> Pager {
+        let params = params.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect();
+        Pager::new(&client("http://127.0.0.1:1/v1"), "/items", Ok(params), Cursor::After(None))
+    }
+
+    // Answers one connection per canned body and sends back the request line of each.
+    fn serve(bodies: Vec<&'static str>) -> (String, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/v1", listener.local_addr().unwrap());
+        let (requests, received) = mpsc::channel();
+        thread::spawn(move || {
+            for body in bodies {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 2 {
+                    line.clear();
+                }
+                requests.send(request_line.trim().to_string()).unwrap();
+                write!(stream, "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", body.len(), body).unwrap();
+            }
+        });
+        (base_url, received)
+    }
+
+    #[test]
+    fn takes_the_first_cursor_from_the_params() {
+        let pager = pager(&[("limit", "2"), ("starting_after", "it_1")]);
+        assert_eq!(pager.query(), vec![("limit".to_string(), "2".to_string()), ("starting_after".to_string(), "it_1".to_string())]);
+
+        let pager = self::pager(&[("ending_before", "it_9")]);
+        assert!(matches!(pager.cursor, Cursor::Before(Some(ref id)) if id == "it_9"));
+
+        let pager = self::pager(&[("page", "token")]);
+        assert!(matches!(pager.cursor, Cursor::Page(Some(ref page)) if page == "token"));
+    }
+
+    #[test]
+    fn walks_forwards_after_the_last_id() {
+        let mut pager = pager(&[]);
+        let items = pager.advance(page(&["it_3", "it_2"], true));
+        assert_eq!(ids(&items), vec!["it_3", "it_2"]);
+        assert!(matches!(pager.cursor, Cursor::After(Some(ref id)) if id == "it_2"));
+        assert!(!pager.done);
+
+        pager.advance(page(&["it_1"], false));
+        assert!(pager.done);
+    }
+
+    #[test]
+    fn walks_backwards_before_the_first_id_oldest_first() {
+        let mut pager = pager(&[("ending_before", "it_1")]);
+        let items = pager.advance(page(&["it_3", "it_2"], true));
+        assert_eq!(ids(&items), vec!["it_2", "it_3"]);
+        assert!(matches!(pager.cursor, Cursor::Before(Some(ref id)) if id == "it_3"));
+        assert!(!pager.done);
+    }
+
+    #[test]
+    fn follows_the_next_page_token_of_a_search() {
+        let mut pager = pager(&[("page", "first")]);
+        let mut first = page(&["it_1"], true);
+        first.next_page = Some("second".to_string());
+        pager.advance(first);
+        assert!(matches!(pager.cursor, Cursor::Page(Some(ref page)) if page == "second"));
+
+        pager.advance(page(&["it_2"], false));
+        assert!(pager.done);
+    }
+
+    #[test]
+    fn stops_on_an_empty_page() {
+        let mut pager = pager(&[]);
+        assert!(pager.advance(page(&[], true)).is_empty());
+        assert!(pager.done);
+    }
+
+    #[test]
+    fn iterates_over_every_page_until_has_more_is_false() {
+        let (base_url, requests) = serve(vec![
+            r#"{"object": "list", "data": [{"id": "it_3"}, {"id": "it_2"}], "has_more": true}"#,
+            r#"{"object": "list", "data": [{"id": "it_1"}], "has_more": false}"#,
+        ]);
+        let items: Vec<Item> = iter(&client(&base_url), "/items", &serde_json::json!({"limit": 2})).collect::<Result<_, _>>().unwrap();
+        assert_eq!(ids(&items), vec!["it_3", "it_2", "it_1"]);
+        assert_eq!(requests.recv().unwrap(), "GET /v1/items?limit=2 HTTP/1.1");
+        assert_eq!(requests.recv().unwrap(), "GET /v1/items?limit=2&starting_after=it_2 HTTP/1.1");
+    }
+
+    #[test]
+    fn an_empty_list_yields_nothing() {
+        let (base_url, _requests) = serve(vec![r#"{"object": "list", "data": [], "has_more": false}"#]);
+        assert_eq!(iter::<Item, _>(&client(&base_url), "/items", &serde_json::json!({"limit": 2})).count(), 0);
+    }
+
+    #[tokio::test]
+    async fn streams_every_page_until_has_more_is_false() {
+        use futures_util::TryStreamExt;
+
+        let (base_url, requests) = serve(vec![
+            r#"{"object": "list", "data": [{"id": "it_2"}], "has_more": true}"#,
+            r#"{"object": "list", "data": [{"id": "it_1"}], "has_more": false}"#,
+        ]);
+        let items: Vec<Item> = stream(&client(&base_url), "/items", &serde_json::json!({"limit": 1})).try_collect().await.unwrap();
+        assert_eq!(ids(&items), vec!["it_2", "it_1"]);
+        assert_eq!(requests.recv().unwrap(), "GET /v1/items?limit=1 HTTP/1.1");
+        assert_eq!(requests.recv().unwrap(), "GET /v1/items?limit=1&starting_after=it_2 HTTP/1.1");
+    }
+}
@@ -2,6 +2,11 @@ use serde_derive::Deserialize;
 use serde_derive::Serialize;
 use serde_json::Value;
 
+use std::collections::HashMap;
+
+use crate::stripe::list::Object;
+use crate::stripe::{Discount, Expandable};
+
 
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -18,14 +23,15 @@ pub struct Customer {
     // pub default_source: Value,
     // pub delinquent: bool,
     pub description: Option<String>,
-    // pub discount: Value,
+    pub discount: Option<Discount>,
     pub email: Option<String>,
     // #[serde(rename = "invoice_prefix")]
     // pub invoice_prefix: String,
     // #[serde(rename = "invoice_settings")]
     // pub invoice_settings: InvoiceSettings,
     // pub livemode: bool,
-    // pub metadata: Metadata,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
     pub name: Option<String>,
     // #[serde(rename = "next_invoice_sequence")]
     // pub next_invoice_sequence: i64,
@@ -37,6 +43,12 @@ pub struct Customer {
     // pub tax_exempt: String,
 }
 
+impl Object for Customer {
+    fn object_id(&self) -> Option<&str> {
+        Some(&self.id)
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct InvoiceSettings {
@@ -78,7 +90,8 @@ pub struct Plan {
     #[serde(rename = "interval_count")]
     pub interval_count: i64,
     pub livemode: bool,
-    // pub metadata: Metadata,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
     // pub nickname: Value,
     pub product: String,
     // #[serde(rename = "tiers_mode")]
@@ -89,13 +102,20 @@ pub struct Plan {
     // pub trial_period_days: Value,
     #[serde(rename = "usage_type")]
     pub usage_type: String,
+    /// Set when Stripe answered with the stored result of an earlier request with the same idempotency key.
+    #[serde(skip_serializing)]
+    pub idempotent_replayed: Option<bool>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Metadata {
+impl Object for Plan {
+    fn object_id(&self) -> Option<&str> {
+        Some(&self.id)
+    }
 }
 
+/// Key-value pairs attached to a Stripe object.
+pub type Metadata = HashMap<String, String>;
+
 
 
 
@@ -180,9 +200,19 @@ pub struct PaymentMethod {
     pub created: i64,
     pub customer: Option<String>,
     pub livemode: bool,
+    #[serde(default)]
     pub metadata: Metadata,
     #[serde(rename = "type")]
     pub type_field: String,
+    /// Set when Stripe answered with the stored result of an earlier request with the same idempotency key.
+    #[serde(skip_serializing)]
+    pub idempotent_replayed: Option<bool>,
+}
+
+impl Object for PaymentMethod {
+    fn object_id(&self) -> Option<&str> {
+        Some(&self.id)
+    }
 }
 
 
@@ -241,7 +271,7 @@ pub struct Subscription {
     pub current_period_end: Option<i64>,
     #[serde(rename = "current_period_start")]
     pub current_period_start: Option<i64>,
-    pub customer: Option<String>,
+    pub customer: Option<Expandable<Customer>>,
     #[serde(rename = "days_until_due")]
     pub days_until_due: Option<i64>,
     #[serde(rename = "default_payment_method")]
@@ -250,7 +280,7 @@ pub struct Subscription {
     pub ended_at: Option<i64>,
     pub items: Option<SubscriptionItems>,
     #[serde(rename = "latest_invoice")]
-    pub latest_invoice: Option<String>,
+    pub latest_invoice: Option<Expandable<Invoice>>,
     pub livemode: Option<bool>,
     // #[serde(rename = "payment_settings")]
     // pub payment_settings: PaymentSettings,
@@ -264,8 +294,9 @@ pub struct Subscription {
     // pub default_source: Value,
     // #[serde(rename = "default_tax_rates")]
     // pub default_tax_rates: Vec<Value>,
-    // pub discount: Value,
-    // pub metadata: Metadata4,
+    pub discount: Option<Discount>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
     // #[serde(rename = "next_pending_invoice_item_invoice")]
     // pub next_pending_invoice_item_invoice: Value,
     // #[serde(rename = "pause_collection")]
@@ -284,6 +315,9 @@ pub struct Subscription {
     // pub trial_end: Value,
     // #[serde(rename = "trial_start")]
     // pub trial_start: Value,
+    /// Set when Stripe answered with the stored result of an earlier request with the same idempotency key.
+    #[serde(skip_serializing)]
+    pub idempotent_replayed: Option<bool>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -306,7 +340,8 @@ pub struct SubscriptionItem {
     // #[serde(rename = "billing_thresholds")]
     // pub billing_thresholds: Value,
     pub created: i64,
-    // pub metadata: Metadata,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
     // pub plan: Plan,
     // pub price: Price,
     pub quantity: i64,
@@ -345,7 +380,8 @@ pub struct Price {
     pub livemode: bool,
     // #[serde(rename = "lookup_key")]
     // pub lookup_key: Value,
-    // pub metadata: Metadata,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
     // pub nickname: Value,
     pub product: String,
     pub recurring: Recurring,
@@ -361,6 +397,9 @@ pub struct Price {
     pub unit_amount: i64,
     #[serde(rename = "unit_amount_decimal")]
     pub unit_amount_decimal: String,
+    /// Set when Stripe answered with the stored result of an earlier request with the same idempotency key.
+    #[serde(skip_serializing)]
+    pub idempotent_replayed: Option<bool>,
 }
 
 
@@ -462,7 +501,8 @@ pub struct Invoice {
     pub last_finalization_error: Value,
     pub lines: InvoiceLines,
     pub livemode: bool,
-    // pub metadata: Metadata4,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
     #[serde(rename = "next_payment_attempt")]
     pub next_payment_attempt: Value,
     pub number: String,
@@ -507,6 +547,12 @@ pub struct Invoice {
     pub webhooks_delivered_at: i64,
 }
 
+impl Object for Invoice {
+    fn object_id(&self) -> Option<&str> {
+        Some(&self.id)
+    }
+}
+
 
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -534,7 +580,8 @@ pub struct InvoiceLine {
     pub discountable: bool,
     pub discounts: Vec<Value>,
     pub livemode: bool,
-    // pub metadata: Metadata,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
     pub period: Period,
     pub plan: Plan,
     pub price: Price,
@@ -0,0 +1,208 @@
+use reqwest::header::HeaderMap;
+use reqwest::Method;
+
+use std::time::Duration;
+
+use crate::stripe::client::IDEMPOTENCY_KEY;
+
+const RETRY_AFTER: &str = "Retry-After";
+const STRIPE_SHOULD_RETRY: &str = "Stripe-Should-Retry";
+
+// Longest Retry-After that is honored, anything above falls back to the regular backoff.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// Controls how a StripeClient retries requests that failed for a transient reason.
+///
+/// Only GET requests and requests carrying an `Idempotency-Key` are retried, so a retry can never
+/// create a second charge. Connection errors, timeouts, `409`, `429` and `5xx` responses are retried
+/// unless Stripe answers with `Stripe-Should-Retry: false`, and a `Retry-After` header overrides the
+/// computed backoff.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> Result<(), payup::stripe::StripeError> {
+/// # let (client, secret) = (String::new(), String::new());
+/// let auth = payup::stripe::Auth::new(client, secret);
+///
+/// let mut retry = payup::stripe::RetryPolicy::default();
+/// retry.max_attempts = 5;
+///
+/// let client = payup::stripe::StripeClient::builder(auth)
+///     .retry_policy(retry)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Total number of attempts made for a request, including the first one.
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled for every following retry.
+    pub initial_backoff: Duration,
+    /// Upper bound for the delay between two attempts.
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(8),
+        }
+    }
+}
+
+/// What came back from a single attempt.
+pub(crate) enum Outcome<'a> {
+    Response(u16, &'a HeaderMap),
+    Failed(&'a reqwest::Error),
+}
+
+impl RetryPolicy {
+    /// Returns a policy that sends every request exactly once.
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            ..RetryPolicy::default()
+        }
+    }
+
+    /// Whether a request may be sent again after the given attempt failed.
+    pub(crate) fn allows(&self, attempt: u32, method: &Method, headers: &HeaderMap) -> bool {
+        attempt < self.max_attempts && (method == Method::GET || headers.contains_key(IDEMPOTENCY_KEY))
+    }
+
+    /// Returns how long to wait before the next attempt, or None when the outcome should be returned as is.
+    pub(crate) fn delay(&self, attempt: u32, outcome: Outcome<'_>) -> Option<Duration> {
+        match outcome {
+            Outcome::Failed(err) => {
+                if !(err.is_connect() || err.is_timeout() || err.is_request()) {
+                    return None;
+                }
+                Some(self.backoff(attempt))
+            }
+            Outcome::Response(status, headers) => {
+                match header(headers, STRIPE_SHOULD_RETRY) {
+                    Some("true") => {}
+                    Some("false") => return None,
+                    _ => {
+                        if !(status == 409 || status == 429 || status >= 500) {
+                            return None;
+                        }
+                    }
+                }
+                let retry_after = header(headers, RETRY_AFTER)
+                    .and_then(|value| value.trim().parse::<u64>().ok())
+                    .map(Duration::from_secs)
+                    .filter(|retry_after| *retry_after <= MAX_RETRY_AFTER);
+                Some(retry_after.unwrap_or_else(|| self.backoff(attempt)))
+            }
+        }
+    }
+
+    // Exponential backoff with jitter, so clients that failed together do not retry together.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16);
+        let backoff = self.initial_backoff.saturating_mul(1 << exponent).min(self.max_backoff);
+        backoff.mul_f64(0.5 + rand::random::<f64>() / 2.0)
+    }
+}
+
+fn header<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use reqwest::header::HeaderValue;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 4,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(300),
+        }
+    }
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, HeaderValue::from_static(value));
+        }
+        headers
+    }
+
+    fn assert_between(delay: Duration, min: u64, max: u64) {
+        assert!(delay >= Duration::from_millis(min) && delay <= Duration::from_millis(max), "{:?} not in {}..={}ms", delay, min, max);
+    }
+
+    #[test]
+    fn backoff_doubles_with_jitter_up_to_the_maximum() {
+        let policy = policy();
+        for _ in 0..100 {
+            assert_between(policy.backoff(1), 50, 100);
+            assert_between(policy.backoff(2), 100, 200);
+            assert_between(policy.backoff(3), 150, 300);
+            assert_between(policy.backoff(30), 150, 300);
+        }
+    }
+
+    #[test]
+    fn retries_conflicts_rate_limits_and_server_errors() {
+        let policy = policy();
+        let none = HeaderMap::new();
+        for status in [409, 429, 500, 502, 503] {
+            assert!(policy.delay(1, Outcome::Response(status, &none)).is_some(), "{} is not retried", status);
+        }
+        for status in [200, 400, 401, 402, 404] {
+            assert_eq!(policy.delay(1, Outcome::Response(status, &none)), None, "{} is retried", status);
+        }
+    }
+
+    #[test]
+    fn stripe_should_retry_overrides_the_status() {
+        let policy = policy();
+        let retry = headers(&[(STRIPE_SHOULD_RETRY, "true")]);
+        let no_retry = headers(&[(STRIPE_SHOULD_RETRY, "false")]);
+        assert!(policy.delay(1, Outcome::Response(400, &retry)).is_some());
+        assert_eq!(policy.delay(1, Outcome::Response(503, &no_retry)), None);
+    }
+
+    #[test]
+    fn retry_after_replaces_the_backoff() {
+        let policy = policy();
+        let retry_after = headers(&[(RETRY_AFTER, "7")]);
+        assert_eq!(policy.delay(1, Outcome::Response(429, &retry_after)), Some(Duration::from_secs(7)));
+
+        // Too long or unparsable values fall back to the backoff
+        for value in ["3600", "soon"] {
+            let retry_after = headers(&[(RETRY_AFTER, value)]);
+            assert_between(policy.delay(1, Outcome::Response(429, &retry_after)).unwrap(), 50, 100);
+        }
+    }
+
+    #[test]
+    fn only_gets_and_idempotent_requests_are_retried() {
+        let policy = policy();
+        let none = HeaderMap::new();
+        let idempotent = headers(&[(IDEMPOTENCY_KEY, "key")]);
+        assert!(policy.allows(1, &Method::GET, &none));
+        assert!(policy.allows(1, &Method::DELETE, &idempotent));
+        assert!(policy.allows(1, &Method::POST, &idempotent));
+        assert!(!policy.allows(1, &Method::POST, &none));
+        assert!(!policy.allows(1, &Method::DELETE, &none));
+    }
+
+    #[test]
+    fn stops_after_max_attempts() {
+        let policy = policy();
+        let none = HeaderMap::new();
+        assert!(policy.allows(3, &Method::GET, &none));
+        assert!(!policy.allows(4, &Method::GET, &none));
+        assert!(!RetryPolicy::none().allows(1, &Method::GET, &none));
+    }
+}
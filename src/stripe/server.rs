@@ -0,0 +1,166 @@
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::post;
+use axum::Router;
+
+use std::sync::Arc;
+
+use crate::stripe::webhook::SIGNATURE_HEADER;
+use crate::stripe::{AsyncEventDispatcher, EventStore, Webhook};
+
+/// Receives webhook requests, verifies them and dispatches their events, ready to be mounted in axum.
+///
+/// Answers `200 OK` once the handlers ran, or when the event is a duplicate or has no handler,
+/// `400 Bad Request` when the signature or the payload is invalid, and `500 Internal Server Error` when a
+/// handler or the store failed, so Stripe delivers the event again later. Only available with the
+/// `webhook-server` feature.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// use payup::stripe::{AsyncEventDispatcher, EventType, Invoice, MemoryEventStore, Webhook, WebhookReceiver};
+///
+/// let dispatcher = AsyncEventDispatcher::new()
+///     .on(EventType::InvoicePaid, |invoice: Invoice| async move {
+///         println!("{:?} was paid", invoice.id);
+///         Ok(())
+///     });
+///
+/// let receiver = WebhookReceiver::new(Webhook::new("whsec_"), dispatcher).store(MemoryEventStore::new());
+/// let app: axum::Router = axum::Router::new().merge(receiver.router("/stripe/webhook"));
+/// # Ok(())
+/// # }
+/// ```
+pub struct WebhookReceiver {
+    webhook: Webhook,
+    dispatcher: AsyncEventDispatcher,
+    store: Option<Arc<dyn EventStore>>,
+}
+
+impl WebhookReceiver {
+    /// Returns a receiver that verifies requests with `webhook` and hands their events to `dispatcher`.
+    pub fn new(webhook: Webhook, dispatcher: AsyncEventDispatcher) -> Self {
+        WebhookReceiver {
+            webhook,
+            dispatcher,
+            store: None,
+        }
+    }
+
+    /// Handles a single request and returns the status code and a short message to answer with.
+    ///
+    /// Takes the `Stripe-Signature` header and the raw body, so a receiver can be tested without a server.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature` - The value of the `Stripe-Signature` header, `None` when it is missing.
+    /// * `payload` - The raw body of the request.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use payup::stripe::{AsyncEventDispatcher, Charge, EventType, MemoryEventStore, Webhook, WebhookReceiver};
+    /// use axum::http::StatusCode;
+    ///
+    /// let dispatcher = AsyncEventDispatcher::new()
+    ///     .on(EventType::ChargeSucceeded, |charge: Charge| async move {
+    ///         assert_eq!(charge.amount, Some(2000));
+    ///         Ok(())
+    ///     });
+    /// let receiver = WebhookReceiver::new(Webhook::new("whsec_test"), dispatcher).store(MemoryEventStore::new());
+    ///
+    /// // A charge.succeeded event the way Stripe sends it
+    /// let payload = r#"{
+    ///   "id": "evt_3LQJ0zCZ6qsJgndJ0Tv1yUQ5",
+    ///   "object": "event",
+    ///   "api_version": "2020-08-27",
+    ///   "created": 1659104221,
+    ///   "data": {
+    ///     "object": {
+    ///       "id": "ch_3LQJ0zCZ6qsJgndJ0DLnGaUw",
+    ///       "object": "charge",
+    ///       "amount": 2000,
+    ///       "amount_captured": 2000,
+    ///       "amount_refunded": 0,
+    ///       "balance_transaction": "txn_3LQJ0zCZ6qsJgndJ0jLlrGmX",
+    ///       "billing_details": {"address": {"city": null, "country": null, "line1": null, "line2": null, "postal_code": null, "state": null}, "email": null, "name": null, "phone": null},
+    ///       "captured": true,
+    ///       "created": 1659104221,
+    ///       "currency": "usd",
+    ///       "customer": null,
+    ///       "description": "(created by Stripe CLI)",
+    ///       "disputed": false,
+    ///       "fraud_details": {},
+    ///       "livemode": false,
+    ///       "metadata": {},
+    ///       "paid": true,
+    ///       "payment_intent": "pi_3LQJ0zCZ6qsJgndJ0mOrZ3Kq",
+    ///       "payment_method": "pm_1LQJ0zCZ6qsJgndJxSFvqGcf",
+    ///       "payment_method_details": {
+    ///         "card": {"brand": "visa", "country": "US", "exp_month": 7, "exp_year": 2023, "fingerprint": "hcu7VJP8IrpyJnWL", "funding": "credit", "last4": "4242", "network": "visa"},
+    ///         "type": "card"
+    ///       },
+    ///       "receipt_url": "https://pay.stripe.com/receipts/acct_1KAsvvCZ6qsJgndJ/ch_3LQJ0zCZ6qsJgndJ0DLnGaUw/rcpt_M8aBiVQfFS2usnMmxW4nMSoKFDWBuSs",
+    ///       "refunded": false,
+    ///       "refunds": {"object": "list", "data": [], "has_more": false, "url": "/v1/charges/ch_3LQJ0zCZ6qsJgndJ0DLnGaUw/refunds"},
+    ///       "status": "succeeded"
+    ///     }
+    ///   },
+    ///   "livemode": false,
+    ///   "pending_webhooks": 2,
+    ///   "request": {"id": "req_6gKXJYmV8qbPTf", "idempotency_key": "5d7a8b2e-30c8-4b1d-8c4f-3b4e0e0d9d1a"},
+    ///   "type": "charge.succeeded"
+    /// }"#;
+    /// let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs() as i64;
+    ///
+    /// // Sign the fixture with the secret the receiver was created with
+    /// let signature = Webhook::new("whsec_test").sign(payload, now);
+    /// let (status, _) = receiver.handle(Some(&signature), payload.as_bytes()).await;
+    /// assert_eq!(status, StatusCode::OK);
+    ///
+    /// // A fixture signed with another secret is rejected
+    /// let signature = Webhook::new("whsec_other").sign(payload, now);
+    /// let (status, _) = receiver.handle(Some(&signature), payload.as_bytes()).await;
+    /// assert_eq!(status, StatusCode::BAD_REQUEST);
+    /// # }
+    /// ```
+    pub async fn handle(&self, signature: Option<&str>, payload: &[u8]) -> (StatusCode, String) {
+        let signature = match signature {
+            Some(signature) => signature,
+            None => return (StatusCode::BAD_REQUEST, format!("missing {} header", SIGNATURE_HEADER)),
+        };
+        let event = match self.webhook.construct_event(payload, signature) {
+            Ok(event) => event,
+            Err(err) => return (StatusCode::BAD_REQUEST, err.to_string()),
+        };
+
+        let dispatched = match &self.store {
+            Some(store) => self.dispatcher.dispatch_once(store.as_ref(), &event).await,
+            None => self.dispatcher.dispatch(&event).await,
+        };
+        match dispatched {
+            Ok(_) => (StatusCode::OK, String::new()),
+            Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
+        }
+    }
+
+    /// Returns a Router that receives webhook requests with `POST` at `path`, e.g. `/stripe/webhook`.
+    pub fn router<S: Clone + Send + Sync + 'static>(self, path: &str) -> Router<S> {
+        Router::new().route(path, post(receive)).with_state(Arc::new(self))
+    }
+
+    /// Skips events the store recorded before, so an event Stripe delivers twice is handled once.
+    pub fn store(mut self, store: impl EventStore + 'static) -> Self {
+        self.store = Some(Arc::new(store));
+        self
+    }
+}
+
+async fn receive(State(receiver): State<Arc<WebhookReceiver>>, headers: HeaderMap, payload: Bytes) -> (StatusCode, String) {
+    let signature = headers.get(SIGNATURE_HEADER).and_then(|value| value.to_str().ok());
+    receiver.handle(signature, &payload).await
+}
@@ -0,0 +1,360 @@
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Stripe lists events for 30 days and stops retrying a delivery long before, older ids never come back.
+const RETENTION: i64 = 30 * 24 * 60 * 60;
+// The fewest entries added between two prunes, so pruning does not walk every event on each insert.
+const PRUNE_EVERY: usize = 1000;
+
+/// Remembers which events were processed, so an event Stripe delivers more than once is handled once.
+///
+/// Stripe delivers webhooks at least once and retries a delivery until it was acknowledged, see
+/// `EventDispatcher::dispatch_once` and `EventDispatcher::replay`. Besides the events it keeps the replay
+/// checkpoint, the `created` timestamp up to which every event is known to be processed. Both stores
+/// forget events created more than 30 days ago, Stripe neither retries nor lists those anymore. Implement
+/// it on top of your own database to share the processed events between several servers.
+pub trait EventStore: Send + Sync {
+    /// The replay checkpoint, `None` when it was never set.
+    fn checkpoint(&self) -> io::Result<Option<i64>>;
+
+    /// Records an event as processed, returns `false` when it was recorded before.
+    ///
+    /// Has to check and record in one step, so two deliveries of the same event handled at the same time
+    /// cannot both get `true`.
+    fn insert(&self, id: &str, created: i64) -> io::Result<bool>;
+
+    /// Forgets an event, e.g. because handling it failed and it has to be processed again.
+    fn remove(&self, id: &str) -> io::Result<()>;
+
+    /// Stores the replay checkpoint, the dispatcher only ever moves it forward.
+    fn set_checkpoint(&self, created: i64) -> io::Result<()>;
+}
+
+/// Keeps the processed events in memory, they are lost when the process exits.
+///
+/// # Examples
+///
+/// ```
+/// use payup::stripe::EventStore;
+///
+/// let store = payup::stripe::MemoryEventStore::new();
+/// assert!(store.insert("evt_1", 1651234567).unwrap());
+/// assert!(!store.insert("evt_1", 1651234567).unwrap());
+/// ```
+#[derive(Debug, Default)]
+pub struct MemoryEventStore {
+    events: Mutex<Events>,
+    checkpoint: Mutex<Option<i64>>,
+}
+
+impl MemoryEventStore {
+    pub fn new() -> Self {
+        MemoryEventStore::default()
+    }
+}
+
+impl EventStore for MemoryEventStore {
+    fn checkpoint(&self) -> io::Result<Option<i64>> {
+        Ok(*lock(&self.checkpoint))
+    }
+
+    fn insert(&self, id: &str, created: i64) -> io::Result<bool> {
+        let mut events = lock(&self.events);
+        if events.created.contains_key(id) {
+            return Ok(false);
+        }
+        events.prune();
+        events.insert(id, created);
+        Ok(true)
+    }
+
+    fn remove(&self, id: &str) -> io::Result<()> {
+        lock(&self.events).created.remove(id);
+        Ok(())
+    }
+
+    fn set_checkpoint(&self, created: i64) -> io::Result<()> {
+        *lock(&self.checkpoint) = Some(created);
+        Ok(())
+    }
+}
+
+/// Keeps the processed events in a file, so they survive a restart.
+///
+/// Every change is appended as a line, `{id} {created}` for a processed event, `-{id}` for one that was
+/// removed and `@{created}` for the replay checkpoint, and the file is read back into memory when it is
+/// opened. When events are pruned the file is rewritten with the ones that are left. A line cut off by a
+/// crash at the end of the file is dropped when it is opened. Only one process may use a file at a time.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> std::io::Result<()> {
+/// let store = payup::stripe::FileEventStore::open("stripe-events.log")?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct FileEventStore {
+    state: Mutex<FileState>,
+}
+
+#[derive(Debug)]
+struct FileState {
+    events: Events,
+    checkpoint: Option<i64>,
+    file: File,
+    path: PathBuf,
+}
+
+impl FileEventStore {
+    /// Opens the file at `path`, it is created when it does not exist.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let file = OpenOptions::new().create(true).read(true).append(true).open(&path)?;
+        let mut events = Events::default();
+        let mut checkpoint = None;
+        let mut reader = BufReader::new(&file);
+        let (mut line, mut complete) = (String::new(), 0);
+        loop {
+            line.clear();
+            let read = reader.read_line(&mut line)?;
+            if read == 0 {
+                break;
+            }
+            let parsed = line.strip_suffix('\n').and_then(|line| apply(line, &mut events, &mut checkpoint));
+            match parsed {
+                Some(()) => complete += read as u64,
+                // Only the last line can have been cut off by a crash, one before it means the file is broken.
+                None if reader.fill_buf()?.is_empty() => break,
+                None => return Err(io::Error::new(io::ErrorKind::InvalidData, format!("invalid event line: {}", line.trim_end()))),
+            }
+        }
+        if complete < file.metadata()?.len() {
+            file.set_len(complete)?;
+        }
+
+        Ok(FileEventStore {
+            state: Mutex::new(FileState { events, checkpoint, file, path }),
+        })
+    }
+}
+
+// Applies one line of the file, returns `None` when it cannot be parsed.
+fn apply(line: &str, events: &mut Events, checkpoint: &mut Option<i64>) -> Option<()> {
+    events.appended += 1;
+    if let Some(id) = line.strip_prefix('-') {
+        events.created.remove(id);
+    } else if let Some(created) = line.strip_prefix('@') {
+        *checkpoint = Some(created.parse().ok()?);
+    } else if let Some((id, created)) = line.split_once(' ') {
+        events.created.insert(id.to_string(), created.parse().ok()?);
+    }
+    Some(())
+}
+
+impl FileState {
+    fn append(&mut self, line: std::fmt::Arguments<'_>) -> io::Result<()> {
+        writeln!(self.file, "{}", line)?;
+        self.events.appended += 1;
+        Ok(())
+    }
+
+    // Writes the events that are left to a new file and moves it over the old one, the new file is kept
+    // open for the lines appended later.
+    fn rewrite(&mut self) -> io::Result<()> {
+        let mut temporary = self.path.clone().into_os_string();
+        temporary.push(".tmp");
+        let file = File::create(&temporary)?;
+        let mut writer = BufWriter::new(&file);
+        for (id, created) in &self.events.created {
+            writeln!(writer, "{} {}", id, created)?;
+        }
+        if let Some(checkpoint) = self.checkpoint {
+            writeln!(writer, "@{}", checkpoint)?;
+        }
+        writer.flush()?;
+        drop(writer);
+        file.sync_all()?;
+        fs::rename(&temporary, &self.path)?;
+        self.file = file;
+        Ok(())
+    }
+}
+
+impl EventStore for FileEventStore {
+    fn checkpoint(&self) -> io::Result<Option<i64>> {
+        Ok(lock(&self.state).checkpoint)
+    }
+
+    // Pruning happens before the event is written, so a failed rewrite does not leave it recorded.
+    fn insert(&self, id: &str, created: i64) -> io::Result<bool> {
+        let mut state = lock(&self.state);
+        if state.events.created.contains_key(id) {
+            return Ok(false);
+        }
+        if state.events.prune() {
+            state.rewrite()?;
+        }
+        state.append(format_args!("{} {}", id, created))?;
+        state.events.created.insert(id.to_string(), created);
+        Ok(true)
+    }
+
+    fn remove(&self, id: &str) -> io::Result<()> {
+        let mut state = lock(&self.state);
+        if state.events.created.remove(id).is_some() {
+            state.append(format_args!("-{}", id))?;
+        }
+        Ok(())
+    }
+
+    fn set_checkpoint(&self, created: i64) -> io::Result<()> {
+        let mut state = lock(&self.state);
+        state.append(format_args!("@{}", created))?;
+        state.checkpoint = Some(created);
+        Ok(())
+    }
+}
+
+// The processed events of a store by id, with the `created` timestamp they are pruned by.
+#[derive(Debug, Default)]
+struct Events {
+    created: HashMap<String, i64>,
+    // Entries added since the last prune, for the file store the lines appended since it was rewritten.
+    appended: usize,
+}
+
+impl Events {
+    fn insert(&mut self, id: &str, created: i64) {
+        self.created.insert(id.to_string(), created);
+        self.appended += 1;
+    }
+
+    // Drops the events older than the retention once as many entries were added as are kept, at least
+    // `PRUNE_EVERY`, so the work is spread over the inserts. Returns whether it pruned.
+    fn prune(&mut self) -> bool {
+        if self.appended < PRUNE_EVERY.max(self.created.len()) {
+            return false;
+        }
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs() as i64)
+            .unwrap_or(0);
+        self.created.retain(|_, created| *created >= now - RETENTION);
+        self.appended = 0;
+        true
+    }
+}
+
+// A panic while the lock was held cannot leave the map half updated, so a poisoned lock is still usable.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Removes the file when the test is done, also when it fails.
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(contents: &str) -> Self {
+            let path = std::env::temp_dir().join(format!("payup-events-{}.log", uuid::Uuid::new_v4()));
+            fs::write(&path, contents).unwrap();
+            TempFile(path)
+        }
+
+        fn contents(&self) -> String {
+            fs::read_to_string(&self.0).unwrap()
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    fn now() -> i64 {
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64
+    }
+
+    #[test]
+    fn memory_store_forgets_events_older_than_the_retention() {
+        let store = MemoryEventStore::new();
+        for i in 0..PRUNE_EVERY {
+            assert!(store.insert(&format!("evt_old_{}", i), now() - RETENTION - 60).unwrap());
+        }
+        assert!(store.insert("evt_recent", now() - 60).unwrap());
+        assert!(store.insert("evt_old_0", now() - RETENTION - 60).unwrap());
+        assert!(!store.insert("evt_recent", now() - 60).unwrap());
+    }
+
+    #[test]
+    fn file_store_reads_back_what_was_written() {
+        let file = TempFile::new("");
+        let store = FileEventStore::open(&file.0).unwrap();
+        store.insert("evt_1", 100).unwrap();
+        store.insert("evt_2", 101).unwrap();
+        store.remove("evt_2").unwrap();
+        store.set_checkpoint(100).unwrap();
+        drop(store);
+
+        let store = FileEventStore::open(&file.0).unwrap();
+        assert!(!store.insert("evt_1", 100).unwrap());
+        assert!(store.insert("evt_2", 101).unwrap());
+        assert_eq!(store.checkpoint().unwrap(), Some(100));
+    }
+
+    #[test]
+    fn file_store_drops_a_line_cut_off_at_the_end() {
+        for cut_off in ["evt_2 10", "evt_2 ", "@1"] {
+            let file = TempFile::new(&format!("evt_1 100\n@100\n{}", cut_off));
+            let store = FileEventStore::open(&file.0).unwrap();
+            assert_eq!(store.checkpoint().unwrap(), Some(100));
+            assert!(store.insert("evt_3", 102).unwrap());
+            assert_eq!(file.contents(), "evt_1 100\n@100\nevt_3 102\n");
+        }
+    }
+
+    #[test]
+    fn file_store_drops_an_invalid_last_line() {
+        let file = TempFile::new("evt_1 100\nevt_2 x\n");
+        let store = FileEventStore::open(&file.0).unwrap();
+        assert!(!store.insert("evt_1", 100).unwrap());
+        assert!(store.insert("evt_2", 101).unwrap());
+        assert_eq!(file.contents(), "evt_1 100\nevt_2 101\n");
+    }
+
+    #[test]
+    fn file_store_rejects_an_invalid_line_before_the_last() {
+        let file = TempFile::new("evt_1 x\nevt_2 101\n");
+        let err = FileEventStore::open(&file.0).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn file_store_rewrites_the_file_when_pruning() {
+        let old: String = (0..PRUNE_EVERY).map(|i| format!("evt_old_{} {}\n", i, now() - RETENTION - 60)).collect();
+        let file = TempFile::new(&format!("{}@{}\n", old, now() - RETENTION - 60));
+        let store = FileEventStore::open(&file.0).unwrap();
+        assert!(store.insert("evt_recent", now()).unwrap());
+        assert!(store.insert("evt_next", now()).unwrap());
+
+        let contents = file.contents();
+        assert!(!contents.contains("evt_old_"), "{}", contents);
+        assert_eq!(contents.lines().count(), 3);
+        drop(store);
+
+        let store = FileEventStore::open(&file.0).unwrap();
+        assert!(!store.insert("evt_recent", now()).unwrap());
+        assert!(!store.insert("evt_next", now()).unwrap());
+        assert!(store.insert("evt_old_0", now() - RETENTION - 60).unwrap());
+    }
+}
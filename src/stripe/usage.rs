@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::stripe::{StripeClient, StripeError, UsageRecord};
+
+/// Buffers usage increments of metered subscription items and reports them to Stripe in batches.
+///
+/// `add` only counts the quantity in memory, `flush` sends one `increment` usage record per subscription
+/// item with everything added since the last flush. A batch that failed to send because of the network, a
+/// rate limit, a conflicting request or a problem on Stripe's end is kept together with its idempotency key
+/// and sent again unchanged by the next flush, so a request that reached Stripe before failing is not
+/// counted twice. A batch Stripe rejected would be rejected again, so it is dropped. Usage that was never
+/// flushed is lost when the aggregator is dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> Result<(), payup::stripe::StripeError> {
+/// # let (client, secret) = (String::new(), String::new());
+/// // Create the Authentication refererence
+/// let auth = payup::stripe::Auth::new(client, secret);
+/// let client = payup::stripe::StripeClient::new(auth);
+///
+/// let usage = payup::stripe::UsageAggregator::new(&client);
+///
+/// // Count every api call as it is served
+/// usage.add("si_", 1);
+/// usage.add("si_", 1);
+///
+/// // Report the calls, e.g. once a minute
+/// let records = usage.flush()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct UsageAggregator {
+    client: StripeClient,
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    pending: HashMap<String, i64>,
+    failed: Vec<Batch>,
+}
+
+// Everything added for a subscription item between two flushes, sent as a single usage record.
+struct Batch {
+    subscription_item: String,
+    quantity: i64,
+    timestamp: i64,
+    idempotency_key: String,
+}
+
+impl UsageAggregator {
+    pub fn new(client: &StripeClient) -> Self {
+        UsageAggregator {
+            client: client.clone(),
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// Adds `quantity` to the usage of a subscription item that is reported by the next flush.
+    pub fn add(&self, subscription_item: impl Into<String>, quantity: i64) {
+        let mut state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        *state.pending.entry(subscription_item.into()).or_insert(0) += quantity;
+    }
+
+    /// Asynchronously sends everything added since the last flush, plus the batches that failed before.
+    ///
+    /// Every batch is tried, the records Stripe created are returned. When a batch fails the first error is
+    /// returned instead, the batch is kept for the next flush if the failure was transient and dropped
+    /// otherwise, e.g. when Stripe rejected it as an invalid request.
+    pub async fn async_flush(&self) -> Result<Vec<UsageRecord>, StripeError> {
+        let mut flushed = Flushed::default();
+        for batch in self.take() {
+            let result = batch.to_record().async_post(&self.client.with_idempotency_key(batch.idempotency_key.clone())).await;
+            self.settle(&mut flushed, batch, result);
+        }
+        flushed.finish()
+    }
+
+    /// Sends everything added since the last flush, plus the batches that failed before.
+    ///
+    /// Every batch is tried, the records Stripe created are returned. When a batch fails the first error is
+    /// returned instead, the batch is kept for the next flush if the failure was transient and dropped
+    /// otherwise, e.g. when Stripe rejected it as an invalid request.
+    pub fn flush(&self) -> Result<Vec<UsageRecord>, StripeError> {
+        let mut flushed = Flushed::default();
+        for batch in self.take() {
+            let result = batch.to_record().post(&self.client.with_idempotency_key(batch.idempotency_key.clone()));
+            self.settle(&mut flushed, batch, result);
+        }
+        flushed.finish()
+    }
+
+    /// Whether there is nothing left to report.
+    pub fn is_empty(&self) -> bool {
+        let state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        state.pending.values().all(|quantity| *quantity == 0) && state.failed.is_empty()
+    }
+
+    /// The quantity of a subscription item that was not reported yet, failed batches included.
+    pub fn pending(&self, subscription_item: &str) -> i64 {
+        let state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let failed: i64 = state.failed.iter()
+            .filter(|batch| batch.subscription_item == subscription_item)
+            .map(|batch| batch.quantity)
+            .sum();
+        failed + state.pending.get(subscription_item).copied().unwrap_or(0)
+    }
+
+    // Turns the counted usage into batches, the lock is released before anything is sent.
+    fn take(&self) -> Vec<Batch> {
+        let mut state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs() as i64)
+            .unwrap_or(0);
+        let pending = std::mem::take(&mut state.pending);
+        let mut batches = std::mem::take(&mut state.failed);
+        batches.extend(pending.into_iter().filter(|(_, quantity)| *quantity != 0).map(|(subscription_item, quantity)| Batch {
+            subscription_item,
+            quantity,
+            timestamp,
+            idempotency_key: uuid::Uuid::new_v4().to_string(),
+        }));
+        batches
+    }
+
+    // Records the outcome of sending a batch, a batch that failed transiently is kept for the next flush.
+    fn settle(&self, flushed: &mut Flushed, batch: Batch, result: Result<UsageRecord, StripeError>) {
+        match result {
+            Ok(record) => flushed.records.push(record),
+            Err(err) => {
+                if is_transient(&err) {
+                    let mut state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+                    state.failed.push(batch);
+                }
+                flushed.error.get_or_insert(err);
+            }
+        }
+    }
+}
+
+// The records one flush created and the first error it ran into.
+#[derive(Default)]
+struct Flushed {
+    records: Vec<UsageRecord>,
+    error: Option<StripeError>,
+}
+
+impl Flushed {
+    fn finish(self) -> Result<Vec<UsageRecord>, StripeError> {
+        self.error.map_or(Ok(self.records), Err)
+    }
+}
+
+// Only failures that may go away on their own are worth sending the batch again for.
+fn is_transient(err: &StripeError) -> bool {
+    match err {
+        StripeError::Transport(_) | StripeError::RateLimit(_) => true,
+        // A 409 means a request with the same idempotency key was still running or the object was locked,
+        // sending the batch again with the same key is safe.
+        _ => err.api_error().is_some_and(|err| err.status == 409 || err.status >= 500),
+    }
+}
+
+impl Batch {
+    fn to_record(&self) -> UsageRecord {
+        UsageRecord {
+            action: Some("increment".to_string()),
+            quantity: Some(self.quantity),
+            subscription_item: Some(self.subscription_item.clone()),
+            timestamp: Some(self.timestamp),
+            ..UsageRecord::default()
+        }
+    }
+}
@@ -0,0 +1,283 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::stripe::Event;
+
+/// The header Stripe sends the signature of a webhook request in.
+pub const SIGNATURE_HEADER: &str = "Stripe-Signature";
+
+// Same default as Stripe's own libraries, long enough for a retried delivery to arrive.
+const DEFAULT_TOLERANCE: Duration = Duration::from_secs(300);
+
+/// Verifies the `Stripe-Signature` header of webhook requests and parses their payload into an Event.
+///
+/// The header carries a timestamp and one or more `v1` signatures, an HMAC-SHA256 of
+/// `{timestamp}.{payload}` made with the signing secret of the endpoint. A request is accepted when any
+/// of the signatures was made with any of the secrets, so a secret can be rolled without dropping
+/// events, and when the timestamp is not older than the tolerance, so a captured request cannot be
+/// replayed later. The payload must be the raw request body, a body that was parsed and serialized
+/// again does not match the signature anymore.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> Result<(), payup::stripe::WebhookError> {
+/// # let (payload, header) = (String::new(), String::new());
+/// let webhook = payup::stripe::Webhook::new("whsec_");
+///
+/// // The raw body and the Stripe-Signature header of the request
+/// let event = webhook.construct_event(&payload, &header)?;
+/// if let Some(payup::stripe::EventObject::Invoice(invoice)) = event.data.map(|data| data.object) {
+///     println!("{:?} is {:?}", invoice.id, invoice.status);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Webhook {
+    secrets: Vec<String>,
+    tolerance: Option<Duration>,
+}
+
+impl Webhook {
+    /// Returns a verifier for the signing secret of an endpoint, e.g. `whsec_...`.
+    pub fn new(secret: impl Into<String>) -> Self {
+        Webhook {
+            secrets: vec![secret.into()],
+            tolerance: Some(DEFAULT_TOLERANCE),
+        }
+    }
+
+    /// Checks the signature of a webhook request and parses its payload into an Event.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - The raw body of the request.
+    /// * `header` - The value of the `Stripe-Signature` header.
+    pub fn construct_event(&self, payload: impl AsRef<[u8]>, header: &str) -> Result<Event, WebhookError> {
+        let payload = payload.as_ref();
+        self.verify(payload, header)?;
+        serde_json::from_slice(payload).map_err(WebhookError::Decode)
+    }
+
+    /// Accepts signatures made with another secret as well, e.g. the previous one while a secret is rolled
+    /// or the secret of a second endpoint that sends to the same URL.
+    pub fn secret(mut self, secret: impl Into<String>) -> Self {
+        self.secrets.push(secret.into());
+        self
+    }
+
+    /// Signs a payload the way Stripe does and returns the value for the `Stripe-Signature` header.
+    ///
+    /// Uses the first secret. Useful to send signed fixtures to your own endpoint in tests.
+    pub fn sign(&self, payload: impl AsRef<[u8]>, timestamp: i64) -> String {
+        let signature = mac(&self.secrets[0], timestamp, payload.as_ref()).finalize().into_bytes();
+        format!("t={},v1={}", timestamp, hex::encode(signature))
+    }
+
+    /// Sets how old a signature may be before it is rejected. Defaults to 5 minutes, `None` accepts any age.
+    pub fn tolerance(mut self, tolerance: Option<Duration>) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Checks the signature of a webhook request without parsing its payload.
+    pub fn verify(&self, payload: impl AsRef<[u8]>, header: &str) -> Result<(), WebhookError> {
+        let (timestamp, signatures) = parse_header(header)?;
+        let signed = self.secrets.iter().any(|secret| {
+            let mac = mac(secret, timestamp, payload.as_ref());
+            signatures.iter().any(|signature| mac.clone().verify_slice(signature).is_ok())
+        });
+        if !signed {
+            return Err(WebhookError::NoMatchingSignature);
+        }
+
+        if let Some(tolerance) = self.tolerance {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_secs() as i64)
+                .unwrap_or(0);
+            if timestamp < now - tolerance.as_secs() as i64 {
+                return Err(WebhookError::TimestampOutsideTolerance(timestamp));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn mac(secret: &str, timestamp: i64, payload: &[u8]) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(payload);
+    mac
+}
+
+// Returns the timestamp and the decoded `v1` signatures, other schemes like the `v0` of test events are skipped.
+fn parse_header(header: &str) -> Result<(i64, Vec<Vec<u8>>), WebhookError> {
+    let mut timestamp = None;
+    let mut signatures = Vec::new();
+    for (key, value) in header.split(',').filter_map(|item| item.trim().split_once('=')) {
+        match key {
+            "t" => timestamp = value.parse::<i64>().ok(),
+            "v1" => signatures.extend(hex::decode(value).ok()),
+            _ => {}
+        }
+    }
+
+    match timestamp {
+        Some(timestamp) if !signatures.is_empty() => Ok((timestamp, signatures)),
+        _ => Err(WebhookError::InvalidHeader),
+    }
+}
+
+/// Errors returned when a webhook request cannot be verified or parsed.
+#[derive(Debug)]
+pub enum WebhookError {
+    /// The signature header has no timestamp or no `v1` signature.
+    InvalidHeader,
+    /// None of the signatures was made with one of the secrets for this payload.
+    NoMatchingSignature,
+    /// The signature is older than the tolerance, the request may have been replayed. Carries the timestamp.
+    TimestampOutsideTolerance(i64),
+    /// The payload is not a valid event.
+    Decode(serde_json::Error),
+}
+
+impl fmt::Display for WebhookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WebhookError::InvalidHeader => write!(f, "invalid {} header", SIGNATURE_HEADER),
+            WebhookError::NoMatchingSignature => write!(f, "no webhook signature matches the payload"),
+            WebhookError::TimestampOutsideTolerance(timestamp) => write!(f, "webhook signature timestamp {} is outside the tolerance", timestamp),
+            WebhookError::Decode(err) => write!(f, "could not decode webhook event: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for WebhookError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            WebhookError::Decode(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::stripe::{EventObject, EventType};
+
+    const PAYLOAD: &str = r#"{"id": "evt_1", "object": "event", "created": 1680064028, "data": {"object": {"id": "ch_1", "object": "charge", "amount": 1099}}, "type": "charge.succeeded"}"#;
+
+    fn now() -> i64 {
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64
+    }
+
+    fn signature(secret: &str, timestamp: i64) -> String {
+        let header = Webhook::new(secret).sign(PAYLOAD, timestamp);
+        header.split_once(",v1=").unwrap().1.to_string()
+    }
+
+    #[test]
+    fn accepts_a_signed_payload() {
+        let webhook = Webhook::new("whsec_1");
+        let event = webhook.construct_event(PAYLOAD, &webhook.sign(PAYLOAD, now())).unwrap();
+        assert_eq!(event.type_field, Some(EventType::ChargeSucceeded));
+        match event.data.map(|data| data.object) {
+            Some(EventObject::Charge(charge)) => assert_eq!(charge.amount, Some(1099)),
+            object => panic!("expected a charge, got {:?}", object),
+        }
+    }
+
+    #[test]
+    fn rejects_a_payload_signed_with_another_secret() {
+        let header = Webhook::new("whsec_other").sign(PAYLOAD, now());
+        assert!(matches!(Webhook::new("whsec_1").verify(PAYLOAD, &header), Err(WebhookError::NoMatchingSignature)));
+    }
+
+    #[test]
+    fn rejects_a_changed_payload() {
+        let webhook = Webhook::new("whsec_1");
+        let header = webhook.sign(PAYLOAD, now());
+        let changed = PAYLOAD.replace("1099", "1");
+        assert!(matches!(webhook.verify(changed, &header), Err(WebhookError::NoMatchingSignature)));
+    }
+
+    #[test]
+    fn accepts_the_previous_secret_while_rolling() {
+        let webhook = Webhook::new("whsec_new").secret("whsec_old");
+        for secret in ["whsec_new", "whsec_old"] {
+            let header = Webhook::new(secret).sign(PAYLOAD, now());
+            assert!(webhook.verify(PAYLOAD, &header).is_ok(), "{} is rejected", secret);
+        }
+    }
+
+    #[test]
+    fn accepts_any_of_several_v1_signatures() {
+        let timestamp = now();
+        let header = format!("t={},v1={},v1={}", timestamp, signature("whsec_old", timestamp), signature("whsec_new", timestamp));
+        assert!(Webhook::new("whsec_new").verify(PAYLOAD, &header).is_ok());
+        assert!(Webhook::new("whsec_old").verify(PAYLOAD, &header).is_ok());
+        assert!(matches!(Webhook::new("whsec_other").verify(PAYLOAD, &header), Err(WebhookError::NoMatchingSignature)));
+    }
+
+    #[test]
+    fn ignores_v0_signatures() {
+        let timestamp = now();
+        let v0 = format!("t={},v0={}", timestamp, signature("whsec_1", timestamp));
+        assert!(matches!(Webhook::new("whsec_1").verify(PAYLOAD, &v0), Err(WebhookError::InvalidHeader)));
+
+        let both = format!("t={},v1={},v0={}", timestamp, signature("whsec_1", timestamp), signature("whsec_other", timestamp));
+        assert!(Webhook::new("whsec_1").verify(PAYLOAD, &both).is_ok());
+    }
+
+    #[test]
+    fn rejects_an_expired_timestamp() {
+        let timestamp = now() - 301;
+        let webhook = Webhook::new("whsec_1");
+        let header = webhook.sign(PAYLOAD, timestamp);
+        assert!(matches!(webhook.verify(PAYLOAD, &header), Err(WebhookError::TimestampOutsideTolerance(t)) if t == timestamp));
+        assert!(webhook.clone().tolerance(Some(Duration::from_secs(600))).verify(PAYLOAD, &header).is_ok());
+        assert!(webhook.tolerance(None).verify(PAYLOAD, &header).is_ok());
+    }
+
+    #[test]
+    fn rejects_a_malformed_header() {
+        let timestamp = now();
+        let signature = signature("whsec_1", timestamp);
+        let headers = [
+            String::new(),
+            format!("v1={}", signature),
+            format!("t={}", timestamp),
+            format!("t=yesterday,v1={}", signature),
+            format!("t={},v1=not-hex", timestamp),
+            format!("t:{};v1:{}", timestamp, signature),
+        ];
+        for header in &headers {
+            assert!(matches!(Webhook::new("whsec_1").verify(PAYLOAD, header), Err(WebhookError::InvalidHeader)), "{:?} is accepted", header);
+        }
+    }
+
+    #[test]
+    fn keeps_an_object_that_does_not_decode_as_unknown() {
+        let payload = PAYLOAD.replace("1099", r#""1099""#);
+        let webhook = Webhook::new("whsec_1");
+        let event = webhook.construct_event(&payload, &webhook.sign(&payload, now())).unwrap();
+        match event.data.map(|data| data.object) {
+            Some(EventObject::Unknown(object)) => assert_eq!(object["amount"], "1099"),
+            object => panic!("expected an unknown object, got {:?}", object),
+        }
+    }
+
+    #[test]
+    fn rejects_a_payload_that_is_not_an_event() {
+        let webhook = Webhook::new("whsec_1");
+        assert!(matches!(webhook.construct_event("not json", &webhook.sign("not json", now())), Err(WebhookError::Decode(_))));
+    }
+}